@@ -0,0 +1,135 @@
+use core::time::Duration;
+
+use clinvoice_match::{
+	Compile,
+	Explain,
+	IsMatch,
+	Match,
+	MatchEmployee,
+	MatchJob,
+	MatchStr,
+	MatchTimesheet,
+};
+use clinvoice_schema::{
+	chrono::{NaiveDate, NaiveDateTime},
+	Currency,
+	Employee,
+	Invoice,
+	Job,
+	Location,
+	Money,
+	Organization,
+	Timesheet,
+};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// The number of [`Timesheet`]s which each benchmark filters.
+const RECORDS: i64 = 10_000;
+
+fn date(day: i64) -> NaiveDateTime
+{
+	NaiveDate::from_ymd_opt(2022, 1, 1)
+		.unwrap()
+		.and_hms_opt(0, 0, 0)
+		.unwrap() +
+		clinvoice_schema::chrono::Duration::days(day % 365)
+}
+
+fn timesheets() -> Vec<Timesheet>
+{
+	const NAMES: [&str; 5] = ["Alice", "Bob", "Carol", "Dan", "Erin"];
+
+	(0..RECORDS)
+		.map(|i| Timesheet {
+			employee: Employee {
+				id: i % 50,
+				name: NAMES[(i % 5) as usize].into(),
+				status: "Hired".into(),
+				title: "Engineer".into(),
+			},
+			expenses: Vec::new(),
+			id: i,
+			job: Job {
+				client: Organization {
+					id: i % 20,
+					location: Location {
+						id: 0,
+						name: "Sweden".into(),
+						outer: None,
+					},
+					name: "Big Old Test Corporation".into(),
+				},
+				date_close: None,
+				date_open: date(i),
+				id: i % 100,
+				increment: Duration::from_secs(900),
+				invoice: Invoice {
+					date: None,
+					hourly_rate: Money::new(20_00, 2, Currency::Usd),
+				},
+				notes: String::new(),
+				objectives: "Get it done".into(),
+			},
+			time_begin: date(i),
+			time_end: None,
+			work_notes: format!("Worked on task #{i}"),
+		})
+		.collect()
+}
+
+fn condition() -> MatchTimesheet
+{
+	MatchTimesheet {
+		employee: MatchEmployee {
+			name: MatchStr::Regex("^[ABC]".into()),
+			..Default::default()
+		},
+		job: MatchJob {
+			id: Match::Or((0..10).map(|i| Match::EqualTo(i * 7)).collect()),
+			..Default::default()
+		},
+		time_begin: Match::InRange(date(30), date(300)),
+		work_notes: MatchStr::Regex(r"#\d*7$".into()),
+		..Default::default()
+	}
+}
+
+fn filter(c: &mut Criterion)
+{
+	let condition = condition();
+	let timesheets = timesheets();
+	let mut group = c.benchmark_group("filter timesheets");
+
+	group.bench_function("compile once", |b| {
+		b.iter(|| {
+			let compiled = condition.compile().unwrap();
+			timesheets
+				.iter()
+				.filter(|t| compiled.is_match(black_box(t)))
+				.count()
+		})
+	});
+
+	group.bench_function("compile per record", |b| {
+		b.iter(|| {
+			timesheets
+				.iter()
+				.filter(|t| condition.compile().unwrap().is_match(black_box(t)))
+				.count()
+		})
+	});
+
+	group.bench_function("explain per record", |b| {
+		b.iter(|| {
+			timesheets
+				.iter()
+				.filter(|t| condition.explain(black_box(t)).unwrap().is_match)
+				.count()
+		})
+	});
+
+	group.finish();
+}
+
+criterion_group!(benches, filter);
+criterion_main!(benches);
@@ -0,0 +1,214 @@
+mod aggregate;
+mod group;
+
+use core::mem;
+use std::collections::BTreeMap;
+
+pub use aggregate::Aggregate;
+pub use group::Group;
+use money2::{Currency, ExchangeRates};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{query::value_at, Compile, Error, FieldPath, FieldValue, IsMatch, Orderable, Result};
+
+/// An entity matcher (e.g. [`MatchTimesheet`](crate::MatchTimesheet)), along with how the records
+/// which match it should be summarized.
+///
+/// Matching records are divided into [`Group`]s which have the same value for every field in
+/// `group_by` (or a single [`Group`] if there are none), and each [`Aggregate`] is computed over
+/// the records of every group. Fields are referred to in the same way as the
+/// [`OrderBy`](crate::OrderBy)s of a [`Query`](crate::Query), and
+/// [`Timesheet`](clinvoice_schema::Timesheet)s also have a `duration` field which is the time
+/// between their `time_begin` and `time_end`.
+///
+/// Like SQL, aggregates ignore `NULL`s.
+///
+/// # Examples
+///
+/// ```rust
+/// use clinvoice_match::{Aggregate, Aggregation, FieldValue, Group, MatchExpense};
+/// use clinvoice_schema::{Currency, Expense, Money};
+/// use money2::ExchangeRates;
+/// # use pretty_assertions::assert_eq;
+///
+/// let expense = |category: &str, cents| Expense {
+///   category: category.into(),
+///   cost: Money::new(cents, 2, Currency::Usd),
+///   ..Default::default()
+/// };
+///
+/// let aggregation = Aggregation {
+///   aggregates: vec![Aggregate::Sum("cost".parse().unwrap())],
+///   group_by: vec!["category".parse().unwrap()],
+///   ..Aggregation::new(MatchExpense::default(), Currency::Usd)
+/// };
+///
+/// let expenses = [expense("Food", 10_00), expense("Travel", 20_00), expense("Food", 5_50)];
+/// assert_eq!(aggregation.apply(&expenses, &ExchangeRates::default()).unwrap(), [
+///   Group {
+///     count: 2,
+///     key: vec!["Food".into()],
+///     values: vec![FieldValue::Money(Money::new(15_50, 2, Currency::Usd))],
+///   },
+///   Group {
+///     count: 1,
+///     key: vec!["Travel".into()],
+///     values: vec![FieldValue::Money(Money::new(20_00, 2, Currency::Usd))],
+///   },
+/// ]);
+/// ```
+///
+/// ## YAML
+///
+/// Requires the `serde` feature. If any field except the `condition` and `currency` is omitted,
+/// it will be set to the [`Default`] for its type.
+///
+/// ```rust
+/// # assert!(serde_yaml::from_str::<clinvoice_match::Aggregation<clinvoice_match::MatchTimesheet>>(r#"
+/// condition:
+///   job:
+///     client:
+///       name:
+///         contains: "Corp"
+/// group_by:
+///   - employee.id
+///   - employee.name
+/// aggregates:
+///   - sum: duration
+///   - min: time_begin
+///   - max: time_end
+/// currency: USD
+/// # "#).is_ok());
+/// ```
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Aggregation<M>
+{
+	/// What to compute for each [`Group`].
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub aggregates: Vec<Aggregate>,
+
+	/// The matcher which records must match in order to be aggregated.
+	pub condition: M,
+
+	/// The [`Currency`] which sums of [`Money`](clinvoice_schema::Money) are converted to.
+	#[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Currency"))]
+	pub currency: Currency,
+
+	/// The fields which records must have the same value for in order to be in the same [`Group`].
+	#[cfg_attr(feature = "serde", serde(default))]
+	#[cfg_attr(feature = "schemars", schemars(with = "Vec<String>"))]
+	pub group_by: Vec<FieldPath>,
+}
+
+impl<M> Aggregation<M>
+{
+	/// Create an [`Aggregation`] which counts every record that matches the `condition`, and
+	/// converts any sums of [`Money`](clinvoice_schema::Money) to the `currency`.
+	pub const fn new(condition: M, currency: Currency) -> Self
+	{
+		Self {
+			aggregates: Vec::new(),
+			condition,
+			currency,
+			group_by: Vec::new(),
+		}
+	}
+}
+
+impl<M> Aggregation<M>
+where
+	M: Orderable,
+{
+	/// Summarize the `records` which match, with one [`Group`] for each distinct value of the
+	/// `group_by` fields, ordered by that value. When there are no `group_by` fields, there is
+	/// always exactly one [`Group`], even if no records match.
+	///
+	/// The `rates` are used to convert [`Money`](clinvoice_schema::Money) to the `currency`.
+	///
+	/// # Errors
+	///
+	/// * When the `condition` cannot be [compiled](Compile).
+	/// * When the [`Aggregation`] is not [valid](Self::check).
+	/// * When the values of an [`Aggregate::Sum`] add up to more than can be represented.
+	pub fn apply(&self, records: &[M::Record], rates: &ExchangeRates) -> Result<Vec<Group>>
+	where
+		M: Compile,
+		M::Compiled: IsMatch<M::Record>,
+	{
+		self.check()?;
+		let compiled = self.condition.compile()?;
+
+		let mut groups = BTreeMap::new();
+		if self.group_by.is_empty()
+		{
+			groups.insert(Vec::new(), self.group(Vec::new()));
+		}
+
+		for record in records.iter().filter(|r| compiled.is_match(r))
+		{
+			let key = self
+				.group_by
+				.iter()
+				.map(|path| value_at::<M>(Some(record), path))
+				.collect::<Result<Vec<_>>>()?;
+
+			let group = groups
+				.entry(key)
+				.or_insert_with_key(|key| self.group(key.clone()));
+			group.count += 1;
+
+			for (aggregate, total) in self.aggregates.iter().zip(&mut group.values)
+			{
+				let value = value_at::<M>(Some(record), aggregate.field())?;
+				*total = aggregate.fold(mem::take(total), value, self.currency, rates)?;
+			}
+		}
+
+		Ok(groups.into_values().collect())
+	}
+
+	/// Check that every `group_by` field and [`Aggregate`] refers to a field which records can be
+	/// ordered by, and that every [`Aggregate::Sum`] refers to a field which can be summed.
+	///
+	/// # Errors
+	///
+	/// * When a `group_by` field or [`Aggregate`] refers to a field which does not exist, or cannot
+	///   be ordered by (e.g. the `expenses` of a [`Timesheet`](clinvoice_schema::Timesheet)).
+	/// * When an [`Aggregate::Sum`] refers to a field which is neither a
+	///   [`Duration`](core::time::Duration) nor [`Money`](clinvoice_schema::Money).
+	pub fn check(&self) -> Result<()>
+	{
+		self
+			.group_by
+			.iter()
+			.chain(self.aggregates.iter().map(Aggregate::field))
+			.try_for_each(|path| value_at::<M>(None, path).map(drop))?;
+
+		self
+			.aggregates
+			.iter()
+			.try_for_each(|aggregate| match aggregate
+			{
+				Aggregate::Sum(path) if !M::is_summable(&path.fields().collect::<Vec<_>>()) =>
+				{
+					Err(Error::NotSummable { path: path.clone() })
+				},
+				_ => Ok(()),
+			})
+	}
+
+	/// Create an empty [`Group`] for the `key`.
+	fn group(&self, key: Vec<FieldValue>) -> Group
+	{
+		Group {
+			count: 0,
+			key,
+			values: vec![FieldValue::Null; self.aggregates.len()],
+		}
+	}
+}
@@ -0,0 +1,109 @@
+use core::cmp::{max, min};
+
+use clinvoice_schema::Money;
+use money2::{Currency, Exchange, ExchangeRates};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, FieldPath, FieldValue, Result};
+
+/// A value which is computed from the records of each [`Group`](super::Group) of an
+/// [`Aggregation`](super::Aggregation).
+///
+/// # YAML
+///
+/// Requires the `serde` feature.
+///
+/// ```rust
+/// # assert!(serde_yaml::from_str::<clinvoice_match::Aggregate>(r#"
+/// sum: duration
+/// # "#).is_ok());
+/// ```
+#[cfg_attr(
+	feature = "serde",
+	derive(Deserialize, Serialize),
+	serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Aggregate
+{
+	/// The greatest value of a field.
+	Max(#[cfg_attr(feature = "schemars", schemars(with = "String"))] FieldPath),
+
+	/// The least value of a field.
+	Min(#[cfg_attr(feature = "schemars", schemars(with = "String"))] FieldPath),
+
+	/// The sum of a field which is either a [`Duration`](core::time::Duration) or [`Money`].
+	/// [`Money`] is converted to the `currency` of the [`Aggregation`](super::Aggregation) before
+	/// it is added.
+	Sum(#[cfg_attr(feature = "schemars", schemars(with = "String"))] FieldPath),
+}
+
+impl Aggregate
+{
+	/// The field which this [`Aggregate`] is computed from.
+	pub const fn field(&self) -> &FieldPath
+	{
+		match self
+		{
+			Self::Max(field) | Self::Min(field) | Self::Sum(field) => field,
+		}
+	}
+
+	/// Add the `value` of the next record to the `total` of the previous records. [`Money`] is
+	/// converted to the `currency` with the `rates` first, so that amounts in different currencies
+	/// are compared and added correctly.
+	///
+	/// # Errors
+	///
+	/// When this is a [`Sum`](Self::Sum), and the `value` cannot be summed, or the sum is too large.
+	pub(super) fn fold(
+		&self,
+		total: FieldValue,
+		value: FieldValue,
+		currency: Currency,
+		rates: &ExchangeRates,
+	) -> Result<FieldValue>
+	{
+		let value = match value
+		{
+			FieldValue::Money(m) => FieldValue::Money(m.exchange(currency, rates)),
+			value => value,
+		};
+
+		Ok(match (self, total, value)
+		{
+			(_, total, FieldValue::Null) => total,
+			(Self::Max(_), FieldValue::Null, value) |
+			(
+				Self::Sum(_),
+				FieldValue::Null,
+				value @ (FieldValue::Duration(_) | FieldValue::Money(_)),
+			) => value,
+			(Self::Max(_), total, value) => max(total, value),
+			(Self::Min(_), total, value) => min(total, value),
+			(Self::Sum(path), FieldValue::Duration(total), FieldValue::Duration(d)) =>
+			{
+				FieldValue::Duration(
+					total
+						.checked_add(d)
+						.ok_or_else(|| Error::Overflow { path: path.clone() })?,
+				)
+			},
+			(Self::Sum(path), FieldValue::Money(total), FieldValue::Money(m)) =>
+			{
+				FieldValue::Money(Money {
+					amount: total
+						.amount
+						.checked_add(m.amount)
+						.ok_or_else(|| Error::Overflow { path: path.clone() })?,
+					..total
+				})
+			},
+			(Self::Sum(path), ..) => return Err(Error::NotSummable { path: path.clone() }),
+		})
+	}
+}
@@ -0,0 +1,26 @@
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::FieldValue;
+
+/// The summary of some records which have the same value for every field that an
+/// [`Aggregation`](super::Aggregation) is grouped by.
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Group
+{
+	/// The number of records in the group.
+	pub count: usize,
+
+	/// The value of each field which the records are grouped by, in the same order as the
+	/// `group_by` of the [`Aggregation`](super::Aggregation).
+	pub key: Vec<FieldValue>,
+
+	/// The result of each [`Aggregate`](super::Aggregate), in the same order as the `aggregates`
+	/// of the [`Aggregation`](super::Aggregation). It is [`FieldValue::Null`] when every value
+	/// in the group was.
+	pub values: Vec<FieldValue>,
+}
@@ -0,0 +1,85 @@
+//! A compact binary encoding for the matchers in this crate, for when the cost of parsing a
+//! human-readable format (e.g. YAML) is too high, such as when caching filters across processes.
+//!
+//! Encoded matchers begin with a header which contains the [`FORMAT_VERSION`], so that bytes which
+//! were encoded by a version of this crate with a different representation are rejected, rather than
+//! being decoded incorrectly.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use clinvoice_match::{binary, Error, Match, MatchEmployee, MatchStr};
+//! # use pretty_assertions::assert_eq;
+//!
+//! let condition = MatchEmployee {
+//!   id: Match::Or(vec![1.into(), 2.into()]),
+//!   name: MatchStr::Regex("^[ABC]".into()),
+//!   ..Default::default()
+//! };
+//!
+//! let mut bytes = binary::to_bytes(&condition).unwrap();
+//! assert_eq!(binary::from_bytes::<MatchEmployee>(&bytes).unwrap(), condition);
+//!
+//! // pretend that the bytes were encoded by a future version of the crate
+//! bytes[4] += 1;
+//! assert!(matches!(
+//!   binary::from_bytes::<MatchEmployee>(&bytes),
+//!   Err(Error::BinaryVersion { expected: binary::FORMAT_VERSION, .. })
+//! ));
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Result};
+
+/// The version of the binary representation of the matchers in this crate.
+///
+/// This is incremented whenever the representation of any matcher changes.
+pub const FORMAT_VERSION: u16 = 2;
+
+/// The bytes which every encoded matcher begins with.
+const MAGIC: &[u8] = b"CLVM";
+
+/// Decode a matcher from `bytes` which were created by [`to_bytes`].
+///
+/// # Errors
+///
+/// * When the `bytes` do not begin with a valid header.
+/// * When the `bytes` were encoded with a different [`FORMAT_VERSION`].
+/// * When the `bytes` do not contain a valid `T`.
+pub fn from_bytes<'de, T>(bytes: &'de [u8]) -> Result<T>
+where
+	T: Deserialize<'de>,
+{
+	let rest = bytes.strip_prefix(MAGIC).ok_or(Error::BinaryHeader)?;
+	let (version, rest) = match rest
+	{
+		[low, high, rest @ ..] => (u16::from_le_bytes([*low, *high]), rest),
+		_ => return Err(Error::BinaryHeader),
+	};
+
+	if version != FORMAT_VERSION
+	{
+		return Err(Error::BinaryVersion {
+			expected: FORMAT_VERSION,
+			found: version,
+		});
+	}
+
+	postcard::from_bytes(rest).map_err(Error::from)
+}
+
+/// Encode a matcher `value` into bytes which can be decoded by [`from_bytes`].
+///
+/// # Errors
+///
+/// * When the `value` cannot be encoded.
+pub fn to_bytes<T>(value: &T) -> Result<Vec<u8>>
+where
+	T: Serialize + ?Sized,
+{
+	let mut bytes = MAGIC.to_vec();
+	bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+	bytes.extend(postcard::to_allocvec(value)?);
+	Ok(bytes)
+}
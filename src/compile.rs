@@ -0,0 +1,51 @@
+use crate::Result;
+
+/// A matcher which can be compiled into a form which is faster to evaluate many times.
+///
+/// Compiling a matcher:
+///
+/// * builds the [`Regex`](regex::Regex) of each [`MatchStr::Regex`](crate::MatchStr::Regex)
+///   ahead of time, and
+/// * turns each [`Or`](crate::Match::Or) which only contains [`EqualTo`](crate::Match::EqualTo)
+///   conditions into a sorted set which can be binary searched.
+///
+/// The result is immutable and can be shared across threads, so the cost of compilation only needs
+/// to be paid once.
+///
+/// # Examples
+///
+/// ```rust
+/// use clinvoice_match::{Compile, IsMatch, MatchEmployee, MatchStr};
+/// use clinvoice_schema::Employee;
+///
+/// let condition = MatchEmployee {
+///   name: MatchStr::Regex("^[ABC]".into()),
+///   status: MatchStr::Or(vec!["Hired".to_owned().into(), "Promoted".to_owned().into()]),
+///   ..Default::default()
+/// };
+///
+/// let compiled = condition.compile().unwrap();
+/// std::thread::spawn(move || {
+///   assert!(compiled.is_match(&Employee {
+///     id: 0,
+///     name: "Bob".into(),
+///     status: "Promoted".into(),
+///     title: "CEO".into(),
+///   }));
+/// })
+/// .join()
+/// .unwrap();
+/// ```
+pub trait Compile
+{
+	/// The compiled form of this matcher.
+	type Compiled;
+
+	/// Compile this matcher.
+	///
+	/// # Errors
+	///
+	/// * When any [`MatchStr::Regex`](crate::MatchStr::Regex) contains an invalid regular
+	///   expression.
+	fn compile(&self) -> Result<Self::Compiled>;
+}
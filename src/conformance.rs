@@ -0,0 +1,127 @@
+//! A conformance suite for storage adapters, which checks that they select the same records as
+//! the in-memory evaluator (i.e. [`IsMatch`](crate::IsMatch)).
+//!
+//! Every adapter interprets the matchers of this crate independently, so they can disagree about
+//! details such as whether [`Match::InRange`](crate::Match::InRange) includes its upper bound. The
+//! suite stores the [`Dataset::fixture`] with an [`Adapter`], runs each [`Case`] in the
+//! [`Case::catalog`], and reports every [`Mismatch`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use clinvoice_match::{
+//!   conformance::{self, Adapter, AdapterResult, Dataset},
+//!   Compile,
+//!   IsMatch,
+//!   MatchEmployee,
+//!   MatchExpense,
+//!   MatchJob,
+//!   MatchTimesheet,
+//! };
+//! use clinvoice_schema::Id;
+//!
+//! /// An adapter which forgets the expenses it is given.
+//! #[derive(Default)]
+//! struct Forgetful(Dataset);
+//!
+//! impl Adapter for Forgetful
+//! {
+//!   fn insert(&mut self, dataset: &Dataset) -> AdapterResult<()>
+//!   {
+//!     self.0 = Dataset { expenses: Vec::new(), ..dataset.clone() };
+//!     Ok(())
+//!   }
+//!
+//!   fn retrieve_employees(&mut self, condition: &MatchEmployee) -> AdapterResult<Vec<Id>>
+//!   {
+//!     let compiled = condition.compile()?;
+//!     Ok(self.0.employees.iter().filter(|e| compiled.is_match(e)).map(|e| e.id).collect())
+//!   }
+//!
+//!   fn retrieve_expenses(&mut self, condition: &MatchExpense) -> AdapterResult<Vec<Id>>
+//!   {
+//!     let compiled = condition.compile()?;
+//!     Ok(self.0.expenses.iter().filter(|e| compiled.is_match(e)).map(|e| e.id).collect())
+//!   }
+//!
+//!   fn retrieve_jobs(&mut self, condition: &MatchJob) -> AdapterResult<Vec<Id>>
+//!   {
+//!     let compiled = condition.compile()?;
+//!     Ok(self.0.jobs.iter().filter(|j| compiled.is_match(j)).map(|j| j.id).collect())
+//!   }
+//!
+//!   fn retrieve_timesheets(&mut self, condition: &MatchTimesheet) -> AdapterResult<Vec<Id>>
+//!   {
+//!     let compiled = condition.compile()?;
+//!     Ok(self.0.timesheets.iter().filter(|t| compiled.is_match(t)).map(|t| t.id).collect())
+//!   }
+//! }
+//!
+//! let mismatches = conformance::run(&mut Forgetful::default()).unwrap();
+//! assert!(!mismatches.is_empty());
+//! assert!(mismatches.iter().all(|m| m.actual.is_empty()));
+//! ```
+
+mod adapter;
+mod case;
+mod dataset;
+mod mismatch;
+mod query;
+
+pub use adapter::{Adapter, AdapterResult};
+pub use case::Case;
+pub use dataset::Dataset;
+pub use mismatch::Mismatch;
+pub use query::Query;
+
+use crate::{Error, Result};
+
+/// Store the [`Dataset::fixture`] with the `adapter`, and run every [`Case`] in the
+/// [`Case::catalog`] against it.
+///
+/// # Errors
+///
+/// When the `adapter` fails.
+pub fn run(adapter: &mut dyn Adapter) -> Result<Vec<Mismatch>>
+{
+	run_with(adapter, &Dataset::fixture(), Case::catalog())
+}
+
+/// Store the `dataset` with the `adapter`, and run each of the `cases` against it.
+///
+/// The records which the `adapter` retrieves for a [`Case`] are a [`Mismatch`] if they are not
+/// exactly those which the in-memory evaluator matches, including when a record is retrieved more
+/// than once.
+///
+/// # Errors
+///
+/// * When the `adapter` fails.
+/// * When the matcher of one of the `cases` cannot be [compiled](crate::Compile).
+pub fn run_with(
+	adapter: &mut dyn Adapter,
+	dataset: &Dataset,
+	cases: impl IntoIterator<Item = Case>,
+) -> Result<Vec<Mismatch>>
+{
+	adapter.insert(dataset).map_err(Error::Adapter)?;
+
+	let mut mismatches = Vec::new();
+	for case in cases
+	{
+		let mut expected = case.query.expected(dataset)?;
+		let mut actual = case.query.retrieve(adapter).map_err(Error::Adapter)?;
+		expected.sort_unstable();
+		actual.sort_unstable();
+
+		if actual != expected
+		{
+			mismatches.push(Mismatch {
+				actual,
+				case,
+				expected,
+			});
+		}
+	}
+
+	Ok(mismatches)
+}
@@ -0,0 +1,55 @@
+use core::error::Error;
+
+use clinvoice_schema::Id;
+
+use super::Dataset;
+use crate::{MatchEmployee, MatchExpense, MatchJob, MatchTimesheet};
+
+/// The result of an operation on an [`Adapter`].
+pub type AdapterResult<T> = Result<T, Box<dyn Error + Send + Sync>>;
+
+/// A storage adapter (e.g. one which translates matchers into SQL), which is checked for
+/// [conformance](super::run) with the in-memory evaluator.
+///
+/// Adapters which are asynchronous should block on each operation.
+pub trait Adapter
+{
+	/// Store every record in the `dataset`, keeping their `id`s.
+	///
+	/// # Errors
+	///
+	/// When the records cannot be stored.
+	fn insert(&mut self, dataset: &Dataset) -> AdapterResult<()>;
+
+	/// Get the `id` of every stored [`Employee`](clinvoice_schema::Employee) which matches the
+	/// `condition`, in any order.
+	///
+	/// # Errors
+	///
+	/// When the records cannot be retrieved.
+	fn retrieve_employees(&mut self, condition: &MatchEmployee) -> AdapterResult<Vec<Id>>;
+
+	/// Get the `id` of every stored [`Expense`](clinvoice_schema::Expense) which matches the
+	/// `condition`, in any order.
+	///
+	/// # Errors
+	///
+	/// When the records cannot be retrieved.
+	fn retrieve_expenses(&mut self, condition: &MatchExpense) -> AdapterResult<Vec<Id>>;
+
+	/// Get the `id` of every stored [`Job`](clinvoice_schema::Job) which matches the `condition`, in
+	/// any order.
+	///
+	/// # Errors
+	///
+	/// When the records cannot be retrieved.
+	fn retrieve_jobs(&mut self, condition: &MatchJob) -> AdapterResult<Vec<Id>>;
+
+	/// Get the `id` of every stored [`Timesheet`](clinvoice_schema::Timesheet) which matches the
+	/// `condition`, in any order.
+	///
+	/// # Errors
+	///
+	/// When the records cannot be retrieved.
+	fn retrieve_timesheets(&mut self, condition: &MatchTimesheet) -> AdapterResult<Vec<Id>>;
+}
@@ -0,0 +1,343 @@
+use core::time::Duration;
+
+use clinvoice_schema::{
+	chrono::{NaiveDate, NaiveDateTime},
+	Currency,
+	Money,
+};
+
+use super::Query;
+use crate::{
+	Match,
+	MatchEmployee,
+	MatchExpense,
+	MatchInvoice,
+	MatchJob,
+	MatchLocation,
+	MatchOption,
+	MatchOrganization,
+	MatchOuterLocation,
+	MatchSet,
+	MatchStr,
+	MatchTimesheet,
+};
+
+/// A [`Query`] which an [`Adapter`](super::Adapter) must answer in the same way as the in-memory
+/// evaluator.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Case
+{
+	/// A description of what the [`Case`] checks.
+	pub name: &'static str,
+
+	/// The matcher to run.
+	pub query: Query,
+}
+
+/// Midnight on some day.
+fn date(year: i32, month: u32, day: u32) -> NaiveDateTime
+{
+	NaiveDate::from_ymd_opt(year, month, day)
+		.and_then(|d| d.and_hms_opt(0, 0, 0))
+		.expect("catalog dates are valid")
+}
+
+/// A [`Case`] which retrieves [`Employee`](clinvoice_schema::Employee)s.
+const fn employee(name: &'static str, condition: MatchEmployee) -> Case
+{
+	Case {
+		name,
+		query: Query::Employee(condition),
+	}
+}
+
+/// A [`Case`] which retrieves [`Expense`](clinvoice_schema::Expense)s.
+const fn expense(name: &'static str, condition: MatchExpense) -> Case
+{
+	Case {
+		name,
+		query: Query::Expense(condition),
+	}
+}
+
+/// A [`Case`] which retrieves [`Job`](clinvoice_schema::Job)s.
+fn job(name: &'static str, condition: MatchJob) -> Case
+{
+	Case {
+		name,
+		query: Query::Job(condition.into()),
+	}
+}
+
+/// A [`Case`] which retrieves [`Timesheet`](clinvoice_schema::Timesheet)s.
+fn timesheet(name: &'static str, condition: MatchTimesheet) -> Case
+{
+	Case {
+		name,
+		query: Query::Timesheet(condition.into()),
+	}
+}
+
+/// An amount of [`Money`] in dollars, given in cents.
+fn usd(cents: i64) -> Money
+{
+	Money::new(cents, 2, Currency::Usd)
+}
+
+impl Case
+{
+	/// The [`Case`]s which are run against the [`Dataset::fixture`](super::Dataset::fixture).
+	///
+	/// Each covers a behavior which adapters have implemented differently from the in-memory
+	/// evaluator, such as:
+	///
+	/// * [`Match::InRange`] including its lower bound, but not its upper bound.
+	/// * [`MatchStr::Contains`] and [`MatchStr::EqualTo`] being case-sensitive, and treating `%`,
+	///   `_`, and `\` literally.
+	/// * [`MatchOption::Not`] matching `NULL`s (e.g. `not: {equal_to: x}` matches `NULL`).
+	/// * [`MatchSet`] matching each record once, no matter how many of its values match.
+	pub fn catalog() -> Vec<Self>
+	{
+		vec![
+			employee("any employee", MatchEmployee::default()),
+			employee("empty `and` matches everything", MatchEmployee {
+				name: MatchStr::And(Vec::new()),
+				..Default::default()
+			}),
+			employee("empty `or` matches nothing", MatchEmployee {
+				name: MatchStr::Or(Vec::new()),
+				..Default::default()
+			}),
+			employee("`equal_to` is case-sensitive", MatchEmployee {
+				name: "Alice".to_owned().into(),
+				..Default::default()
+			}),
+			employee("`contains` is case-sensitive", MatchEmployee {
+				title: MatchStr::Contains("CEO".into()),
+				..Default::default()
+			}),
+			employee("`equal_to` an empty string", MatchEmployee {
+				name: String::new().into(),
+				..Default::default()
+			}),
+			employee("`not` `equal_to`", MatchEmployee {
+				title: MatchStr::Not(MatchStr::from("CEO".to_owned()).into()),
+				..Default::default()
+			}),
+			employee("`regex` is case-sensitive", MatchEmployee {
+				name: MatchStr::Regex("^[a-z]".into()),
+				..Default::default()
+			}),
+			employee("`regex` with non-ASCII characters", MatchEmployee {
+				name: MatchStr::Regex("ë$".into()),
+				..Default::default()
+			}),
+			employee("`in_range` includes only its lower bound", MatchEmployee {
+				id: Match::InRange(2, 4),
+				..Default::default()
+			}),
+			employee("`or` of values", MatchEmployee {
+				status: MatchStr::Or(vec!["Fired".to_owned().into(), "Retired".to_owned().into()]),
+				..Default::default()
+			}),
+			employee("`not` `or`", MatchEmployee {
+				id: Match::Not(Match::Or(vec![Match::EqualTo(1), Match::GreaterThan(3)]).into()),
+				..Default::default()
+			}),
+			expense("`in_range` of money", MatchExpense {
+				cost: Match::InRange(usd(1250), usd(10000)),
+				..Default::default()
+			}),
+			expense("`less_than` money", MatchExpense {
+				cost: Match::LessThan(usd(2000)),
+				..Default::default()
+			}),
+			expense("`contains` a percent sign", MatchExpense {
+				description: MatchStr::Contains("%".into()),
+				..Default::default()
+			}),
+			expense("`contains` an underscore", MatchExpense {
+				description: MatchStr::Contains("_".into()),
+				..Default::default()
+			}),
+			expense(
+				"`equal_to` is case-sensitive for categories",
+				MatchExpense {
+					category: "Travel".to_owned().into(),
+					..Default::default()
+				},
+			),
+			job("`none` matches `NULL`", MatchJob {
+				date_close: MatchOption::None,
+				..Default::default()
+			}),
+			job("`not` `none`", MatchJob {
+				date_close: MatchOption::Not(MatchOption::None.into()),
+				..Default::default()
+			}),
+			job("`not` `equal_to` matches `NULL`", MatchJob {
+				date_close: MatchOption::Not(MatchOption::EqualTo(date(2022, 7, 1)).into()),
+				..Default::default()
+			}),
+			job("`less_than` does not match `NULL`", MatchJob {
+				date_close: MatchOption::LessThan(date(2023, 1, 1)),
+				..Default::default()
+			}),
+			job("`in_range` of dates", MatchJob {
+				date_open: Match::InRange(date(2022, 1, 1), date(2023, 1, 1)),
+				..Default::default()
+			}),
+			job("`greater_than` a duration", MatchJob {
+				increment: Match::GreaterThan(Duration::from_secs(5 * 60).into()),
+				..Default::default()
+			}),
+			job("`equal_to` a duration", MatchJob {
+				increment: Match::EqualTo(Duration::from_secs(15 * 60).into()),
+				..Default::default()
+			}),
+			job("unpaid invoices", MatchJob {
+				invoice: MatchInvoice {
+					date_paid: MatchOption::None,
+					..Default::default()
+				},
+				..Default::default()
+			}),
+			job("issued invoices", MatchJob {
+				invoice: MatchInvoice {
+					date_issued: MatchOption::Not(MatchOption::None.into()),
+					..Default::default()
+				},
+				..Default::default()
+			}),
+			job("`greater_than` an hourly rate", MatchJob {
+				invoice: MatchInvoice {
+					hourly_rate: Match::GreaterThan(usd(2000)),
+					..Default::default()
+				},
+				..Default::default()
+			}),
+			job("`contains` a backslash", MatchJob {
+				client: MatchOrganization {
+					name: MatchStr::Contains(r"\".into()),
+					..Default::default()
+				},
+				..Default::default()
+			}),
+			job("`contains` is case-sensitive for objectives", MatchJob {
+				objectives: MatchStr::Contains("bug".into()),
+				..Default::default()
+			}),
+			job("`regex` anchors do not match at line breaks", MatchJob {
+				notes: MatchStr::Regex("^Line 2$".into()),
+				..Default::default()
+			}),
+			job("outer location", MatchJob {
+				client: MatchOrganization {
+					location: MatchLocation {
+						outer: MatchOuterLocation::Some(
+							MatchLocation {
+								name: "Europe".to_owned().into(),
+								..Default::default()
+							}
+							.into(),
+						),
+						..Default::default()
+					},
+					..Default::default()
+				},
+				..Default::default()
+			}),
+			job("no outer location", MatchJob {
+				client: MatchOrganization {
+					location: MatchLocation {
+						outer: MatchOuterLocation::None,
+						..Default::default()
+					},
+					..Default::default()
+				},
+				..Default::default()
+			}),
+			job("non-ASCII location", MatchJob {
+				client: MatchOrganization {
+					location: MatchLocation {
+						name: MatchStr::Contains("Île".into()),
+						..Default::default()
+					},
+					..Default::default()
+				},
+				..Default::default()
+			}),
+			timesheet("set `contains`", MatchTimesheet {
+				expenses: MatchSet::Contains(MatchExpense {
+					category: "Travel".to_owned().into(),
+					..Default::default()
+				}),
+				..Default::default()
+			}),
+			timesheet("set `contains` matches each record once", MatchTimesheet {
+				expenses: MatchSet::Contains(MatchExpense::default()),
+				..Default::default()
+			}),
+			timesheet("empty set", MatchTimesheet {
+				expenses: MatchSet::Not(MatchSet::Contains(MatchExpense::default()).into()),
+				..Default::default()
+			}),
+			timesheet("set `and`", MatchTimesheet {
+				expenses: MatchSet::And(vec![
+					MatchSet::Contains(MatchExpense {
+						cost: Match::GreaterThan(usd(5000)),
+						..Default::default()
+					}),
+					MatchSet::Contains(MatchExpense {
+						category: "Food".to_owned().into(),
+						..Default::default()
+					}),
+				]),
+				..Default::default()
+			}),
+			timesheet("no end", MatchTimesheet {
+				time_end: MatchOption::None,
+				..Default::default()
+			}),
+			timesheet("`not` `greater_than` matches `NULL`", MatchTimesheet {
+				time_end: MatchOption::Not(MatchOption::GreaterThan(date(2022, 6, 1)).into()),
+				..Default::default()
+			}),
+			timesheet("duration `greater_than`", MatchTimesheet {
+				duration: MatchOption::GreaterThan(Duration::from_secs(60 * 60).into()),
+				..Default::default()
+			}),
+			timesheet("duration `in_range`", MatchTimesheet {
+				duration: MatchOption::InRange(
+					Duration::from_secs(5 * 60).into(),
+					Duration::from_secs(60 * 60).into(),
+				),
+				..Default::default()
+			}),
+			timesheet("`not` duration matches `NULL`", MatchTimesheet {
+				duration: MatchOption::Not(
+					MatchOption::EqualTo(Duration::from_secs(60 * 60).into()).into(),
+				),
+				..Default::default()
+			}),
+			timesheet("nested fields", MatchTimesheet {
+				employee: MatchEmployee {
+					name: MatchStr::Regex("^A".into()),
+					..Default::default()
+				},
+				job: MatchJob {
+					date_close: MatchOption::None,
+					..Default::default()
+				},
+				..Default::default()
+			}),
+			timesheet(
+				"`contains` is case-sensitive for work notes",
+				MatchTimesheet {
+					work_notes: MatchStr::Contains("fix".into()),
+					..Default::default()
+				},
+			),
+		]
+	}
+}
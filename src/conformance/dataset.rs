@@ -0,0 +1,220 @@
+use core::time::Duration;
+
+use clinvoice_schema::{
+	chrono::{NaiveDate, NaiveDateTime},
+	Currency,
+	Employee,
+	Expense,
+	Invoice,
+	InvoiceDate,
+	Job,
+	Location,
+	Money,
+	Organization,
+	Timesheet,
+};
+
+/// Records which an [`Adapter`](super::Adapter) stores before the [`Case`](super::Case)s are run.
+///
+/// Every record refers only to other records in the [`Dataset`] (e.g. each [`Timesheet`]'s
+/// `employee` is also in the `employees`, and its `expenses` are exactly those in the `expenses`
+/// whose `timesheet_id` is its `id`).
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Dataset
+{
+	/// The [`Employee`]s.
+	pub employees: Vec<Employee>,
+
+	/// The [`Expense`]s.
+	pub expenses: Vec<Expense>,
+
+	/// The [`Job`]s.
+	pub jobs: Vec<Job>,
+
+	/// The [`Timesheet`]s.
+	pub timesheets: Vec<Timesheet>,
+}
+
+/// A [`NaiveDateTime`] from its parts.
+fn date(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime
+{
+	NaiveDate::from_ymd_opt(year, month, day)
+		.and_then(|d| d.and_hms_opt(hour, minute, 0))
+		.expect("fixture dates are valid")
+}
+
+/// A [`Location`] and those it is inside of.
+fn location(id: i64, name: &str, outer: Option<&Location>) -> Location
+{
+	Location {
+		id,
+		name: name.into(),
+		outer: outer.cloned().map(Box::new),
+	}
+}
+
+/// An amount of [`Money`] in dollars, given in cents.
+fn usd(cents: i64) -> Money
+{
+	Money::new(cents, 2, Currency::Usd)
+}
+
+impl Dataset
+{
+	/// The records which adapters have disagreed with the in-memory evaluator about.
+	///
+	/// They include values at the edges of ranges, strings which differ only by case or contain
+	/// characters which are special to SQL `LIKE` (e.g. `%` and `_`), non-ASCII strings, empty
+	/// strings, and `NULL`s.
+	pub fn fixture() -> Self
+	{
+		let earth = location(1, "Earth", None);
+		let europe = location(2, "Europe", Some(&earth));
+		let paris = location(3, "Île-de-France", Some(&europe));
+		let america = location(4, "America", Some(&earth));
+		let phoenix = location(5, "Phoenix, AZ", Some(&america));
+
+		let big_old_test = Organization {
+			id: 1,
+			location: phoenix,
+			name: "Big Old Test Corporation".into(),
+		};
+		let sale = Organization {
+			id: 2,
+			location: paris,
+			name: r"50% Off_Sale \ Co.".into(),
+		};
+
+		let employee = |id, name: &str, status: &str, title: &str| Employee {
+			id,
+			name: name.into(),
+			status: status.into(),
+			title: title.into(),
+		};
+		let employees = vec![
+			employee(1, "Alice", "Hired", "CEO"),
+			employee(2, "alice", "Hired", "Intern"),
+			employee(3, "Bob", "Fired", "ceo"),
+			employee(4, "", "Hired", "Engineer"),
+			employee(5, "Zoë", "Retired", "Senior Engineer"),
+		];
+
+		let jobs = vec![
+			Job {
+				client: big_old_test.clone(),
+				date_close: None,
+				date_open: date(2022, 1, 1, 0, 0),
+				id: 1,
+				increment: Duration::from_secs(15 * 60),
+				invoice: Invoice {
+					date: None,
+					hourly_rate: usd(2000),
+				},
+				notes: String::new(),
+				objectives: "Fix the bug".into(),
+			},
+			Job {
+				client: sale,
+				date_close: Some(date(2022, 7, 1, 0, 0)),
+				date_open: date(2022, 6, 1, 0, 0),
+				id: 2,
+				increment: Duration::from_secs(60 * 60),
+				invoice: Invoice {
+					date: Some(InvoiceDate {
+						issued: date(2022, 7, 1, 0, 0),
+						paid: None,
+					}),
+					hourly_rate: usd(1000),
+				},
+				notes: "100% done".into(),
+				objectives: "Write_Docs".into(),
+			},
+			Job {
+				client: big_old_test,
+				date_close: Some(date(2023, 1, 1, 0, 0)),
+				date_open: date(2023, 1, 1, 0, 0),
+				id: 3,
+				increment: Duration::from_secs(5 * 60),
+				invoice: Invoice {
+					date: Some(InvoiceDate {
+						issued: date(2023, 1, 2, 0, 0),
+						paid: Some(date(2023, 2, 1, 0, 0)),
+					}),
+					hourly_rate: usd(2001),
+				},
+				notes: "Line 1\nLine 2".into(),
+				objectives: "FIX THE BUG".into(),
+			},
+		];
+
+		let expense = |id, timesheet_id, category: &str, cents, description: &str| Expense {
+			category: category.into(),
+			cost: usd(cents),
+			description: description.into(),
+			id,
+			timesheet_id,
+		};
+		let expenses = vec![
+			expense(1, 1, "Travel", 10000, "Train"),
+			expense(2, 1, "Food", 1250, "Lunch"),
+			expense(3, 3, "travel", 2000, "Taxi 10% tip"),
+			expense(4, 4, "Travel", 10000, "Flight_1"),
+		];
+
+		let timesheet =
+			|id, employee: &Employee, job: &Job, time_begin, time_end, work_notes: &str| Timesheet {
+				employee: employee.clone(),
+				expenses: expenses
+					.iter()
+					.filter(|e| e.timesheet_id == id)
+					.cloned()
+					.collect(),
+				id,
+				job: job.clone(),
+				time_begin,
+				time_end,
+				work_notes: work_notes.into(),
+			};
+		let timesheets = vec![
+			timesheet(
+				1,
+				&employees[0],
+				&jobs[0],
+				date(2022, 1, 1, 9, 0),
+				Some(date(2022, 1, 1, 17, 0)),
+				"Fixed",
+			),
+			timesheet(
+				2,
+				&employees[1],
+				&jobs[0],
+				date(2022, 1, 2, 9, 0),
+				None,
+				"fixed the bug",
+			),
+			timesheet(
+				3,
+				&employees[2],
+				&jobs[1],
+				date(2022, 6, 1, 0, 0),
+				Some(date(2022, 6, 1, 1, 0)),
+				"",
+			),
+			timesheet(
+				4,
+				&employees[4],
+				&jobs[2],
+				date(2023, 1, 1, 12, 0),
+				Some(date(2023, 1, 1, 12, 5)),
+				"Zoë's notes",
+			),
+		];
+
+		Self {
+			employees,
+			expenses,
+			jobs,
+			timesheets,
+		}
+	}
+}
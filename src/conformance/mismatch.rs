@@ -0,0 +1,20 @@
+mod display;
+
+use clinvoice_schema::Id;
+
+use super::Case;
+
+/// A [`Case`] which an [`Adapter`](super::Adapter) answered differently from the in-memory
+/// evaluator.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mismatch
+{
+	/// The [`Id`]s which the [`Adapter`](super::Adapter) retrieved, in ascending order.
+	pub actual: Vec<Id>,
+
+	/// The [`Case`] which was run.
+	pub case: Case,
+
+	/// The [`Id`]s which the in-memory evaluator matched, in ascending order.
+	pub expected: Vec<Id>,
+}
@@ -0,0 +1,15 @@
+use core::fmt::{Display, Formatter, Result};
+
+use super::Mismatch;
+
+impl Display for Mismatch
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result
+	{
+		write!(
+			f,
+			"{}: expected {:?}, but the adapter retrieved {:?} for {:?}",
+			self.case.name, self.expected, self.actual, self.case.query
+		)
+	}
+}
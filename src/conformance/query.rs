@@ -0,0 +1,66 @@
+use clinvoice_schema::Id;
+
+use super::{Adapter, AdapterResult, Dataset};
+use crate::{Compile, IsMatch, MatchEmployee, MatchExpense, MatchJob, MatchTimesheet, Result};
+
+/// A matcher for one kind of record in a [`Dataset`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Query
+{
+	/// Retrieve the [`Employee`](clinvoice_schema::Employee)s which match.
+	Employee(MatchEmployee),
+
+	/// Retrieve the [`Expense`](clinvoice_schema::Expense)s which match.
+	Expense(MatchExpense),
+
+	/// Retrieve the [`Job`](clinvoice_schema::Job)s which match.
+	Job(Box<MatchJob>),
+
+	/// Retrieve the [`Timesheet`](clinvoice_schema::Timesheet)s which match.
+	Timesheet(Box<MatchTimesheet>),
+}
+
+/// The `id`s of the `records` which match the `condition`.
+fn ids<M, R>(condition: &M, records: &[R], id: fn(&R) -> Id) -> Result<Vec<Id>>
+where
+	M: Compile,
+	M::Compiled: IsMatch<R>,
+{
+	let compiled = condition.compile()?;
+	Ok(records
+		.iter()
+		.filter(|r| compiled.is_match(r))
+		.map(id)
+		.collect())
+}
+
+impl Query
+{
+	/// The `id`s of the records in the `dataset` which match, according to [`IsMatch`].
+	///
+	/// # Errors
+	///
+	/// When the matcher cannot be [compiled](Compile).
+	pub fn expected(&self, dataset: &Dataset) -> Result<Vec<Id>>
+	{
+		match self
+		{
+			Self::Employee(condition) => ids(condition, &dataset.employees, |e| e.id),
+			Self::Expense(condition) => ids(condition, &dataset.expenses, |e| e.id),
+			Self::Job(condition) => ids(condition.as_ref(), &dataset.jobs, |j| j.id),
+			Self::Timesheet(condition) => ids(condition.as_ref(), &dataset.timesheets, |t| t.id),
+		}
+	}
+
+	/// The `id`s of the records which match, according to the `adapter`.
+	pub(super) fn retrieve(&self, adapter: &mut dyn Adapter) -> AdapterResult<Vec<Id>>
+	{
+		match self
+		{
+			Self::Employee(condition) => adapter.retrieve_employees(condition),
+			Self::Expense(condition) => adapter.retrieve_expenses(condition),
+			Self::Job(condition) => adapter.retrieve_jobs(condition),
+			Self::Timesheet(condition) => adapter.retrieve_timesheets(condition),
+		}
+	}
+}
@@ -0,0 +1,181 @@
+mod deserialize;
+mod library;
+mod migrations;
+
+pub use library::Library;
+pub use migrations::{Migration, Migrations};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_yaml::Value;
+
+use crate::{Limits, Result};
+
+/// The current version of a [`Document`].
+///
+/// The history of the document format is as follows:
+///
+/// 1. The `employee` of a [`MatchTimesheet`](crate::MatchTimesheet) was a
+///    [`MatchStr`](crate::MatchStr) of the [`Employee`](clinvoice_schema::Employee)'s name, and its
+///    `work_notes` were called `notes`.
+/// 2. The current format.
+pub const DOCUMENT_VERSION: u32 = 2;
+
+/// A saved filter of type `M` (e.g. a [`MatchTimesheet`](crate::MatchTimesheet)), along with the
+/// [version](DOCUMENT_VERSION) of the format it was written in.
+///
+/// A document may also define named `fragments` which its `filter` uses (see [`Library`]). These
+/// are resolved when the document is read, so they are not part of a [`Document`].
+///
+/// When a [`Document`] is deserialized, the `filter` is upgraded to the current format using the
+/// default [`Migrations`], and checked against the default [`Limits`]. Use [`Document::from_value`]
+/// to provide other [`Migrations`] or [`Limits`], or [`Document::from_value_with_library`] to
+/// provide fragments which are shared between documents.
+///
+/// # Examples
+///
+/// ```rust
+/// use clinvoice_match::{Document, MatchEmployee, MatchTimesheet, DOCUMENT_VERSION};
+/// # use pretty_assertions::assert_eq;
+///
+/// let document: Document<MatchTimesheet> = serde_yaml::from_str(r#"
+///   filter:
+///     employee:
+///       equal_to: "Bob"
+///     notes:
+///       contains: "bug"
+///   version: 1
+/// "#).unwrap();
+///
+/// assert_eq!(document, Document::new(MatchTimesheet {
+///   employee: MatchEmployee { name: "Bob".to_owned().into(), ..Default::default() },
+///   work_notes: clinvoice_match::MatchStr::Contains("bug".into()),
+///   ..Default::default()
+/// }));
+/// assert_eq!(document.version, DOCUMENT_VERSION);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Document<M>
+{
+	#[allow(missing_docs)]
+	pub filter: M,
+
+	/// The [version](DOCUMENT_VERSION) of the format that the `filter` is written in.
+	pub version: u32,
+}
+
+/// A [`Document`] whose `filter` has not yet been resolved or migrated.
+#[derive(Deserialize)]
+struct Raw
+{
+	filter: Value,
+	#[serde(default)]
+	fragments: Library,
+	version: u32,
+}
+
+impl<M> Document<M>
+{
+	/// Create a [`Document`] for a `filter` which is written in the current
+	/// [version](DOCUMENT_VERSION).
+	pub const fn new(filter: M) -> Self
+	{
+		Self {
+			filter,
+			version: DOCUMENT_VERSION,
+		}
+	}
+
+	/// Read a [`Document`] from an untyped `value`, upgrading its `filter` to the current
+	/// [version](DOCUMENT_VERSION) with the `migrations`, and then checking that it is within the
+	/// `limits`.
+	///
+	/// # Errors
+	///
+	/// * When the `value` is not a document.
+	/// * When the document was written in a newer version than [`DOCUMENT_VERSION`].
+	/// * When one of the `migrations` fails.
+	/// * When the migrated `filter` is not within the `limits` (see [`Limits::check`]).
+	/// * When the migrated `filter` is not a valid `M`.
+	/// * When the `filter` uses its `fragments` incorrectly (see [`Library::expand`]).
+	pub fn from_value(value: Value, migrations: &Migrations, limits: &Limits) -> Result<Self>
+	where
+		M: DeserializeOwned + 'static,
+	{
+		Self::from_value_with_library(value, &Library::new(), migrations, limits)
+	}
+
+	/// Read a [`Document`] from an untyped `value`, resolving the fragments which its `filter` uses
+	/// with the `library` and then upgrading it to the current [version](DOCUMENT_VERSION) with the
+	/// `migrations`.
+	///
+	/// The `fragments` of the document take precedence over those of the `library` with the same
+	/// name. Fragments must be written in the same version as the document which uses them. The
+	/// `limits` apply to the `filter` once its fragments have been resolved.
+	///
+	/// # Errors
+	///
+	/// * See [`Document::from_value`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use clinvoice_match::{Document, Library, Limits, MatchJob, MatchStr, Migrations};
+	/// # use pretty_assertions::assert_eq;
+	///
+	/// let mut library = Library::new();
+	/// library.insert("eu", MatchStr::EqualTo("EU".to_owned())).unwrap();
+	///
+	/// let value = serde_yaml::from_str(r#"
+	///   filter:
+	///     client:
+	///       $ref: eu_clients
+	///   fragments:
+	///     eu_clients:
+	///       location:
+	///         outer:
+	///           name:
+	///             $ref: eu
+	///   version: 2
+	/// "#).unwrap();
+	///
+	/// let document = Document::<MatchJob>::from_value_with_library(
+	///   value,
+	///   &library,
+	///   &Migrations::default(),
+	///   &Limits::default(),
+	/// ).unwrap();
+	///
+	/// assert_eq!(
+	///   document.filter,
+	///   serde_yaml::from_str::<MatchJob>("client: {location: {outer: {name: EU}}}").unwrap(),
+	/// );
+	/// ```
+	pub fn from_value_with_library(
+		value: Value,
+		library: &Library,
+		migrations: &Migrations,
+		limits: &Limits,
+	) -> Result<Self>
+	where
+		M: DeserializeOwned + 'static,
+	{
+		let Raw {
+			filter,
+			fragments,
+			version,
+		} = serde_yaml::from_value(value)?;
+
+		let mut filter = if fragments.is_empty()
+		{
+			library.expand(&filter, limits)?
+		}
+		else
+		{
+			let mut library = library.clone();
+			library.extend(fragments);
+			library.expand(&filter, limits)?
+		};
+
+		migrations.migrate::<M>(version, &mut filter)?;
+		limits.from_value(filter).map(Self::new)
+	}
+}
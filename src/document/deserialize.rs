@@ -0,0 +1,19 @@
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
+use serde_yaml::Value;
+
+use super::{Document, Migrations};
+use crate::Limits;
+
+impl<'de, M> Deserialize<'de> for Document<M>
+where
+	M: DeserializeOwned + 'static,
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let value = Value::deserialize(deserializer)?;
+		Self::from_value(value, &Migrations::default(), &Limits::default())
+			.map_err(serde::de::Error::custom)
+	}
+}
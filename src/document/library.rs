@@ -0,0 +1,299 @@
+use std::collections::{hash_map, HashMap};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_yaml::{Mapping, Value};
+
+use crate::{Error, FieldPath, Limit, Limits, Result};
+
+/// The key of a mapping which is replaced by the fragment it names.
+const REFERENCE: &str = "$ref";
+
+/// The key of a mapping whose fields are extended by the fragments it names.
+const INCLUDE: &str = "$include";
+
+/// Named fragments of filters (e.g. a [`MatchOrganization`](crate::MatchOrganization) called
+/// `eu_clients`) which can be reused by other filters.
+///
+/// Filters (and other fragments) use a fragment in one of two ways:
+///
+/// * A mapping of the form `$ref: <name>` is replaced by the fragment with that name.
+/// * A mapping with the key `$include: <name>` (or `$include: [<name>, …]`) has the fields of each
+///   named fragment added to it, in order. Fields which are written alongside the `$include` take
+///   precedence over included ones.
+///
+/// A [`Document`](super::Document) may define its own `fragments`, which are added to any
+/// [`Library`] given to [`Document::from_value_with_library`](super::Document::from_value_with_library).
+///
+/// # Examples
+///
+/// ```rust
+/// use clinvoice_match::{Library, Limits, MatchJob, MatchOrganization, MatchTimesheet};
+/// # use pretty_assertions::assert_eq;
+///
+/// let library: Library = serde_yaml::from_str(r#"
+///   eu:
+///     name: ["Austria", "Belgium", "France", "Germany"]
+///   eu_clients:
+///     location:
+///       outer:
+///         $ref: eu
+/// "#).unwrap();
+///
+/// let limits = Limits::default();
+/// let job: MatchJob = library.resolve(serde_yaml::from_str(r#"
+///   client:
+///     $ref: eu_clients
+/// "#).unwrap(), &limits).unwrap();
+///
+/// let timesheet: MatchTimesheet = library.resolve(serde_yaml::from_str(r#"
+///   job:
+///     client:
+///       $include: eu_clients
+///       name:
+///         contains: "GmbH"
+/// "#).unwrap(), &limits).unwrap();
+///
+/// let eu_clients: MatchOrganization =
+///   library.resolve(library.get("eu_clients").unwrap().clone(), &limits).unwrap();
+/// assert_eq!(job.client, eu_clients);
+/// assert_eq!(timesheet.job.client.location, eu_clients.location);
+/// assert_eq!(timesheet.job.client.name, clinvoice_match::MatchStr::Contains("GmbH".into()));
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct Library
+{
+	/// The fragments, by name.
+	fragments: HashMap<String, Value>,
+}
+
+impl Library
+{
+	/// Create a [`Library`] without any fragments.
+	pub fn new() -> Self
+	{
+		Self::default()
+	}
+
+	/// Replace every reference and include in the `value` with the fragments of this library.
+	///
+	/// Since a fragment may be used many times, the result can be far larger than the `value` and
+	/// the fragments which it uses. Expansion stops as soon as the result has more nodes than the
+	/// [`max_nodes`](Limits::max_nodes) of the `limits`.
+	///
+	/// # Errors
+	///
+	/// * When a fragment that does not exist is used.
+	/// * When a fragment (directly or indirectly) uses itself.
+	/// * When a reference or include is not written correctly (e.g. including a fragment which is
+	///   not a mapping).
+	/// * When the result would have more than [`Limits::max_nodes`].
+	pub fn expand(&self, value: &Value, limits: &Limits) -> Result<Value>
+	{
+		self.expand_within(
+			value,
+			&FieldPath::default(),
+			&mut Vec::new(),
+			limits,
+			&mut 0,
+		)
+	}
+
+	/// [Expand](Self::expand) the `value` located at the `path`, which is used by the `fragments`
+	/// that are currently being expanded, given the number of `nodes` which have already been
+	/// expanded.
+	fn expand_within(
+		&self,
+		value: &Value,
+		path: &FieldPath,
+		fragments: &mut Vec<String>,
+		limits: &Limits,
+		nodes: &mut usize,
+	) -> Result<Value>
+	{
+		if let Value::Mapping(mapping) = value
+		{
+			if let Some(name) = mapping.get(&REFERENCE.into())
+			{
+				if mapping.len() > 1
+				{
+					return Err(Error::InvalidReference {
+						reason: format!(
+							"the reference to {name:?} has other fields, which requires `{INCLUDE}` \
+							 instead of `{REFERENCE}`"
+						),
+					});
+				}
+
+				return self.fragment(name, path, fragments, limits, nodes);
+			}
+		}
+
+		*nodes += 1;
+		if *nodes > limits.max_nodes
+		{
+			return Err(Error::LimitExceeded {
+				limit: Limit::Nodes,
+				maximum: limits.max_nodes,
+				path: path.clone(),
+			});
+		}
+
+		match value
+		{
+			Value::Mapping(mapping) =>
+			{
+				let mut expanded = Mapping::new();
+				if let Some(names) = mapping.get(&INCLUDE.into())
+				{
+					let names = match names
+					{
+						Value::Sequence(names) => names.iter().collect(),
+						name => vec![name],
+					};
+
+					for name in names
+					{
+						match self.fragment(name, path, fragments, limits, nodes)?
+						{
+							Value::Mapping(fields) => expanded.extend(fields),
+							_ =>
+							{
+								return Err(Error::InvalidReference {
+									reason: format!(
+										"the fragment {name:?} cannot be included because it does not have \
+										 fields"
+									),
+								})
+							},
+						}
+					}
+				}
+
+				mapping
+					.iter()
+					.filter(|(key, _)| key.as_str() != Some(INCLUDE))
+					.try_for_each(|(key, value)| {
+						let field = key.as_str().map_or_else(|| format!("{key:?}"), Into::into);
+						let value =
+							self.expand_within(value, &path.join(field), fragments, limits, nodes)?;
+						expanded.insert(key.clone(), value);
+						Ok::<_, Error>(())
+					})?;
+
+				Ok(Value::Mapping(expanded))
+			},
+			Value::Sequence(sequence) => sequence
+				.iter()
+				.enumerate()
+				.map(|(index, value)| {
+					self.expand_within(
+						value,
+						&path.join(index.to_string()),
+						fragments,
+						limits,
+						nodes,
+					)
+				})
+				.collect::<Result<_>>()
+				.map(Value::Sequence),
+			_ => Ok(value.clone()),
+		}
+	}
+
+	/// Get the fragment with some `name` and [expand](Self::expand) it at the `path`, while the
+	/// `fragments` are currently being expanded.
+	fn fragment(
+		&self,
+		name: &Value,
+		path: &FieldPath,
+		fragments: &mut Vec<String>,
+		limits: &Limits,
+		nodes: &mut usize,
+	) -> Result<Value>
+	{
+		let name = name.as_str().ok_or_else(|| Error::InvalidReference {
+			reason: format!("expected the name of a fragment, found {name:?}"),
+		})?;
+
+		if let Some(start) = fragments.iter().position(|f| f == name)
+		{
+			let mut cycle = fragments[start..].to_vec();
+			cycle.push(name.into());
+			return Err(Error::FragmentCycle { cycle });
+		}
+
+		let fragment = self.get(name).ok_or_else(|| Error::UnknownFragment {
+			fragment: name.into(),
+		})?;
+
+		fragments.push(name.into());
+		let expanded = self.expand_within(fragment, path, fragments, limits, nodes);
+		fragments.pop();
+		expanded
+	}
+
+	/// Get the fragment with some `name`, without [expanding](Self::expand) it.
+	pub fn get(&self, name: &str) -> Option<&Value>
+	{
+		self.fragments.get(name)
+	}
+
+	/// Whether this library has no fragments.
+	pub fn is_empty(&self) -> bool
+	{
+		self.fragments.is_empty()
+	}
+
+	/// Add a `fragment` with some `name`, replacing any previous fragment with the same name.
+	///
+	/// # Errors
+	///
+	/// * When the `fragment` cannot be serialized.
+	pub fn insert<T>(&mut self, name: impl Into<String>, fragment: T) -> Result<&mut Self>
+	where
+		T: Serialize,
+	{
+		self
+			.fragments
+			.insert(name.into(), serde_yaml::to_value(fragment)?);
+		Ok(self)
+	}
+
+	/// [Expand](Self::expand) the `value`, and then [read](Limits::from_value) the result within the
+	/// `limits`.
+	///
+	/// # Errors
+	///
+	/// * See [`Library::expand`].
+	/// * See [`Limits::from_value`].
+	pub fn resolve<M>(&self, value: Value, limits: &Limits) -> Result<M>
+	where
+		M: DeserializeOwned,
+	{
+		self
+			.expand(&value, limits)
+			.and_then(|expanded| limits.from_value(expanded))
+	}
+}
+
+impl Extend<(String, Value)> for Library
+{
+	fn extend<T>(&mut self, fragments: T)
+	where
+		T: IntoIterator<Item = (String, Value)>,
+	{
+		self.fragments.extend(fragments);
+	}
+}
+
+impl IntoIterator for Library
+{
+	type IntoIter = hash_map::IntoIter<String, Value>;
+	type Item = (String, Value);
+
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.fragments.into_iter()
+	}
+}
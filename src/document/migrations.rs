@@ -0,0 +1,141 @@
+mod default;
+mod nested;
+
+use core::any::TypeId;
+use std::collections::HashMap;
+
+use serde_yaml::Value;
+
+use super::DOCUMENT_VERSION;
+use crate::{Error, Result};
+
+/// A function which upgrades the `filter` of a [`Document`](super::Document) from one
+/// [version](DOCUMENT_VERSION) to the next, or explains why it could not.
+pub type Migration = Box<dyn Fn(&mut Value) -> core::result::Result<(), String> + Send + Sync>;
+
+/// A registry of the [`Migration`]s for each type of matcher.
+///
+/// The [`Default`] registry contains the migrations for every change in the
+/// [history](DOCUMENT_VERSION) of the document format.
+///
+/// # Examples
+///
+/// An application which stored its own filters before a field of
+/// [`MatchOrganization`](crate::MatchOrganization) was renamed can register a migration for it,
+/// which also applies to the `client` of a [`MatchJob`](crate::MatchJob):
+///
+/// ```rust
+/// use clinvoice_match::{Document, Limits, MatchJob, MatchOrganization, Migrations};
+/// # use pretty_assertions::assert_eq;
+///
+/// let mut migrations = Migrations::default();
+/// migrations.register::<MatchOrganization>(1, |filter| {
+///   if let Some(filter) = filter.as_mapping_mut() {
+///     if let Some(company_name) = filter.remove(&"company_name".into()) {
+///       filter.insert("name".into(), company_name);
+///     }
+///   }
+///
+///   Ok(())
+/// });
+///
+/// let value = serde_yaml::from_str(r#"
+///   filter:
+///     client:
+///       company_name:
+///         equal_to: "Acme"
+///   version: 1
+/// "#).unwrap();
+///
+/// assert_eq!(
+///   Document::<MatchJob>::from_value(value, &migrations, &Limits::default())
+///     .unwrap()
+///     .filter
+///     .client
+///     .name,
+///   "Acme".to_owned().into(),
+/// );
+/// ```
+pub struct Migrations
+{
+	/// The [`Migration`]s for each type of matcher, which upgrade a document from some version.
+	migrations: HashMap<(TypeId, u32), Vec<Migration>>,
+}
+
+impl Migrations
+{
+	/// Create a registry without any [`Migration`]s.
+	pub fn new() -> Self
+	{
+		Self {
+			migrations: HashMap::new(),
+		}
+	}
+
+	/// Upgrade the `filter` of a [`Document`](super::Document) for matchers of type `M` from
+	/// `version` to the current [version](DOCUMENT_VERSION).
+	///
+	/// The migrations for a matcher are also applied to the matchers nested within others (e.g. the
+	/// `job` of a [`MatchTimesheet`](crate::MatchTimesheet)). Those of the outer matcher are applied
+	/// first, so nested matchers are found by the names of their fields once the outer matcher has been
+	/// upgraded.
+	///
+	/// # Errors
+	///
+	/// * When the `version` is newer than [`DOCUMENT_VERSION`].
+	/// * When one of the [`Migration`]s fails.
+	pub fn migrate<M>(&self, version: u32, filter: &mut Value) -> Result<()>
+	where
+		M: 'static,
+	{
+		if version > DOCUMENT_VERSION
+		{
+			return Err(Error::DocumentVersion {
+				current: DOCUMENT_VERSION,
+				found: version,
+			});
+		}
+
+		(version..DOCUMENT_VERSION).try_for_each(|v| self.migrate_from(v, TypeId::of::<M>(), filter))
+	}
+
+	/// Upgrade the `filter` of a matcher of type `matcher`, and each matcher nested within it, from
+	/// the `version` to the next.
+	fn migrate_from(&self, version: u32, matcher: TypeId, filter: &mut Value) -> Result<()>
+	{
+		self
+			.migrations
+			.get(&(matcher, version))
+			.into_iter()
+			.flatten()
+			.try_for_each(|migration| {
+				migration(filter).map_err(|reason| Error::Migration { reason, version })
+			})?;
+
+		nested::try_for_each(matcher, filter, &mut |nested, filter| {
+			self.migrate_from(version, nested, filter)
+		})
+	}
+
+	/// Add a `migration` which upgrades the `filter` of a [`Document`](super::Document) for matchers
+	/// of type `M` from the `from_version` to the next version.
+	///
+	/// The `migration` is applied to every matcher of type `M` in the document, including those which
+	/// are nested within other matchers (see [`Migrations::migrate`]). Migrations for the same type and
+	/// version are applied in the order they were registered.
+	pub fn register<M>(
+		&mut self,
+		from_version: u32,
+		migration: impl Fn(&mut Value) -> core::result::Result<(), String> + Send + Sync + 'static,
+	) -> &mut Self
+	where
+		M: 'static,
+	{
+		self
+			.migrations
+			.entry((TypeId::of::<M>(), from_version))
+			.or_default()
+			.push(Box::new(migration));
+		self
+	}
+}
@@ -0,0 +1,40 @@
+use serde_yaml::{Mapping, Value};
+
+use super::Migrations;
+use crate::MatchTimesheet;
+
+/// Turn the `employee` of a [`MatchTimesheet`] from a [`MatchStr`](crate::MatchStr) of the
+/// employee's name into a [`MatchEmployee`](crate::MatchEmployee), and rename `notes` to
+/// `work_notes`.
+fn timesheet_v1(filter: &mut Value) -> Result<(), String>
+{
+	let fields = match filter
+	{
+		Value::Mapping(fields) => fields,
+		other => return Err(format!("expected the fields of a matcher, found {other:?}")),
+	};
+
+	if let Some(name) = fields.remove(&"employee".into())
+	{
+		let mut employee = Mapping::new();
+		employee.insert("name".into(), name);
+		fields.insert("employee".into(), employee.into());
+	}
+
+	if let Some(notes) = fields.remove(&"notes".into())
+	{
+		fields.insert("work_notes".into(), notes);
+	}
+
+	Ok(())
+}
+
+impl Default for Migrations
+{
+	fn default() -> Self
+	{
+		let mut migrations = Self::new();
+		migrations.register::<MatchTimesheet>(1, timesheet_v1);
+		migrations
+	}
+}
@@ -0,0 +1,143 @@
+use core::any::TypeId;
+
+use serde_yaml::Value;
+
+use crate::{
+	MatchEmployee,
+	MatchExpense,
+	MatchInvoice,
+	MatchJob,
+	MatchLocation,
+	MatchOrganization,
+	MatchTimesheet,
+	Result,
+};
+
+/// How a matcher is nested within a field of another.
+#[derive(Clone, Copy)]
+enum Nesting
+{
+	/// The field is the matcher (e.g. the `client` of a [`MatchJob`]).
+	Direct,
+
+	/// The field is a [`MatchOuterLocation`](crate::MatchOuterLocation), which contains the matcher
+	/// when it is not `any` or `none`.
+	Outer,
+
+	/// The field is a [`MatchSet`](crate::MatchSet), which contains the matcher in each of its
+	/// `contains` conditions.
+	Set,
+}
+
+impl Nesting
+{
+	/// Call `f` with each matcher which is nested within the `value` of a field.
+	fn try_for_each(
+		self,
+		value: &mut Value,
+		f: &mut dyn FnMut(&mut Value) -> Result<()>,
+	) -> Result<()>
+	{
+		match self
+		{
+			Self::Direct => f(value),
+			Self::Outer if !value.is_mapping() => Ok(()),
+			Self::Outer => match value.get_mut("some")
+			{
+				Some(location) => f(location),
+				None => f(value),
+			},
+			Self::Set =>
+			{
+				let Some(fields) = value.as_mapping_mut()
+				else
+				{
+					return Ok(());
+				};
+
+				fields
+					.iter_mut()
+					.try_for_each(|(condition, value)| match condition.as_str()
+					{
+						Some("and" | "or") => value
+							.as_sequence_mut()
+							.into_iter()
+							.flatten()
+							.try_for_each(|set| self.try_for_each(set, f)),
+						Some("contains") => f(value),
+						Some("not") => self.try_for_each(value, f),
+						_ => Ok(()),
+					})
+			},
+		}
+	}
+}
+
+/// A field of some matcher which contains other matchers.
+struct Field
+{
+	/// The type of the matchers which the field contains.
+	matcher: fn() -> TypeId,
+
+	/// The name of the field.
+	name: &'static str,
+
+	/// How the matchers are nested within the field.
+	nesting: Nesting,
+}
+
+/// Shorthand for a [`Field`] which contains matchers of type `M`.
+const fn field<M>(name: &'static str, nesting: Nesting) -> Field
+where
+	M: 'static,
+{
+	Field {
+		matcher: TypeId::of::<M>,
+		name,
+		nesting,
+	}
+}
+
+/// The fields of the matcher of type `matcher` which contain other matchers, as they are named in
+/// the current [version](crate::DOCUMENT_VERSION).
+fn fields(matcher: TypeId) -> &'static [Field]
+{
+	const JOB: &[Field] = &[
+		field::<MatchOrganization>("client", Nesting::Direct),
+		field::<MatchInvoice>("invoice", Nesting::Direct),
+	];
+	const LOCATION: &[Field] = &[field::<MatchLocation>("outer", Nesting::Outer)];
+	const ORGANIZATION: &[Field] = &[field::<MatchLocation>("location", Nesting::Direct)];
+	const TIMESHEET: &[Field] = &[
+		field::<MatchEmployee>("employee", Nesting::Direct),
+		field::<MatchExpense>("expenses", Nesting::Set),
+		field::<MatchJob>("job", Nesting::Direct),
+	];
+
+	[
+		(TypeId::of::<MatchJob>(), JOB),
+		(TypeId::of::<MatchLocation>(), LOCATION),
+		(TypeId::of::<MatchOrganization>(), ORGANIZATION),
+		(TypeId::of::<MatchTimesheet>(), TIMESHEET),
+	]
+	.into_iter()
+	.find_map(|(t, fields)| (t == matcher).then_some(fields))
+	.unwrap_or_default()
+}
+
+/// Call `f` with each matcher which is nested within the `filter` of a matcher of type `matcher`,
+/// along with the type of that nested matcher.
+pub(super) fn try_for_each(
+	matcher: TypeId,
+	filter: &mut Value,
+	f: &mut dyn FnMut(TypeId, &mut Value) -> Result<()>,
+) -> Result<()>
+{
+	fields(matcher).iter().try_for_each(|field| {
+		filter.get_mut(field.name).map_or(Ok(()), |value| {
+			field
+				.nesting
+				.try_for_each(value, &mut |nested| f((field.matcher)(), nested))
+		})
+	})
+}
@@ -0,0 +1,225 @@
+use thiserror::Error;
+
+/// An [`Error`](std::error::Error) which can occur while using the matchers in this crate.
+#[derive(Debug, Error)]
+pub enum Error
+{
+	/// A storage [`Adapter`](crate::conformance::Adapter) failed while being checked for
+	/// conformance.
+	#[cfg(feature = "testing")]
+	#[error("the adapter failed: {0}")]
+	Adapter(#[source] Box<dyn core::error::Error + Send + Sync>),
+
+	/// A matcher could not be encoded or decoded with [`binary`](crate::binary).
+	#[cfg(feature = "binary")]
+	#[error(transparent)]
+	Binary(#[from] postcard::Error),
+
+	/// Some bytes passed to [`binary::from_bytes`](crate::binary::from_bytes) were not created by
+	/// [`binary::to_bytes`](crate::binary::to_bytes).
+	#[cfg(feature = "binary")]
+	#[error("the bytes do not begin with the header of an encoded matcher")]
+	BinaryHeader,
+
+	/// Some bytes passed to [`binary::from_bytes`](crate::binary::from_bytes) were encoded by a
+	/// version of this crate which used a different [`binary::FORMAT_VERSION`](crate::binary::FORMAT_VERSION).
+	#[cfg(feature = "binary")]
+	#[error(
+		"the matcher was encoded with format version {found}, but only version {expected} is \
+		 supported"
+	)]
+	BinaryVersion
+	{
+		/// The [`binary::FORMAT_VERSION`](crate::binary::FORMAT_VERSION) of this crate.
+		expected: u16,
+
+		/// The format version which the bytes were encoded with.
+		found: u16,
+	},
+
+	/// The [`Cursor`](crate::Cursor) of a [`Query`](crate::Query) does not have a value for each
+	/// field which the query is ordered by.
+	#[error("the cursor has {found} values, but the query is ordered by {expected} fields")]
+	CursorLength
+	{
+		/// The number of fields which the query is ordered by.
+		expected: usize,
+
+		/// The number of values in the cursor.
+		found: usize,
+	},
+
+	/// A [`Document`](crate::Document) was written in a newer version than
+	/// [`DOCUMENT_VERSION`](crate::DOCUMENT_VERSION).
+	#[cfg(feature = "yaml")]
+	#[error("the document has version {found}, but the newest supported version is {current}")]
+	DocumentVersion
+	{
+		/// The [`DOCUMENT_VERSION`](crate::DOCUMENT_VERSION) of this crate.
+		current: u32,
+
+		/// The version of the document.
+		found: u32,
+	},
+
+	/// Fragments of a [`Library`](crate::Library) use each other in a cycle.
+	#[cfg(feature = "yaml")]
+	#[error("the fragments of the library use each other in a cycle: {}", .cycle.join(" -> "))]
+	FragmentCycle
+	{
+		/// The names of the fragments in the cycle, starting and ending with the same one.
+		cycle: Vec<String>,
+	},
+
+	/// A fragment of a [`Library`](crate::Library) was referenced or included incorrectly.
+	#[cfg(feature = "yaml")]
+	#[error("{reason}")]
+	InvalidReference
+	{
+		/// What is wrong with the reference.
+		reason: String,
+	},
+
+	/// A filter exceeded one of its [`Limits`](crate::Limits).
+	#[cfg(feature = "yaml")]
+	#[error("the filter exceeds the maximum {limit} ({maximum}) at `{path}`")]
+	LimitExceeded
+	{
+		/// The limit which was exceeded.
+		limit: crate::Limit,
+
+		/// The value of the `limit`.
+		maximum: usize,
+
+		/// Where in the filter the `limit` was exceeded.
+		path: crate::FieldPath,
+	},
+
+	/// A [`Template`](crate::Template) contains a placeholder which was not given a value.
+	#[cfg(feature = "yaml")]
+	#[error("the parameter `{parameter}` does not have a value")]
+	MissingParameter
+	{
+		/// The name of the parameter.
+		parameter: String,
+	},
+
+	/// A [`Migration`](crate::Migration) could not upgrade a [`Document`](crate::Document).
+	#[cfg(feature = "yaml")]
+	#[error("could not migrate a document from version {version}: {reason}")]
+	Migration
+	{
+		/// Why the migration failed.
+		reason: String,
+
+		/// The version which the document was being migrated from.
+		version: u32,
+	},
+
+	/// An [`Aggregate::Sum`](crate::Aggregate::Sum) refers to a field which is neither a
+	/// [`Duration`](core::time::Duration) nor [`Money`](clinvoice_schema::Money).
+	#[error("the values of `{path}` cannot be summed")]
+	NotSummable
+	{
+		/// The field which cannot be summed.
+		path: crate::FieldPath,
+	},
+
+	/// The values of an [`Aggregate::Sum`](crate::Aggregate::Sum) added up to more than can be
+	/// represented.
+	#[error("the sum of `{path}` is too large")]
+	Overflow
+	{
+		/// The field whose values were summed.
+		path: crate::FieldPath,
+	},
+
+	/// The value of a parameter of a [`Template`](crate::Template) had the wrong type for where its
+	/// placeholder is used.
+	#[cfg(feature = "yaml")]
+	#[error("the value of parameter `{parameter}` has the wrong type at `{path}`: {reason}")]
+	ParameterType
+	{
+		/// The name of the parameter.
+		parameter: String,
+
+		/// The location of the placeholder, which may be the field which contains it.
+		path: String,
+
+		/// Why the value is not valid.
+		reason: String,
+	},
+
+	/// A [`MatchStr::Regex`](crate::MatchStr::Regex) did not contain a valid regular expression.
+	#[error(transparent)]
+	Regex(#[from] regex::Error),
+
+	/// A [`Pattern`](crate::Pattern) was not a valid regular expression of its
+	/// [`Dialect`](crate::Dialect).
+	#[error("invalid {dialect} regular expression at position {position}: {reason}")]
+	RegexSyntax
+	{
+		/// The dialect which the regular expression was written in.
+		dialect: crate::Dialect,
+
+		/// The index of the character where the error was found.
+		position: usize,
+
+		/// What is wrong with the regular expression.
+		reason: String,
+	},
+
+	/// A [`Pattern`](crate::Pattern) contained a [`Construct`](crate::Construct) which a
+	/// [`Dialect`](crate::Dialect) does not support.
+	#[error("{construct} are not supported by {dialect} regular expressions")]
+	RegexUnsupported
+	{
+		/// The construct which is not supported.
+		construct: crate::Construct,
+
+		/// The dialect which does not support the `construct`.
+		dialect: crate::Dialect,
+	},
+
+	/// A filter contained a [`MatchStr::Regex`](crate::MatchStr::Regex), but the
+	/// [`RegexEngine`](crate::RegexEngine) of its [`Limits`](crate::Limits) does not allow them.
+	#[cfg(feature = "yaml")]
+	#[error("regular expressions are not allowed, but one was found at `{path}`")]
+	RegexDisabled
+	{
+		/// Where in the filter the regular expression was found.
+		path: crate::FieldPath,
+	},
+
+	/// A [`Query`](crate::Query) or [`Aggregation`](crate::Aggregation) refers to a field which does
+	/// not exist, or which records cannot be ordered by.
+	#[error("records cannot be ordered by `{path}`")]
+	UnknownField
+	{
+		/// The field which records cannot be ordered by.
+		path: crate::FieldPath,
+	},
+
+	/// A fragment which is not part of a [`Library`](crate::Library) was used.
+	#[cfg(feature = "yaml")]
+	#[error("the fragment `{fragment}` is not part of the library")]
+	UnknownFragment
+	{
+		/// The name of the fragment.
+		fragment: String,
+	},
+
+	/// A value was given for a parameter which a [`Template`](crate::Template) does not use.
+	#[cfg(feature = "yaml")]
+	#[error("the parameter `{parameter}` is not used by the template")]
+	UnknownParameter
+	{
+		/// The name of the parameter.
+		parameter: String,
+	},
+
+	/// A value could not be converted to or from YAML.
+	#[cfg(feature = "yaml")]
+	#[error(transparent)]
+	Yaml(#[from] serde_yaml::Error),
+}
@@ -0,0 +1,53 @@
+mod explanation;
+
+pub use explanation::Explanation;
+
+use crate::Result;
+
+/// A matcher which can describe why some value of type `T` does or does not match it.
+///
+/// # Examples
+///
+/// ```rust
+/// use clinvoice_match::{Explain, MatchEmployee, MatchStr};
+/// use clinvoice_schema::Employee;
+/// # use pretty_assertions::assert_eq;
+///
+/// let condition = MatchEmployee {
+///   name: MatchStr::Regex("^[ABC]".into()),
+///   status: "Hired".to_owned().into(),
+///   ..Default::default()
+/// };
+///
+/// let employee = Employee {
+///   id: 0,
+///   name: "Bob".into(),
+///   status: "Fired".into(),
+///   title: "CEO".into(),
+/// };
+///
+/// let explanation = condition.explain(&employee).unwrap();
+/// assert!(!explanation.is_match);
+/// assert_eq!(
+///   explanation.to_string(),
+///   r#"✗ MatchEmployee
+///   ✓ id: any
+///   ✓ name: regex "^[ABC]" (actual: "Bob")
+///   ✗ status: equal_to "Hired" (actual: "Fired")
+///   ✓ title: any
+/// "#,
+/// );
+/// ```
+pub trait Explain<T>
+where
+	T: ?Sized,
+{
+	/// Evaluate whether `value` matches `self`, and return an [`Explanation`] of how each part of
+	/// `self` contributed to the result.
+	///
+	/// # Errors
+	///
+	/// * When any [`MatchStr::Regex`](crate::MatchStr::Regex) contains an invalid regular
+	///   expression.
+	fn explain(&self, value: &T) -> Result<Explanation>;
+}
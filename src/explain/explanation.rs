@@ -0,0 +1,102 @@
+mod display;
+
+use core::fmt::Debug;
+
+/// A tree which mirrors the structure of some matcher, recording whether each part of the matcher
+/// matched some value.
+///
+/// An [`Explanation`] is created by the [`Explain`](crate::Explain) trait, and can be rendered as
+/// text using its [`Display`](core::fmt::Display) implementation.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Explanation
+{
+	/// The value which was compared against this part of the matcher, if any.
+	pub actual: Option<String>,
+
+	/// The [`Explanation`]s of the parts of the matcher which this part contains.
+	pub children: Vec<Self>,
+
+	/// Whether or not this part of the matcher matched.
+	pub is_match: bool,
+
+	/// A description of this part of the matcher (e.g. `job`, `equal_to "Foo"`).
+	pub label: String,
+}
+
+impl Explanation
+{
+	/// Create an [`Explanation`] for some condition which matches IFF all of its `children` do.
+	pub(crate) fn all(label: impl Into<String>, children: Vec<Self>) -> Self
+	{
+		Self {
+			actual: None,
+			is_match: children.iter().all(|c| c.is_match),
+			children,
+			label: label.into(),
+		}
+	}
+
+	/// Create an [`Explanation`] for some condition which matches IFF any of its `children` do.
+	pub(crate) fn any(label: impl Into<String>, children: Vec<Self>) -> Self
+	{
+		Self {
+			actual: None,
+			is_match: children.iter().any(|c| c.is_match),
+			children,
+			label: label.into(),
+		}
+	}
+
+	/// Create an [`Explanation`] for a comparison between `actual` and a condition described by
+	/// `label`.
+	pub(crate) fn comparison(label: impl Into<String>, is_match: bool, actual: &impl Debug) -> Self
+	{
+		Self {
+			actual: Some(format!("{actual:?}")),
+			children: Vec::new(),
+			is_match,
+			label: label.into(),
+		}
+	}
+
+	/// Create an [`Explanation`] for a condition which does not depend on any value.
+	pub(crate) fn constant(label: impl Into<String>, is_match: bool) -> Self
+	{
+		Self {
+			actual: None,
+			children: Vec::new(),
+			is_match,
+			label: label.into(),
+		}
+	}
+
+	/// Create an [`Explanation`] for the `field` of some entity, which matches IFF its
+	/// `condition` does.
+	pub(crate) fn field(field: &str, condition: Self) -> Self
+	{
+		Self {
+			actual: None,
+			is_match: condition.is_match,
+			children: vec![condition],
+			label: field.into(),
+		}
+	}
+
+	/// Create an [`Explanation`] for a [`Not`](crate::Match::Not) condition.
+	pub(crate) fn not(condition: Self) -> Self
+	{
+		Self {
+			actual: None,
+			is_match: !condition.is_match,
+			children: vec![condition],
+			label: "not".into(),
+		}
+	}
+
+	/// Replace the [`label`](Self::label) of this [`Explanation`].
+	pub(crate) fn with_label(mut self, label: impl Into<String>) -> Self
+	{
+		self.label = label.into();
+		self
+	}
+}
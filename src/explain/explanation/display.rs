@@ -0,0 +1,47 @@
+use core::fmt::{Display, Formatter, Result};
+
+use super::Explanation;
+
+/// Write the `explanation` and all of its children to `f`, indented by `depth` levels.
+///
+/// When an [`Explanation`] has a single child without any children of its own (e.g. a field which
+/// is compared directly to some value), both are written on the same line.
+fn write_indented(explanation: &Explanation, f: &mut Formatter<'_>, depth: usize) -> Result
+{
+	write!(
+		f,
+		"{:indent$}{} {}",
+		"",
+		if explanation.is_match { '✓' } else { '✗' },
+		explanation.label,
+		indent = depth * 2,
+	)?;
+
+	let (leaf, children) = match explanation.children.as_slice()
+	{
+		[child] if child.children.is_empty() =>
+		{
+			write!(f, ": {}", child.label)?;
+			(child, [].as_slice())
+		},
+		children => (explanation, children),
+	};
+
+	if let Some(ref actual) = leaf.actual
+	{
+		write!(f, " (actual: {actual})")?;
+	}
+
+	writeln!(f)?;
+	children
+		.iter()
+		.try_for_each(|c| write_indented(c, f, depth + 1))
+}
+
+impl Display for Explanation
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result
+	{
+		write_indented(self, f, 0)
+	}
+}
@@ -0,0 +1,45 @@
+#[cfg(feature = "serde")]
+mod deserialize;
+mod display;
+mod from_str;
+#[cfg(feature = "serde")]
+mod serialize;
+
+/// The location of some field within a matcher (e.g. `job.client.name` in a
+/// [`MatchTimesheet`](crate::MatchTimesheet)), relative to the root of the matcher.
+///
+/// # Examples
+///
+/// ```rust
+/// use clinvoice_match::FieldPath;
+/// # use pretty_assertions::assert_eq;
+///
+/// let path = FieldPath::default().join("job").join("client").join("name");
+/// assert_eq!(path.to_string(), "job.client.name");
+/// assert_eq!("job.client.name".parse::<FieldPath>().unwrap(), path);
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FieldPath(Vec<String>);
+
+impl FieldPath
+{
+	/// Get the names of each field in the path, starting from the root.
+	pub fn fields(&self) -> impl Iterator<Item = &str>
+	{
+		self.0.iter().map(String::as_str)
+	}
+
+	/// Whether or not this [`FieldPath`] refers to the root of the matcher.
+	pub const fn is_root(&self) -> bool
+	{
+		self.0.is_empty()
+	}
+
+	/// Create a new [`FieldPath`] which refers to the `field` of whatever `self` refers to.
+	pub fn join(&self, field: impl Into<String>) -> Self
+	{
+		let mut fields = self.0.clone();
+		fields.push(field.into());
+		Self(fields)
+	}
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Deserializer};
+
+use super::FieldPath;
+
+impl<'de> Deserialize<'de> for FieldPath
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let path = String::deserialize(deserializer)?;
+		Ok(path.parse().unwrap_or_else(|never| match never {}))
+	}
+}
@@ -0,0 +1,15 @@
+use core::fmt::{Display, Formatter, Result};
+
+use super::FieldPath;
+
+impl Display for FieldPath
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result
+	{
+		self.0.iter().enumerate().try_for_each(|(i, field)| match i
+		{
+			0 => f.write_str(field),
+			_ => write!(f, ".{field}"),
+		})
+	}
+}
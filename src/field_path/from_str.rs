@@ -0,0 +1,18 @@
+use core::{convert::Infallible, str::FromStr};
+
+use super::FieldPath;
+
+impl FromStr for FieldPath
+{
+	type Err = Infallible;
+
+	/// Parse the names of each field, separated by `.` (e.g. `job.client.name`). An empty string is
+	/// the root.
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		Ok(s
+			.split('.')
+			.filter(|field| !field.is_empty())
+			.fold(Self::default(), |path, field| path.join(field)))
+	}
+}
@@ -0,0 +1,13 @@
+use serde::{Serialize, Serializer};
+
+use super::FieldPath;
+
+impl Serialize for FieldPath
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.collect_str(self)
+	}
+}
@@ -0,0 +1,232 @@
+mod default;
+mod indexable;
+mod interval_tree;
+
+use std::collections::HashMap;
+
+use clinvoice_schema::{chrono::NaiveDateTime, Id};
+pub use indexable::Indexable;
+use interval_tree::IntervalTree;
+
+use crate::{Compile, IsMatch, Result};
+
+/// A collection of many matchers of type `M` (e.g. user-saved alert filters), each identified by a
+/// key of type `K`, which can efficiently determine which of them match some record.
+///
+/// Rather than evaluating every matcher against each record, the [`FilterIndex`] looks at the
+/// conditions which a matcher _requires_ in order to match (e.g. `id: { equal_to: 3 }`) and only
+/// evaluates the matchers whose requirements are met by the record:
+///
+/// * [`Match<Id>`](crate::Match) and [`MatchStr`](crate::MatchStr) conditions which are
+///   [`EqualTo`](crate::Match::EqualTo) some value (or an [`Or`](crate::Match::Or) of such
+///   conditions) are stored in a hash table.
+/// * [`Match<NaiveDateTime>`](crate::Match) conditions which describe a range of dates are stored in
+///   an interval tree.
+/// * All other matchers are evaluated for every record.
+///
+/// # Examples
+///
+/// ```rust
+/// use clinvoice_match::{FilterIndex, Match, MatchEmployee, MatchStr};
+/// use clinvoice_schema::Employee;
+/// # use pretty_assertions::assert_eq;
+///
+/// let mut index = FilterIndex::new();
+/// index.insert("bob", &MatchEmployee::from(Match::Or(vec![1.into(), 2.into()]))).unwrap();
+/// index.insert("ceo", &MatchEmployee {
+///   title: "CEO".to_owned().into(),
+///   ..Default::default()
+/// }).unwrap();
+/// index.insert("hired", &MatchEmployee {
+///   status: MatchStr::Contains("Hired".into()),
+///   ..Default::default()
+/// }).unwrap();
+///
+/// let employee = Employee {
+///   id: 2,
+///   name: "Bob".into(),
+///   status: "Hired".into(),
+///   title: "Janitor".into(),
+/// };
+///
+/// assert_eq!(index.matches(&employee), [&"bob", &"hired"]);
+/// ```
+///
+/// Ranges of dates are found in an interval tree:
+///
+/// ```rust
+/// use clinvoice_match::{FilterIndex, Match, MatchJob};
+/// use clinvoice_schema::{chrono::NaiveDate, Job};
+/// # use pretty_assertions::assert_eq;
+///
+/// let date = |day| NaiveDate::from_ymd_opt(2022, 1, day).unwrap().and_hms_opt(0, 0, 0).unwrap();
+/// let opened = |condition| MatchJob { date_open: condition, ..Default::default() };
+///
+/// let mut index = FilterIndex::new();
+/// index.insert("first week", &opened(Match::InRange(date(1), date(8)))).unwrap();
+/// index.insert("new year", &opened(Match::EqualTo(date(1)))).unwrap();
+/// index.insert("recent", &opened(Match::GreaterThan(date(5)))).unwrap();
+///
+/// let job = |day| Job { date_open: date(day), ..Default::default() };
+/// assert_eq!(index.matches(&job(1)), [&"first week", &"new year"]);
+/// assert_eq!(index.matches(&job(7)), [&"first week", &"recent"]);
+/// assert_eq!(index.matches(&job(8)), [&"recent"]);
+/// ```
+#[derive(Debug)]
+pub struct FilterIndex<K, M>
+where
+	M: Compile,
+{
+	/// An [`IntervalTree`] for each of the [`Indexable::date_conditions`].
+	dates: Vec<IntervalTree<NaiveDateTime>>,
+
+	/// Every filter which has been inserted, in order of insertion.
+	filters: Vec<(K, M::Compiled)>,
+
+	/// For each of the [`Indexable::id_conditions`], the filters which require that field to be
+	/// some [`Id`].
+	ids: Vec<HashMap<Id, Vec<usize>>>,
+
+	/// For each of the [`Indexable::str_conditions`], the filters which require that field to be
+	/// some [`String`].
+	strs: Vec<HashMap<String, Vec<usize>>>,
+
+	/// The filters which could not be indexed.
+	unindexed: Vec<usize>,
+}
+
+impl<K, M> FilterIndex<K, M>
+where
+	M: Indexable,
+	M::Compiled: IsMatch<M::Record>,
+{
+	/// Create an empty [`FilterIndex`].
+	pub const fn new() -> Self
+	{
+		Self {
+			dates: Vec::new(),
+			filters: Vec::new(),
+			ids: Vec::new(),
+			strs: Vec::new(),
+			unindexed: Vec::new(),
+		}
+	}
+
+	/// Add a `filter` to the index, which will be identified by the `key`.
+	///
+	/// # Errors
+	///
+	/// * When the `filter` cannot be [compiled](Compile::compile).
+	pub fn insert(&mut self, key: K, filter: &M) -> Result<()>
+	{
+		let position = self.filters.len();
+		self.filters.push((key, filter.compile()?));
+
+		if let Some((field, ids)) = filter
+			.id_conditions()
+			.into_iter()
+			.enumerate()
+			.find_map(|(i, c)| indexable::required_values(c).map(|ids| (i, ids)))
+		{
+			if self.ids.len() <= field
+			{
+				self.ids.resize_with(field + 1, HashMap::new);
+			}
+
+			ids.into_iter()
+				.for_each(|id| self.ids[field].entry(id).or_default().push(position));
+		}
+		else if let Some((field, strs)) = filter
+			.str_conditions()
+			.into_iter()
+			.enumerate()
+			.find_map(|(i, c)| indexable::required_strs(c).map(|strs| (i, strs)))
+		{
+			if self.strs.len() <= field
+			{
+				self.strs.resize_with(field + 1, HashMap::new);
+			}
+
+			strs.into_iter().for_each(|s| {
+				self.strs[field]
+					.entry(s.to_owned())
+					.or_default()
+					.push(position);
+			});
+		}
+		else if let Some((field, (lower, upper))) = filter
+			.date_conditions()
+			.into_iter()
+			.enumerate()
+			.find_map(|(i, c)| indexable::required_range(c).map(|range| (i, range)))
+		{
+			if self.dates.len() <= field
+			{
+				self.dates.resize_with(field + 1, IntervalTree::default);
+			}
+
+			self.dates[field].insert(lower, upper, position);
+		}
+		else
+		{
+			self.unindexed.push(position);
+		}
+
+		Ok(())
+	}
+
+	/// Whether or not any filters have been [inserted](Self::insert).
+	pub const fn is_empty(&self) -> bool
+	{
+		self.filters.is_empty()
+	}
+
+	/// The number of filters which have been [inserted](Self::insert).
+	pub const fn len(&self) -> usize
+	{
+		self.filters.len()
+	}
+
+	/// Get the keys of every filter which matches the `record`, in the order they were
+	/// [inserted](Self::insert).
+	pub fn matches(&self, record: &M::Record) -> Vec<&K>
+	{
+		let mut candidates = self.unindexed.clone();
+
+		M::record_ids(record)
+			.into_iter()
+			.zip(&self.ids)
+			.for_each(|(id, index)| {
+				if let Some(positions) = index.get(&id)
+				{
+					candidates.extend_from_slice(positions);
+				}
+			});
+
+		M::record_strs(record)
+			.into_iter()
+			.zip(&self.strs)
+			.for_each(|(s, index)| {
+				if let Some(positions) = index.get(s)
+				{
+					candidates.extend_from_slice(positions);
+				}
+			});
+
+		M::record_dates(record)
+			.into_iter()
+			.zip(&self.dates)
+			.for_each(|(date, tree)| {
+				tree.stab(&date, &mut candidates);
+			});
+
+		candidates.sort_unstable();
+		candidates.dedup();
+		candidates
+			.into_iter()
+			.map(|position| &self.filters[position])
+			.filter(|(_, filter)| filter.is_match(record))
+			.map(|(key, _)| key)
+			.collect()
+	}
+}
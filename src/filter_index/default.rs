@@ -0,0 +1,13 @@
+use super::{FilterIndex, Indexable};
+use crate::IsMatch;
+
+impl<K, M> Default for FilterIndex<K, M>
+where
+	M: Indexable,
+	M::Compiled: IsMatch<M::Record>,
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
@@ -0,0 +1,104 @@
+use clinvoice_schema::{chrono::NaiveDateTime, Id};
+
+use super::interval_tree::UpperBound;
+use crate::{Compile, Match, MatchStr};
+
+/// A matcher which can be stored in a [`FilterIndex`](super::FilterIndex).
+///
+/// Each method which returns conditions must always return the same number of conditions in the
+/// same order, and the corresponding method for the [`Indexable::Record`] must return the values
+/// of those fields in that order.
+pub trait Indexable: Compile
+{
+	/// The type of record which this matcher can be applied to.
+	type Record;
+
+	/// The [`NaiveDateTime`] conditions of this matcher which can be indexed.
+	fn date_conditions(&self) -> Vec<&Match<NaiveDateTime>>;
+
+	/// The [`Id`] conditions of this matcher which can be indexed.
+	fn id_conditions(&self) -> Vec<&Match<Id>>;
+
+	/// The values of the fields which correspond to [`Indexable::date_conditions`].
+	fn record_dates(record: &Self::Record) -> Vec<NaiveDateTime>;
+
+	/// The values of the fields which correspond to [`Indexable::id_conditions`].
+	fn record_ids(record: &Self::Record) -> Vec<Id>;
+
+	/// The values of the fields which correspond to [`Indexable::str_conditions`].
+	fn record_strs(record: &Self::Record) -> Vec<&str>;
+
+	/// The [`String`] conditions of this matcher which can be indexed.
+	fn str_conditions(&self) -> Vec<&MatchStr<String>>;
+}
+
+/// Get the range of values which some value must be within in order to match the `condition`, or
+/// [`None`] if there is no such range.
+///
+/// The range may contain values which do not match the `condition`, but every value which matches
+/// the `condition` will be within the range.
+pub(crate) fn required_range<T>(condition: &Match<T>) -> Option<(Option<T>, UpperBound<T>)>
+where
+	T: Clone + Ord,
+{
+	match condition
+	{
+		Match::And(conditions) => conditions.iter().filter_map(required_range).reduce(
+			|(lower, upper), (other_lower, other_upper)| {
+				(lower.max(other_lower), upper.min(other_upper))
+			},
+		),
+		Match::EqualTo(x) => Some((Some(x.clone()), UpperBound::Included(x.clone()))),
+		Match::GreaterThan(x) => Some((Some(x.clone()), UpperBound::Unbounded)),
+		Match::InRange(low, high) => Some((Some(low.clone()), UpperBound::Included(high.clone()))),
+		Match::LessThan(x) => Some((None, UpperBound::Included(x.clone()))),
+		Match::Or(conditions) => conditions
+			.iter()
+			.map(required_range)
+			.reduce(|range, other| {
+				range
+					.zip(other)
+					.map(|((lower, upper), (other_lower, other_upper))| {
+						(lower.min(other_lower), upper.max(other_upper))
+					})
+			})
+			.flatten(),
+		Match::Any | Match::Not(_) => None,
+	}
+}
+
+/// Get the values which some string must be one of in order to match the `condition`, or [`None`]
+/// if there are no such values.
+pub(crate) fn required_strs(condition: &MatchStr<String>) -> Option<Vec<&str>>
+{
+	match condition
+	{
+		MatchStr::And(conditions) => conditions.iter().find_map(required_strs),
+		MatchStr::EqualTo(s) => Some(vec![s.as_str()]),
+		MatchStr::Or(conditions) => conditions
+			.iter()
+			.map(required_strs)
+			.collect::<Option<Vec<_>>>()
+			.map(|strs| strs.concat()),
+		_ => None,
+	}
+}
+
+/// Get the values which some value must be one of in order to match the `condition`, or [`None`]
+/// if there are no such values.
+pub(crate) fn required_values<T>(condition: &Match<T>) -> Option<Vec<T>>
+where
+	T: Clone,
+{
+	match condition
+	{
+		Match::And(conditions) => conditions.iter().find_map(required_values),
+		Match::EqualTo(x) => Some(vec![x.clone()]),
+		Match::Or(conditions) => conditions
+			.iter()
+			.map(required_values)
+			.collect::<Option<Vec<_>>>()
+			.map(|values| values.concat()),
+		_ => None,
+	}
+}
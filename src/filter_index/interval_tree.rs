@@ -0,0 +1,158 @@
+use std::sync::OnceLock;
+
+/// The upper bound of an interval in an [`IntervalTree`].
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) enum UpperBound<T>
+{
+	/// The interval ends at (and includes) this value.
+	Included(T),
+
+	/// The interval never ends.
+	Unbounded,
+}
+
+impl<T> UpperBound<T>
+where
+	T: Ord,
+{
+	/// Whether or not the interval ends before the `point`.
+	fn is_before(&self, point: &T) -> bool
+	{
+		match self
+		{
+			Self::Included(upper) => upper < point,
+			Self::Unbounded => false,
+		}
+	}
+}
+
+/// An interval tree which is used to find all of the intervals which contain some point.
+///
+/// Intervals are collected as they are inserted, and the tree is built from them the next time it
+/// is [stabbed](IntervalTree::stab), so that inserting many intervals does not rebuild the tree
+/// each time.
+#[derive(Clone, Debug)]
+pub(crate) struct IntervalTree<T>
+{
+	/// Every interval which has been inserted, in order of insertion.
+	intervals: Vec<Interval<T>>,
+
+	/// The tree built from the `intervals`, if it has been built since the last insertion.
+	tree: OnceLock<Tree<T>>,
+}
+
+/// The lower bound (where [`None`] is unbounded), upper bound, and value of an interval.
+type Interval<T> = (Option<T>, UpperBound<T>, usize);
+
+/// The intervals of an [`IntervalTree`] stored in a [`Vec`] sorted by their lower bound, which is
+/// treated as an implicit balanced binary tree: the root of any slice of the [`Vec`] is its
+/// midpoint. Each node is augmented with the maximum upper bound of all of the intervals in its
+/// subtree, so that subtrees which end before the point can be skipped.
+#[derive(Clone, Debug)]
+struct Tree<T>
+{
+	/// The intervals in the tree, sorted by their lower bound.
+	intervals: Vec<Interval<T>>,
+
+	/// The maximum upper bound within the subtree rooted at the same index of `intervals`.
+	max_upper: Vec<UpperBound<T>>,
+}
+
+impl<T> IntervalTree<T>
+where
+	T: Clone + Ord,
+{
+	/// Add the interval from `lower` to `upper` (inclusive), which is identified by `value`.
+	pub(crate) fn insert(&mut self, lower: Option<T>, upper: UpperBound<T>, value: usize)
+	{
+		self.intervals.push((lower, upper, value));
+		self.tree.take();
+	}
+
+	/// Add the `value` of each interval which contains the `point` to the `output`.
+	pub(crate) fn stab(&self, point: &T, output: &mut Vec<usize>)
+	{
+		let tree = self.tree.get_or_init(|| Tree::new(self.intervals.clone()));
+		tree.stab_range(0, tree.intervals.len(), point, output);
+	}
+}
+
+impl<T> Tree<T>
+where
+	T: Clone + Ord,
+{
+	/// Build a [`Tree`] from some `intervals`.
+	fn new(mut intervals: Vec<Interval<T>>) -> Self
+	{
+		intervals.sort_by(|(lower, ..), (other, ..)| lower.cmp(other));
+
+		let mut tree = Self {
+			max_upper: intervals
+				.iter()
+				.map(|(_, upper, _)| upper.clone())
+				.collect(),
+			intervals,
+		};
+		tree.augment(0, tree.intervals.len());
+		tree
+	}
+
+	/// Compute [`Tree::max_upper`] for the subtree over `intervals[start..end]`, returning the
+	/// maximum upper bound of the subtree.
+	fn augment(&mut self, start: usize, end: usize) -> Option<UpperBound<T>>
+	{
+		if start >= end
+		{
+			return None;
+		}
+
+		let mid = start + (end - start) / 2;
+		let left = self.augment(start, mid);
+		let right = self.augment(mid + 1, end);
+		let max = [left, right]
+			.into_iter()
+			.flatten()
+			.fold(self.max_upper[mid].clone(), Ord::max);
+		self.max_upper[mid] = max.clone();
+		Some(max)
+	}
+
+	/// [`IntervalTree::stab`] the subtree over `intervals[start..end]`.
+	fn stab_range(&self, start: usize, end: usize, point: &T, output: &mut Vec<usize>)
+	{
+		if start >= end
+		{
+			return;
+		}
+
+		let mid = start + (end - start) / 2;
+		if self.max_upper[mid].is_before(point)
+		{
+			return;
+		}
+
+		self.stab_range(start, mid, point, output);
+
+		let (ref lower, ref upper, value) = self.intervals[mid];
+		if lower.as_ref().is_none_or(|l| l <= point)
+		{
+			if !upper.is_before(point)
+			{
+				output.push(value);
+			}
+
+			self.stab_range(mid + 1, end, point, output);
+		}
+	}
+}
+
+impl<T> Default for IntervalTree<T>
+{
+	fn default() -> Self
+	{
+		Self {
+			intervals: Vec::new(),
+			tree: OnceLock::new(),
+		}
+	}
+}
@@ -0,0 +1,82 @@
+mod filter_matching_iterator;
+#[cfg(feature = "async")]
+mod filter_matching_stream;
+
+use core::{borrow::Borrow, marker::PhantomData};
+
+pub use filter_matching_iterator::FilterMatchingIterator;
+#[cfg(feature = "async")]
+pub use filter_matching_stream::FilterMatchingStream;
+use pin_project_lite::pin_project;
+
+use crate::IsMatch;
+
+pin_project! {
+	/// An [`Iterator`] (or, with the `async` feature, a `Stream`) which only yields the records
+	/// that match some [compiled](crate::Compile) matcher.
+	///
+	/// See [`FilterMatchingIterator::filter_matching`] for more information.
+	#[derive(Clone, Debug)]
+	#[must_use = "iterators and streams are lazy and do nothing unless consumed"]
+	pub struct FilterMatching<I, C, T>
+	where
+		T: ?Sized,
+	{
+		compiled: C,
+		#[pin]
+		inner: I,
+		record: PhantomData<fn(&T)>,
+	}
+}
+
+impl<I, C, T> FilterMatching<I, C, T>
+where
+	T: ?Sized,
+{
+	/// Create a new [`FilterMatching`] which yields the records from `inner` that match `compiled`.
+	pub(crate) const fn new(inner: I, compiled: C) -> Self
+	{
+		Self {
+			compiled,
+			inner,
+			record: PhantomData,
+		}
+	}
+}
+
+impl<I, C, T> DoubleEndedIterator for FilterMatching<I, C, T>
+where
+	C: IsMatch<T>,
+	I: DoubleEndedIterator,
+	I::Item: Borrow<T>,
+	T: ?Sized,
+{
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		let compiled = &self.compiled;
+		self
+			.inner
+			.rfind(|record| compiled.is_match(record.borrow()))
+	}
+}
+
+impl<I, C, T> Iterator for FilterMatching<I, C, T>
+where
+	C: IsMatch<T>,
+	I: Iterator,
+	I::Item: Borrow<T>,
+	T: ?Sized,
+{
+	type Item = I::Item;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let compiled = &self.compiled;
+		self.inner.find(|record| compiled.is_match(record.borrow()))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(0, self.inner.size_hint().1)
+	}
+}
@@ -0,0 +1,56 @@
+use core::borrow::Borrow;
+
+use super::FilterMatching;
+use crate::{Compile, IsMatch, Result};
+
+/// An extension trait for [`Iterator`]s which adds [`FilterMatchingIterator::filter_matching`].
+pub trait FilterMatchingIterator: Iterator + Sized
+{
+	/// Create an [`Iterator`] which only yields the records (e.g.
+	/// [`Timesheet`](clinvoice_schema::Timesheet)s, or `&Timesheet`s) that match the `condition`.
+	///
+	/// The `condition` is [compiled](Compile::compile) once, up front, rather than being
+	/// re-evaluated from scratch for every record.
+	///
+	/// # Errors
+	///
+	/// * When the `condition` cannot be [compiled](Compile::compile).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use clinvoice_match::{FilterMatchingIterator, MatchEmployee, MatchStr};
+	/// use clinvoice_schema::Employee;
+	/// # use pretty_assertions::assert_eq;
+	///
+	/// let employees = ["Alice", "Bob", "Carol"].map(|name| Employee {
+	///   id: 0,
+	///   name: name.into(),
+	///   status: "Hired".into(),
+	///   title: "Janitor".into(),
+	/// });
+	///
+	/// let condition = MatchEmployee {
+	///   name: MatchStr::Regex("^[AC]".into()),
+	///   ..Default::default()
+	/// };
+	///
+	/// assert_eq!(
+	///   employees.iter().filter_matching(&condition).unwrap().map(|e| e.name.as_str()).collect::<Vec<_>>(),
+	///   ["Alice", "Carol"],
+	/// );
+	/// ```
+	fn filter_matching<M, T>(self, condition: &M) -> Result<FilterMatching<Self, M::Compiled, T>>
+	where
+		M: Compile,
+		M::Compiled: IsMatch<T>,
+		Self::Item: Borrow<T>,
+		T: ?Sized,
+	{
+		condition
+			.compile()
+			.map(|compiled| FilterMatching::new(self, compiled))
+	}
+}
+
+impl<I> FilterMatchingIterator for I where I: Iterator {}
@@ -0,0 +1,95 @@
+use core::{
+	borrow::Borrow,
+	pin::Pin,
+	task::{ready, Context, Poll},
+};
+
+use futures_core::Stream;
+
+use super::FilterMatching;
+use crate::{Compile, IsMatch, Result};
+
+/// An extension trait for [`Stream`]s which adds [`FilterMatchingStream::filter_matching`].
+pub trait FilterMatchingStream: Stream + Sized
+{
+	/// Create a [`Stream`] which only yields the records (e.g.
+	/// [`Timesheet`](clinvoice_schema::Timesheet)s, or `&Timesheet`s) that match the `condition`.
+	///
+	/// The `condition` is [compiled](Compile::compile) once, up front, rather than being
+	/// re-evaluated from scratch for every record.
+	///
+	/// # Errors
+	///
+	/// * When the `condition` cannot be [compiled](Compile::compile).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use clinvoice_match::{FilterMatchingStream, MatchEmployee, MatchStr};
+	/// use clinvoice_schema::Employee;
+	/// use futures::{executor, stream, StreamExt};
+	/// # use pretty_assertions::assert_eq;
+	///
+	/// let employees = ["Alice", "Bob", "Carol"].map(|name| Employee {
+	///   id: 0,
+	///   name: name.into(),
+	///   status: "Hired".into(),
+	///   title: "Janitor".into(),
+	/// });
+	///
+	/// let condition = MatchEmployee {
+	///   name: MatchStr::Regex("^[AC]".into()),
+	///   ..Default::default()
+	/// };
+	///
+	/// let filtered = stream::iter(employees)
+	///   .filter_matching(&condition)
+	///   .unwrap()
+	///   .map(|e| e.name)
+	///   .collect::<Vec<_>>();
+	///
+	/// assert_eq!(executor::block_on(filtered), ["Alice", "Carol"]);
+	/// ```
+	fn filter_matching<M, T>(self, condition: &M) -> Result<FilterMatching<Self, M::Compiled, T>>
+	where
+		M: Compile,
+		M::Compiled: IsMatch<T>,
+		Self::Item: Borrow<T>,
+		T: ?Sized,
+	{
+		condition
+			.compile()
+			.map(|compiled| FilterMatching::new(self, compiled))
+	}
+}
+
+impl<S> FilterMatchingStream for S where S: Stream {}
+
+impl<S, C, T> Stream for FilterMatching<S, C, T>
+where
+	C: IsMatch<T>,
+	S: Stream,
+	S::Item: Borrow<T>,
+	T: ?Sized,
+{
+	type Item = S::Item;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>>
+	{
+		let mut this = self.project();
+		while let Some(record) = ready!(this.inner.as_mut().poll_next(cx))
+		{
+			if this.compiled.is_match(record.borrow())
+			{
+				return Poll::Ready(Some(record));
+			}
+		}
+
+		Poll::Ready(None)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(0, self.inner.size_hint().1)
+	}
+}
@@ -0,0 +1,39 @@
+mod text_search;
+
+pub use text_search::TextSearch;
+
+/// A matcher which can be created from an example record of type `T`, such as one which was
+/// partially filled in by a search form.
+///
+/// Every field of the `example` which has its [`Default`] value (e.g. an empty string, or
+/// [`None`]) matches anything, and every other field must be equal to the `example`'s. Strings are
+/// instead matched according to a [`TextSearch`].
+///
+/// # Examples
+///
+/// ```rust
+/// use clinvoice_match::{FromExample, Match, MatchEmployee, MatchStr, TextSearch};
+/// use clinvoice_schema::Employee;
+/// # use pretty_assertions::assert_eq;
+///
+/// let form = Employee { name: "bob".into(), status: "Hired".into(), ..Default::default() };
+///
+/// assert_eq!(MatchEmployee::from_example(&form, TextSearch::EqualTo), MatchEmployee {
+///   id: Match::Any,
+///   name: MatchStr::EqualTo("bob".into()),
+///   status: MatchStr::EqualTo("Hired".into()),
+///   title: MatchStr::Any,
+/// });
+///
+/// assert_eq!(MatchEmployee::from_example(&form, TextSearch::Prefix), MatchEmployee {
+///   name: MatchStr::Regex("(?i)^bob".into()),
+///   status: MatchStr::Regex("(?i)^Hired".into()),
+///   ..Default::default()
+/// });
+/// ```
+pub trait FromExample<T>
+{
+	/// Create a matcher for the records which are like the `example`, matching its strings
+	/// according to the `search`.
+	fn from_example(example: &T, search: TextSearch) -> Self;
+}
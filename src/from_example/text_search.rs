@@ -0,0 +1,37 @@
+use crate::MatchStr;
+
+/// How the strings of an example are matched by [`FromExample`](crate::FromExample).
+///
+/// Empty strings always match anything.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum TextSearch
+{
+	/// Match strings which contain the example's string, as with [`MatchStr::Contains`].
+	Contains,
+
+	/// Match strings which are equal to the example's string, as with [`MatchStr::EqualTo`].
+	#[default]
+	EqualTo,
+
+	/// Match strings which begin with the example's string, ignoring case.
+	Prefix,
+}
+
+impl TextSearch
+{
+	/// Create a [`MatchStr`] for the strings which are like the `example`.
+	pub(crate) fn condition(self, example: &str) -> MatchStr<String>
+	{
+		if example.is_empty()
+		{
+			return MatchStr::Any;
+		}
+
+		match self
+		{
+			Self::Contains => MatchStr::Contains(example.into()),
+			Self::EqualTo => MatchStr::EqualTo(example.into()),
+			Self::Prefix => MatchStr::Regex(format!("(?i)^{}", regex::escape(example))),
+		}
+	}
+}
@@ -0,0 +1,9 @@
+/// A [compiled](crate::Compile) matcher which can determine whether some value of type `T`
+/// matches it.
+pub trait IsMatch<T>
+where
+	T: ?Sized,
+{
+	/// Whether or not `value` matches `self`.
+	fn is_match(&self, value: &T) -> bool;
+}
@@ -0,0 +1,113 @@
+//! Stand-ins for foreign types which do not implement [`JsonSchema`], so that their serialized form
+//! can still be described in the schema of the types in this crate.
+
+use schemars::{
+	gen::SchemaGenerator,
+	schema::{InstanceType, Metadata, ObjectValidation, Schema, SchemaObject, StringValidation},
+	JsonSchema,
+};
+
+/// The schema of a [`Currency`](clinvoice_schema::Currency), which is its ISO 4217 code.
+pub(crate) enum Currency {}
+
+impl JsonSchema for Currency
+{
+	fn schema_name() -> String
+	{
+		"Currency".into()
+	}
+
+	fn json_schema(_: &mut SchemaGenerator) -> Schema
+	{
+		SchemaObject {
+			instance_type: Some(InstanceType::String.into()),
+			metadata: Some(Box::new(Metadata {
+				examples: vec!["USD".into()],
+				..Default::default()
+			})),
+			string: Some(Box::new(StringValidation {
+				pattern: Some("^[A-Z]{3}$".into()),
+				..Default::default()
+			})),
+			..Default::default()
+		}
+		.into()
+	}
+}
+
+/// The schema of a [`Serde<Duration>`](humantime_serde::Serde), which is a human-readable string.
+pub(crate) enum Duration {}
+
+impl JsonSchema for Duration
+{
+	fn schema_name() -> String
+	{
+		"Duration".into()
+	}
+
+	fn json_schema(_: &mut SchemaGenerator) -> Schema
+	{
+		SchemaObject {
+			instance_type: Some(InstanceType::String.into()),
+			metadata: Some(Box::new(Metadata {
+				description: Some(
+					"A human-readable duration, as understood by the `humantime` crate.".into(),
+				),
+				examples: vec!["5min".into(), "1h 30m".into()],
+				..Default::default()
+			})),
+			string: Some(Box::new(StringValidation {
+				pattern: Some(r"^\s*([0-9]+\s*[a-zA-Zµ]+\s*)+$".into()),
+				..Default::default()
+			})),
+			..Default::default()
+		}
+		.into()
+	}
+}
+
+/// The schema of a [`Money`](clinvoice_schema::Money).
+pub(crate) enum Money {}
+
+impl JsonSchema for Money
+{
+	fn schema_name() -> String
+	{
+		"Money".into()
+	}
+
+	fn json_schema(_: &mut SchemaGenerator) -> Schema
+	{
+		let string = |pattern: &str, example: &str| -> Schema {
+			SchemaObject {
+				instance_type: Some(InstanceType::String.into()),
+				metadata: Some(Box::new(Metadata {
+					examples: vec![example.into()],
+					..Default::default()
+				})),
+				string: Some(Box::new(StringValidation {
+					pattern: Some(pattern.into()),
+					..Default::default()
+				})),
+				..Default::default()
+			}
+			.into()
+		};
+
+		SchemaObject {
+			instance_type: Some(InstanceType::Object.into()),
+			object: Some(Box::new(ObjectValidation {
+				properties: [
+					("amount".into(), string(r"^-?[0-9]+(\.[0-9]+)?$", "15.00")),
+					("currency".into(), string("^[A-Z]{3}$", "USD")),
+				]
+				.into_iter()
+				.collect(),
+				required: ["amount".into(), "currency".into()].into_iter().collect(),
+				..Default::default()
+			})),
+			..Default::default()
+		}
+		.into()
+	}
+}
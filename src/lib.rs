@@ -9,13 +9,31 @@
 //!
 //! # Features
 //!
-//! * `serde` adds support for the [`serde`] crate. This crate is intended for and tested
-//!   with [`serde_yaml`](https://docs.serde.rs/serde_yaml/) in particular.
+//! * `async` adds [`FilterMatchingStream`], for filtering `Stream`s of records.
+//! * `binary` adds [`binary`], a compact encoding for matchers (and enables `serde`).
+//! * `proptest` implements [`proptest::arbitrary::Arbitrary`] for every matcher, with a configurable
+//!   [`Recursion`], and adds [`RecordStrategy`], which generates records that do or do not match a
+//!   matcher. This is useful for testing that adapters agree with [`IsMatch`].
+//! * `rayon` adds [`ParIsMatch`], for evaluating a matcher against many records in parallel.
+//! * `schemars` derives [`schemars::JsonSchema`] for every matcher (and enables `serde`), so that
+//!   filters can be validated by other tools (e.g. web editors).
+//! * `serde` adds support for the [`serde`] crate. This crate is tested with
+//!   [`serde_json`](https://docs.rs/serde_json/), [`serde_yaml`](https://docs.rs/serde_yaml/), and
+//!   [`toml`](https://docs.rs/toml/). Note that dates must be written as strings in TOML (e.g.
+//!   `"2022-01-01T00:00:00"`), rather than as TOML's own date-time values.
+//! * `testing` adds [`conformance`], a suite which checks that storage adapters select the same
+//!   records as [`IsMatch`].
+//! * `yaml` adds [`Document`]s (and enables `serde`), which record the version of the format that a
+//!   filter was written in so that it can be upgraded by [`Migrations`] when the format changes,
+//!   [`Library`]s of named fragments which filters can reuse, [`Template`]s, which contain
+//!   placeholders that are filled in by [`Parameters`], and [`Limits`] on the size and complexity
+//!   of filters from untrusted sources.
 //!
 //! # Re-exports
 //!
 //! This crate re-exports [`humantime_serde::Serde`], as it is required to deserialize the
-//! `increment` of a [`MatchJob`] via human-readable time (e.g. "15min").
+//! `increment` of a [`MatchJob`] and the `duration` of a [`MatchTimesheet`] via human-readable time
+//! (e.g. "15min").
 //!
 //! # Examples
 //!
@@ -102,6 +120,25 @@
 	clippy::wildcard_imports
 )]
 
+mod aggregation;
+#[cfg(feature = "binary")]
+pub mod binary;
+mod compile;
+#[cfg(feature = "testing")]
+pub mod conformance;
+#[cfg(feature = "yaml")]
+mod document;
+mod error;
+mod explain;
+mod field_path;
+mod filter_index;
+mod filter_matching;
+mod from_example;
+mod is_match;
+#[cfg(feature = "schemars")]
+mod json_schema;
+#[cfg(feature = "yaml")]
+mod limits;
 mod r#match;
 mod match_contact;
 mod match_employee;
@@ -114,17 +151,66 @@ mod match_organization;
 mod match_set;
 mod match_str;
 mod match_timesheet;
+#[cfg(feature = "rayon")]
+mod par_is_match;
+mod pattern;
+mod query;
+mod result;
+mod satisfy;
+#[cfg(feature = "serde")]
+mod shorthand;
+#[cfg(feature = "proptest")]
+mod strategy;
+#[cfg(feature = "yaml")]
+mod template;
+mod visit;
 
+pub use aggregation::{Aggregate, Aggregation, Group};
+pub use compile::Compile;
+#[cfg(feature = "yaml")]
+pub use document::{Document, Library, Migration, Migrations, DOCUMENT_VERSION};
+pub use error::Error;
+pub use explain::{Explain, Explanation};
+pub use field_path::FieldPath;
+pub use filter_index::{FilterIndex, Indexable};
+#[cfg(feature = "async")]
+pub use filter_matching::FilterMatchingStream;
+pub use filter_matching::{FilterMatching, FilterMatchingIterator};
+pub use from_example::{FromExample, TextSearch};
 pub use humantime_serde::Serde;
-pub use match_contact::{MatchContact, MatchContactKind};
-pub use match_employee::MatchEmployee;
-pub use match_expense::MatchExpense;
-pub use match_invoice::MatchInvoice;
-pub use match_job::MatchJob;
-pub use match_location::{MatchLocation, MatchOuterLocation};
-pub use match_option::MatchOption;
-pub use match_organization::MatchOrganization;
-pub use match_set::MatchSet;
-pub use match_str::MatchStr;
-pub use match_timesheet::MatchTimesheet;
-pub use r#match::Match;
+pub use is_match::IsMatch;
+#[cfg(feature = "yaml")]
+pub use limits::{Limit, Limits, RegexEngine};
+pub use match_contact::{
+	CompiledMatchContact,
+	CompiledMatchContactKind,
+	MatchContact,
+	MatchContactKind,
+};
+pub use match_employee::{CompiledMatchEmployee, MatchEmployee};
+pub use match_expense::{CompiledMatchExpense, MatchExpense};
+pub use match_invoice::{CompiledMatchInvoice, MatchInvoice};
+pub use match_job::{CompiledMatchJob, MatchJob};
+pub use match_location::{
+	CompiledMatchLocation,
+	CompiledMatchOuterLocation,
+	MatchLocation,
+	MatchOuterLocation,
+};
+pub use match_option::{CompiledMatchOption, MatchOption};
+pub use match_organization::{CompiledMatchOrganization, MatchOrganization};
+pub use match_set::{CompiledMatchSet, MatchSet};
+pub use match_str::{CompiledMatchStr, MatchStr};
+pub use match_timesheet::{CompiledMatchTimesheet, MatchTimesheet};
+#[cfg(feature = "rayon")]
+pub use par_is_match::ParIsMatch;
+pub use pattern::{Construct, Dialect, Pattern};
+pub use query::{Cursor, Direction, FieldValue, OrderBy, Orderable, Query};
+pub use r#match::{CompiledMatch, Match};
+pub use result::Result;
+pub use satisfy::{Satisfiability, Satisfy};
+#[cfg(feature = "proptest")]
+pub use strategy::{RecordStrategy, Recursion};
+#[cfg(feature = "yaml")]
+pub use template::{Parameters, Template};
+pub use visit::{Fold, Folder, Visit, VisitMut, Visitor, VisitorMut};
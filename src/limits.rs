@@ -0,0 +1,216 @@
+mod default;
+mod limit;
+mod regex_engine;
+
+pub use limit::Limit;
+use regex::RegexBuilder;
+pub use regex_engine::RegexEngine;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_yaml::Value;
+
+use crate::{Compile, Error, FieldPath, Result};
+
+/// Limits on the size and complexity of filters, so that filters from untrusted sources (e.g. the
+/// users of an API) cannot exhaust the stack or hang evaluation.
+///
+/// Limits are measured on the YAML representation of a filter, in which every mapping, sequence,
+/// and scalar is a node, and the fields which lead to a node are its [path](FieldPath). A filter is
+/// checked against the limits as it is [read](Limits::from_value), before it is deserialized into a
+/// matcher, or afterwards by [`Limits::check`]. [`Document`](crate::Document)s and
+/// [`Template`](crate::Template)s are checked once their fragments and parameters have been filled
+/// in, so that a fragment or parameter cannot be used to avoid the limits.
+///
+/// # Examples
+///
+/// ```rust
+/// use clinvoice_match::{Error, Limit, Limits, MatchEmployee, RegexEngine};
+/// # use pretty_assertions::assert_eq;
+///
+/// let limits = Limits { max_depth: 4, ..Default::default() };
+///
+/// let nested = "name: {not: {not: {not: {equal_to: Bob}}}}";
+/// assert!(matches!(
+///   limits.from_str::<MatchEmployee>(nested),
+///   Err(Error::LimitExceeded { limit: Limit::Depth, maximum: 4, .. }),
+/// ));
+/// assert_eq!(
+///   limits.from_str::<MatchEmployee>(nested).unwrap_err().to_string(),
+///   "the filter exceeds the maximum nesting depth (4) at `name.not.not.not`",
+/// );
+///
+/// let limits = Limits { regex_engine: RegexEngine::Disabled, ..Default::default() };
+/// assert!(matches!(
+///   limits.from_str::<MatchEmployee>("title: {regex: '^(a+)+$'}"),
+///   Err(Error::RegexDisabled { .. }),
+/// ));
+/// assert!(limits.from_str::<MatchEmployee>("title: {contains: CEO}").is_ok());
+/// ```
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(default)]
+pub struct Limits
+{
+	/// The greatest number of nodes which may be nested inside one another.
+	pub max_depth: usize,
+
+	/// The greatest number of items that a sequence (e.g. the conditions of a
+	/// [`Match::Or`](crate::Match::Or)) may contain.
+	pub max_list_length: usize,
+
+	/// The greatest number of nodes that a filter may contain.
+	pub max_nodes: usize,
+
+	/// The greatest number of bytes that the compiled form of a
+	/// [`MatchStr::Regex`](crate::MatchStr::Regex) may use.
+	pub max_regex_size: usize,
+
+	/// How [`MatchStr::Regex`](crate::MatchStr::Regex) conditions are evaluated.
+	pub regex_engine: RegexEngine,
+}
+
+impl Limits
+{
+	/// Check that some `matcher` is within these limits.
+	///
+	/// # Errors
+	///
+	/// * When the `matcher` exceeds one of the limits.
+	/// * When the `matcher` contains a [`MatchStr::Regex`](crate::MatchStr::Regex) which is not
+	///   allowed by the [`RegexEngine`], or is not valid.
+	/// * When the `matcher` cannot be serialized.
+	pub fn check<M>(&self, matcher: &M) -> Result<()>
+	where
+		M: Serialize,
+	{
+		self.check_value(&serde_yaml::to_value(matcher)?)
+	}
+
+	/// Check that the YAML `value` of some filter is within these limits.
+	///
+	/// # Errors
+	///
+	/// * See [`Limits::check`].
+	pub fn check_value(&self, value: &Value) -> Result<()>
+	{
+		let mut nodes = 0;
+		self.check_node(value, &FieldPath::default(), 1, &mut nodes)
+	}
+
+	/// Check that a `value` located at the `path`, which is nested `depth` nodes deep, is within
+	/// these limits, given the number of `nodes` which have already been checked.
+	fn check_node(
+		&self,
+		value: &Value,
+		path: &FieldPath,
+		depth: usize,
+		nodes: &mut usize,
+	) -> Result<()>
+	{
+		let exceeded = |limit, maximum| Error::LimitExceeded {
+			limit,
+			maximum,
+			path: path.clone(),
+		};
+
+		if depth > self.max_depth
+		{
+			return Err(exceeded(Limit::Depth, self.max_depth));
+		}
+
+		*nodes += 1;
+		if *nodes > self.max_nodes
+		{
+			return Err(exceeded(Limit::Nodes, self.max_nodes));
+		}
+
+		match value
+		{
+			Value::Mapping(mapping) => mapping.iter().try_for_each(|(key, value)| {
+				let field = key.as_str().map_or_else(|| format!("{key:?}"), Into::into);
+				let path = path.join(field);
+
+				if let (Some("regex"), Value::String(pattern)) = (key.as_str(), value)
+				{
+					self.check_regex(pattern, &path)?;
+				}
+
+				self.check_node(value, &path, depth + 1, nodes)
+			}),
+			Value::Sequence(sequence) =>
+			{
+				if sequence.len() > self.max_list_length
+				{
+					return Err(exceeded(Limit::ListLength, self.max_list_length));
+				}
+
+				sequence.iter().enumerate().try_for_each(|(index, value)| {
+					self.check_node(value, &path.join(index.to_string()), depth + 1, nodes)
+				})
+			},
+			_ => Ok(()),
+		}
+	}
+
+	/// Check that the `pattern` of a [`MatchStr::Regex`](crate::MatchStr::Regex) located at the
+	/// `path` is within these limits.
+	fn check_regex(&self, pattern: &str, path: &FieldPath) -> Result<()>
+	{
+		match self.regex_engine
+		{
+			RegexEngine::Disabled => Err(Error::RegexDisabled { path: path.clone() }),
+			RegexEngine::Linear => RegexBuilder::new(pattern)
+				.size_limit(self.max_regex_size)
+				.dfa_size_limit(self.max_regex_size)
+				.build()
+				.map(drop)
+				.map_err(|e| match e
+				{
+					regex::Error::CompiledTooBig(_) => Error::LimitExceeded {
+						limit: Limit::RegexSize,
+						maximum: self.max_regex_size,
+						path: path.clone(),
+					},
+					e => e.into(),
+				}),
+		}
+	}
+
+	/// [Check](Limits::check) some `matcher`, and then [compile](Compile::compile) it.
+	///
+	/// # Errors
+	///
+	/// * See [`Limits::check`].
+	pub fn compile<M>(&self, matcher: &M) -> Result<M::Compiled>
+	where
+		M: Compile + Serialize,
+	{
+		self.check(matcher)?;
+		matcher.compile()
+	}
+
+	/// Read a matcher from some `value`, after checking that it is within these limits.
+	///
+	/// # Errors
+	///
+	/// * See [`Limits::check`].
+	/// * When the `value` is not a valid `M`.
+	pub fn from_value<M>(&self, value: Value) -> Result<M>
+	where
+		M: DeserializeOwned,
+	{
+		self.check_value(&value)?;
+		serde_yaml::from_value(value).map_err(Into::into)
+	}
+
+	/// Read a matcher from some `yaml`, after checking that it is within these limits.
+	///
+	/// # Errors
+	///
+	/// * See [`Limits::from_value`].
+	/// * When the `yaml` is not valid.
+	pub fn from_str<M>(&self, yaml: &str) -> Result<M>
+	where
+		M: DeserializeOwned,
+	{
+		self.from_value(serde_yaml::from_str(yaml)?)
+	}
+}
@@ -0,0 +1,15 @@
+use super::{Limits, RegexEngine};
+
+impl Default for Limits
+{
+	fn default() -> Self
+	{
+		Self {
+			max_depth: 64,
+			max_list_length: 1000,
+			max_nodes: 10_000,
+			max_regex_size: 1 << 20,
+			regex_engine: RegexEngine::default(),
+		}
+	}
+}
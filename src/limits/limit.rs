@@ -0,0 +1,18 @@
+mod display;
+
+/// One of the [`Limits`](crate::Limits) on a filter.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Limit
+{
+	/// See [`Limits::max_depth`](crate::Limits::max_depth).
+	Depth,
+
+	/// See [`Limits::max_list_length`](crate::Limits::max_list_length).
+	ListLength,
+
+	/// See [`Limits::max_nodes`](crate::Limits::max_nodes).
+	Nodes,
+
+	/// See [`Limits::max_regex_size`](crate::Limits::max_regex_size).
+	RegexSize,
+}
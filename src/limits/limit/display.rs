@@ -0,0 +1,17 @@
+use core::fmt::{Display, Formatter, Result};
+
+use super::Limit;
+
+impl Display for Limit
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result
+	{
+		f.write_str(match self
+		{
+			Self::Depth => "nesting depth",
+			Self::ListLength => "list length",
+			Self::Nodes => "number of nodes",
+			Self::RegexSize => "regex size",
+		})
+	}
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// How [`MatchStr::Regex`](crate::MatchStr::Regex) conditions are evaluated, according to
+/// [`Limits::regex_engine`](crate::Limits::regex_engine).
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RegexEngine
+{
+	/// Regular expressions are not allowed.
+	Disabled,
+
+	/// Regular expressions are evaluated by the [`regex`] crate, which takes time linear in the size
+	/// of the regular expression and the value being matched. Features which require backtracking
+	/// (e.g. look-around and backreferences) are not supported.
+	#[default]
+	Linear,
+}
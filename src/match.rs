@@ -1,11 +1,25 @@
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod compiled_match;
 mod default;
+#[cfg(feature = "serde")]
+mod deserialize;
 mod exchange;
+mod explain;
 mod from;
+mod from_example;
+#[cfg(feature = "schemars")]
+mod json_schema;
+#[cfg(feature = "proptest")]
+mod record_strategy;
+mod satisfy;
+mod visit;
 
-use core::{cmp::Eq, fmt::Debug};
+use core::{cmp::Eq, convert::Infallible, fmt::Debug};
 
+pub use compiled_match::CompiledMatch;
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 
 /// A value which describes the condition which some value of type `T` must meet in order to
 /// "_match_".
@@ -108,6 +122,29 @@ use serde::{Deserialize, Serialize};
 /// # ").is_ok());
 /// ```
 ///
+/// ### Shorthand
+///
+/// A bare value is shorthand for [`Match::EqualTo`], and a list of values is shorthand for
+/// [`Match::Or`] of [`Match::EqualTo`]. Serializing a [`Match`] always uses the explicit form.
+///
+/// A map with a single key which names a condition (e.g. `equal_to`) is always read as that
+/// condition, so mistakes within it are reported rather than tried as shorthand.
+///
+/// ```rust
+/// # use clinvoice_match::Match;
+/// # use pretty_assertions::assert_eq;
+/// # use serde_yaml::from_str;
+/// # assert_eq!(from_str::<Match<isize>>("
+/// 3
+/// # ").unwrap(), Match::EqualTo(3));
+///
+/// // -----------------------
+///
+/// # assert_eq!(from_str::<Match<isize>>("
+/// [1, 2, 3]
+/// # ").unwrap(), Match::Or(vec![1.into(), 2.into(), 3.into()]));
+/// ```
+///
 /// ### Warnings
 ///
 /// Never use the following, as it is always `false` and often begets an error:
@@ -117,11 +154,7 @@ use serde::{Deserialize, Serialize};
 /// not: any
 /// # ").is_ok());
 /// ```
-#[cfg_attr(
-	feature = "serde",
-	derive(Deserialize, Serialize),
-	serde(rename_all = "snake_case")
-)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "snake_case"))]
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Match<T>
 {
@@ -170,27 +203,13 @@ impl<T> Match<T>
 	///   Match::EqualTo(5)
 	/// );
 	/// ```
-	pub fn map<F, MapTo>(self, f: F) -> Match<MapTo>
+	pub fn map<F, MapTo>(self, mut f: F) -> Match<MapTo>
 	where
-		F: Copy + Fn(T) -> MapTo,
+		F: FnMut(T) -> MapTo,
 	{
-		match self
-		{
-			Self::And(match_conditions) =>
-			{
-				Match::And(match_conditions.into_iter().map(|m| m.map(f)).collect())
-			},
-			Self::Any => Match::Any,
-			Self::EqualTo(x) => Match::EqualTo(f(x)),
-			Self::GreaterThan(x) => Match::GreaterThan(f(x)),
-			Self::InRange(low, high) => Match::InRange(f(low), f(high)),
-			Self::LessThan(x) => Match::LessThan(f(x)),
-			Self::Not(match_condition) => Match::Not(match_condition.map(f).into()),
-			Self::Or(match_conditions) =>
-			{
-				Match::Or(match_conditions.into_iter().map(|m| m.map(f)).collect())
-			},
-		}
+		self
+			.try_map(|x| Ok::<_, Infallible>(f(x)))
+			.unwrap_or_else(|e| match e {})
 	}
 
 	/// Transform some [`Match`] of type `T` into another type `U` by providing a mapping `f`unction.
@@ -198,26 +217,130 @@ impl<T> Match<T>
 	/// # See also
 	///
 	/// * [`Match::map`]
-	pub fn map_ref<F, MapTo>(&self, f: F) -> Match<MapTo>
+	pub fn map_ref<F, MapTo>(&self, mut f: F) -> Match<MapTo>
+	where
+		F: FnMut(&T) -> MapTo,
+	{
+		self
+			.try_map_ref(|x| Ok::<_, Infallible>(f(x)))
+			.unwrap_or_else(|e| match e {})
+	}
+
+	/// Transform some [`Match`] of type `T` into another type `U` by providing a fallible mapping
+	/// `f`unction. The first [`Err`] returned by `f` is returned immediately.
+	///
+	/// # See also
+	///
+	/// * [`Match::map`]
+	/// * [`Iterator::try_fold`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use clinvoice_match::Match;
+	/// # use pretty_assertions::assert_eq;
+	///
+	/// assert_eq!(
+	///   Match::EqualTo("5").try_map(str::parse::<isize>),
+	///   Ok(Match::EqualTo(5))
+	/// );
+	///
+	/// assert!(Match::EqualTo("five").try_map(str::parse::<isize>).is_err());
+	/// ```
+	///
+	/// Because `f` is [`FnMut`], it may keep state between calls:
+	///
+	/// ```rust
+	/// use std::collections::HashMap;
+	///
+	/// use clinvoice_match::Match;
+	/// # use pretty_assertions::assert_eq;
+	///
+	/// let mut cache = HashMap::new();
+	/// let condition = Match::Or(vec![Match::EqualTo("1"), Match::Not(Box::new(Match::EqualTo("1")))]);
+	///
+	/// assert_eq!(
+	///   condition.try_map(|s| {
+	///     let len = cache.len();
+	///     s.parse::<isize>().map(|i| *cache.entry(i).or_insert(i + len as isize))
+	///   }),
+	///   Ok(Match::Or(vec![Match::EqualTo(1), Match::Not(Box::new(Match::EqualTo(1)))])),
+	/// );
+	/// assert_eq!(cache.len(), 1);
+	/// ```
+	pub fn try_map<E, F, MapTo>(self, mut f: F) -> Result<Match<MapTo>, E>
+	where
+		F: FnMut(T) -> Result<MapTo, E>,
+	{
+		self.try_map_with(&mut f)
+	}
+
+	/// Transform some [`Match`] of type `T` into another type `U` by providing a fallible mapping
+	/// `f`unction. The first [`Err`] returned by `f` is returned immediately.
+	///
+	/// # See also
+	///
+	/// * [`Match::try_map`]
+	pub fn try_map_ref<E, F, MapTo>(&self, mut f: F) -> Result<Match<MapTo>, E>
+	where
+		F: FnMut(&T) -> Result<MapTo, E>,
+	{
+		self.try_map_ref_with(&mut f)
+	}
+
+	/// Implementation of [`Match::try_map_ref`] which can be called recursively.
+	fn try_map_ref_with<E, F, MapTo>(&self, f: &mut F) -> Result<Match<MapTo>, E>
+	where
+		F: FnMut(&T) -> Result<MapTo, E>,
+	{
+		Ok(match self
+		{
+			Self::And(match_conditions) => Match::And(
+				match_conditions
+					.iter()
+					.map(|m| m.try_map_ref_with(f))
+					.collect::<Result<_, _>>()?,
+			),
+			Self::Any => Match::Any,
+			Self::EqualTo(x) => Match::EqualTo(f(x)?),
+			Self::GreaterThan(x) => Match::GreaterThan(f(x)?),
+			Self::InRange(low, high) => Match::InRange(f(low)?, f(high)?),
+			Self::LessThan(x) => Match::LessThan(f(x)?),
+			Self::Not(match_condition) => Match::Not(match_condition.try_map_ref_with(f)?.into()),
+			Self::Or(match_conditions) => Match::Or(
+				match_conditions
+					.iter()
+					.map(|m| m.try_map_ref_with(f))
+					.collect::<Result<_, _>>()?,
+			),
+		})
+	}
+
+	/// Implementation of [`Match::try_map`] which can be called recursively.
+	fn try_map_with<E, F, MapTo>(self, f: &mut F) -> Result<Match<MapTo>, E>
 	where
-		F: Copy + Fn(&T) -> MapTo,
+		F: FnMut(T) -> Result<MapTo, E>,
 	{
-		match self
+		Ok(match self
 		{
-			Self::And(match_conditions) =>
-			{
-				Match::And(match_conditions.iter().map(|m| m.map_ref(f)).collect())
-			},
+			Self::And(match_conditions) => Match::And(
+				match_conditions
+					.into_iter()
+					.map(|m| m.try_map_with(f))
+					.collect::<Result<_, _>>()?,
+			),
 			Self::Any => Match::Any,
-			Self::EqualTo(x) => Match::EqualTo(f(x)),
-			Self::GreaterThan(x) => Match::GreaterThan(f(x)),
-			Self::InRange(low, high) => Match::InRange(f(low), f(high)),
-			Self::LessThan(x) => Match::LessThan(f(x)),
-			Self::Not(match_condition) => Match::Not(match_condition.map_ref(f).into()),
-			Self::Or(match_conditions) =>
-			{
-				Match::Or(match_conditions.iter().map(|m| m.map_ref(f)).collect())
-			},
-		}
+			Self::EqualTo(x) => Match::EqualTo(f(x)?),
+			Self::GreaterThan(x) => Match::GreaterThan(f(x)?),
+			Self::InRange(low, high) => Match::InRange(f(low)?, f(high)?),
+			Self::LessThan(x) => Match::LessThan(f(x)?),
+			Self::Not(match_condition) => Match::Not((*match_condition).try_map_with(f)?.into()),
+			Self::Or(match_conditions) => Match::Or(
+				match_conditions
+					.into_iter()
+					.map(|m| m.try_map_with(f))
+					.collect::<Result<_, _>>()?,
+			),
+		})
 	}
 }
@@ -0,0 +1,107 @@
+use super::Match;
+use crate::{Compile, IsMatch, Result};
+
+/// A [`Match`] which has been [compiled](Compile).
+///
+/// The only difference from [`Match`] is that an [`Or`](Match::Or) which only contains
+/// [`EqualTo`](Match::EqualTo) conditions is compiled into [`In`](Self::In).
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CompiledMatch<T>
+{
+	/// Same as [`Match::And`].
+	And(Vec<Self>),
+
+	/// Same as [`Match::Any`].
+	Any,
+
+	/// Same as [`Match::EqualTo`].
+	EqualTo(T),
+
+	/// Same as [`Match::GreaterThan`].
+	GreaterThan(T),
+
+	/// Match IFF some value `v` is equal to any of the contained values, which are sorted and
+	/// deduplicated.
+	In(Box<[T]>),
+
+	/// Same as [`Match::InRange`].
+	InRange(T, T),
+
+	/// Same as [`Match::LessThan`].
+	LessThan(T),
+
+	/// Same as [`Match::Not`].
+	Not(Box<Self>),
+
+	/// Same as [`Match::Or`].
+	Or(Vec<Self>),
+}
+
+impl<T> Compile for Match<T>
+where
+	T: Clone + Ord,
+{
+	type Compiled = CompiledMatch<T>;
+
+	fn compile(&self) -> Result<Self::Compiled>
+	{
+		Ok(match self
+		{
+			Self::And(conditions) => CompiledMatch::And(
+				conditions
+					.iter()
+					.map(Self::compile)
+					.collect::<Result<_>>()?,
+			),
+			Self::Any => CompiledMatch::Any,
+			Self::EqualTo(x) => CompiledMatch::EqualTo(x.clone()),
+			Self::GreaterThan(x) => CompiledMatch::GreaterThan(x.clone()),
+			Self::InRange(low, high) => CompiledMatch::InRange(low.clone(), high.clone()),
+			Self::LessThan(x) => CompiledMatch::LessThan(x.clone()),
+			Self::Not(condition) => CompiledMatch::Not(condition.compile()?.into()),
+			Self::Or(conditions) => match conditions
+				.iter()
+				.map(|c| match c
+				{
+					Self::EqualTo(x) => Some(x.clone()),
+					_ => None,
+				})
+				.collect::<Option<Vec<_>>>()
+			{
+				Some(mut values) =>
+				{
+					values.sort_unstable();
+					values.dedup();
+					CompiledMatch::In(values.into())
+				},
+				None => CompiledMatch::Or(
+					conditions
+						.iter()
+						.map(Self::compile)
+						.collect::<Result<_>>()?,
+				),
+			},
+		})
+	}
+}
+
+impl<T> IsMatch<T> for CompiledMatch<T>
+where
+	T: Ord,
+{
+	fn is_match(&self, value: &T) -> bool
+	{
+		match self
+		{
+			Self::And(conditions) => conditions.iter().all(|c| c.is_match(value)),
+			Self::Any => true,
+			Self::EqualTo(x) => value == x,
+			Self::GreaterThan(x) => value > x,
+			Self::In(values) => values.binary_search(value).is_ok(),
+			Self::InRange(low, high) => low <= value && value < high,
+			Self::LessThan(x) => value < x,
+			Self::Not(condition) => !condition.is_match(value),
+			Self::Or(conditions) => conditions.iter().any(|c| c.is_match(value)),
+		}
+	}
+}
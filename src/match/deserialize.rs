@@ -0,0 +1,112 @@
+use serde::{de, Deserialize, Deserializer};
+
+use super::Match;
+use crate::shorthand::{Condition, Conditions, Unknown};
+
+/// The explicit form of a [`Match`], which is also how it is serialized.
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Deserialize)]
+#[serde(rename = "Match", rename_all = "snake_case")]
+pub(super) enum Explicit<T>
+{
+	And(Vec<Match<T>>),
+	Any,
+	EqualTo(T),
+	GreaterThan(T),
+	InRange(T, T),
+	LessThan(T),
+	Not(Box<Match<T>>),
+	Or(Vec<Match<T>>),
+}
+
+impl<T> Conditions for Explicit<T>
+{
+	const CONDITIONS: &'static [&'static str] = &[
+		"and",
+		"any",
+		"equal_to",
+		"greater_than",
+		"in_range",
+		"less_than",
+		"not",
+		"or",
+	];
+}
+
+/// The forms that a [`Match`] can be written in.
+///
+/// Only [human-readable](Deserializer::is_human_readable) formats can tell these apart, so the
+/// others always use the [`Explicit`] form.
+#[derive(Deserialize)]
+#[serde(
+	expecting = "a condition (e.g. `equal_to: 3`), a value, or a list of values",
+	untagged
+)]
+enum Shorthand<T>
+{
+	/// See [`Condition`].
+	Condition(Condition<Explicit<T>>),
+
+	/// Shorthand for [`Match::Or`] of [`Match::EqualTo`].
+	Many(Vec<T>),
+
+	/// Shorthand for [`Match::EqualTo`].
+	One(T),
+
+	/// See [`Unknown`].
+	Unknown(Unknown<Explicit<T>>),
+}
+
+impl<T> From<Explicit<T>> for Match<T>
+{
+	fn from(explicit: Explicit<T>) -> Self
+	{
+		match explicit
+		{
+			Explicit::And(conditions) => Self::And(conditions),
+			Explicit::Any => Self::Any,
+			Explicit::EqualTo(x) => Self::EqualTo(x),
+			Explicit::GreaterThan(x) => Self::GreaterThan(x),
+			Explicit::InRange(low, high) => Self::InRange(low, high),
+			Explicit::LessThan(x) => Self::LessThan(x),
+			Explicit::Not(condition) => Self::Not(condition),
+			Explicit::Or(conditions) => Self::Or(conditions),
+		}
+	}
+}
+
+impl<T> Shorthand<T>
+{
+	/// Turn this shorthand into a [`Match`], or explain why it is not one.
+	fn into_match<E>(self) -> Result<Match<T>, E>
+	where
+		E: de::Error,
+	{
+		match self
+		{
+			Self::Condition(condition) => condition.into_result().map(Into::into),
+			Self::Many(values) => Ok(Match::Or(values.into_iter().map(Match::EqualTo).collect())),
+			Self::One(value) => Ok(Match::EqualTo(value)),
+			Self::Unknown(unknown) => Err(unknown.into_error()),
+		}
+	}
+}
+
+impl<'de, T> Deserialize<'de> for Match<T>
+where
+	T: Deserialize<'de>,
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		if deserializer.is_human_readable()
+		{
+			Shorthand::deserialize(deserializer)?.into_match()
+		}
+		else
+		{
+			Explicit::deserialize(deserializer).map(Into::into)
+		}
+	}
+}
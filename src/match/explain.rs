@@ -0,0 +1,43 @@
+use core::fmt::Debug;
+
+use super::Match;
+use crate::{Explain, Explanation, Result};
+
+impl<T> Explain<T> for Match<T>
+where
+	T: Debug + PartialOrd,
+{
+	fn explain(&self, value: &T) -> Result<Explanation>
+	{
+		Ok(match self
+		{
+			Self::And(conditions) => Explanation::all(
+				"and",
+				conditions
+					.iter()
+					.map(|c| c.explain(value))
+					.collect::<Result<_>>()?,
+			),
+			Self::Any => Explanation::constant("any", true),
+			Self::EqualTo(x) => Explanation::comparison(format!("equal_to {x:?}"), value == x, value),
+			Self::GreaterThan(x) =>
+			{
+				Explanation::comparison(format!("greater_than {x:?}"), value > x, value)
+			},
+			Self::InRange(low, high) => Explanation::comparison(
+				format!("in_range [{low:?}, {high:?}]"),
+				low <= value && value < high,
+				value,
+			),
+			Self::LessThan(x) => Explanation::comparison(format!("less_than {x:?}"), value < x, value),
+			Self::Not(condition) => Explanation::not(condition.explain(value)?),
+			Self::Or(conditions) => Explanation::any(
+				"or",
+				conditions
+					.iter()
+					.map(|c| c.explain(value))
+					.collect::<Result<_>>()?,
+			),
+		})
+	}
+}
@@ -0,0 +1,16 @@
+use super::Match;
+use crate::{FromExample, TextSearch};
+
+impl<T> FromExample<T> for Match<T>
+where
+	T: Clone + Default + PartialEq,
+{
+	fn from_example(example: &T, _: TextSearch) -> Self
+	{
+		match *example == T::default()
+		{
+			true => Self::Any,
+			false => Self::EqualTo(example.clone()),
+		}
+	}
+}
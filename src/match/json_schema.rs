@@ -0,0 +1,33 @@
+use schemars::{
+	gen::SchemaGenerator,
+	schema::{Schema, SchemaObject, SubschemaValidation},
+	JsonSchema,
+};
+
+use super::{deserialize::Explicit, Match};
+
+impl<T> JsonSchema for Match<T>
+where
+	T: JsonSchema,
+{
+	fn schema_name() -> String
+	{
+		format!("Match_for_{}", T::schema_name())
+	}
+
+	fn json_schema(gen: &mut SchemaGenerator) -> Schema
+	{
+		SchemaObject {
+			subschemas: Some(Box::new(SubschemaValidation {
+				any_of: Some(vec![
+					Explicit::<T>::json_schema(gen),
+					gen.subschema_for::<Vec<T>>(),
+					gen.subschema_for::<T>(),
+				]),
+				..Default::default()
+			})),
+			..Default::default()
+		}
+		.into()
+	}
+}
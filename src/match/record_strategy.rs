@@ -0,0 +1,24 @@
+use proptest::prelude::BoxedStrategy;
+
+use super::Match;
+use crate::{satisfy, strategy, RecordStrategy};
+
+impl<T> RecordStrategy for Match<T>
+where
+	T: satisfy::Domain + strategy::Value,
+{
+	fn matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		strategy::matching::<_, T>(self, Self::records())
+	}
+
+	fn not_matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		Self::Not(self.clone().into()).matching()
+	}
+
+	fn records() -> BoxedStrategy<Self::Record>
+	{
+		T::strategy()
+	}
+}
@@ -0,0 +1,44 @@
+use super::Match;
+use crate::{satisfy, Compile, FieldPath, IsMatch, Satisfiability, Satisfy};
+
+/// Add every value which the `condition` compares against to the `constants`.
+fn constants<T>(condition: &Match<T>, constants: &mut Vec<T>)
+where
+	T: Clone,
+{
+	match condition
+	{
+		Match::And(conditions) | Match::Or(conditions) =>
+		{
+			conditions
+				.iter()
+				.for_each(|c| self::constants(c, constants));
+		},
+		Match::Any => (),
+		Match::EqualTo(x) | Match::GreaterThan(x) | Match::LessThan(x) => constants.push(x.clone()),
+		Match::InRange(low, high) => constants.extend([low.clone(), high.clone()]),
+		Match::Not(condition) => self::constants(condition, constants),
+	}
+}
+
+impl<T> Satisfy for Match<T>
+where
+	T: satisfy::Domain,
+{
+	type Record = T;
+
+	fn satisfy_at(&self, path: &FieldPath) -> Satisfiability<Self::Record>
+	{
+		let Ok(compiled) = self.compile()
+		else
+		{
+			return Satisfiability::Unknown;
+		};
+
+		let mut values = Vec::new();
+		constants(self, &mut values);
+
+		let (candidates, exhaustive) = satisfy::representatives(values);
+		Satisfiability::search(candidates, exhaustive, path, |c| compiled.is_match(c))
+	}
+}
@@ -0,0 +1,127 @@
+use core::time::Duration;
+
+use clinvoice_schema::{chrono::NaiveDateTime, Id, Money};
+use humantime_serde::Serde;
+
+use super::Match;
+use crate::{FieldPath, Fold, Folder, Visit, VisitMut, Visitor, VisitorMut};
+
+impl Visit for Match<NaiveDateTime>
+{
+	fn visit_at<V>(&self, path: &FieldPath, visitor: &mut V)
+	where
+		V: Visitor + ?Sized,
+	{
+		visitor.visit_match_date(path, self);
+	}
+}
+
+impl VisitMut for Match<NaiveDateTime>
+{
+	fn visit_mut_at<V>(&mut self, path: &FieldPath, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized,
+	{
+		visitor.visit_match_date_mut(path, self);
+	}
+}
+
+impl Fold for Match<NaiveDateTime>
+{
+	fn fold_at<F>(self, path: &FieldPath, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized,
+	{
+		folder.fold_match_date(path, self)
+	}
+}
+
+impl Visit for Match<Serde<Duration>>
+{
+	fn visit_at<V>(&self, path: &FieldPath, visitor: &mut V)
+	where
+		V: Visitor + ?Sized,
+	{
+		visitor.visit_match_duration(path, self);
+	}
+}
+
+impl VisitMut for Match<Serde<Duration>>
+{
+	fn visit_mut_at<V>(&mut self, path: &FieldPath, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized,
+	{
+		visitor.visit_match_duration_mut(path, self);
+	}
+}
+
+impl Fold for Match<Serde<Duration>>
+{
+	fn fold_at<F>(self, path: &FieldPath, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized,
+	{
+		folder.fold_match_duration(path, self)
+	}
+}
+
+impl Visit for Match<Id>
+{
+	fn visit_at<V>(&self, path: &FieldPath, visitor: &mut V)
+	where
+		V: Visitor + ?Sized,
+	{
+		visitor.visit_match_id(path, self);
+	}
+}
+
+impl VisitMut for Match<Id>
+{
+	fn visit_mut_at<V>(&mut self, path: &FieldPath, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized,
+	{
+		visitor.visit_match_id_mut(path, self);
+	}
+}
+
+impl Fold for Match<Id>
+{
+	fn fold_at<F>(self, path: &FieldPath, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized,
+	{
+		folder.fold_match_id(path, self)
+	}
+}
+
+impl Visit for Match<Money>
+{
+	fn visit_at<V>(&self, path: &FieldPath, visitor: &mut V)
+	where
+		V: Visitor + ?Sized,
+	{
+		visitor.visit_match_money(path, self);
+	}
+}
+
+impl VisitMut for Match<Money>
+{
+	fn visit_mut_at<V>(&mut self, path: &FieldPath, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized,
+	{
+		visitor.visit_match_money_mut(path, self);
+	}
+}
+
+impl Fold for Match<Money>
+{
+	fn fold_at<F>(self, path: &FieldPath, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized,
+	{
+		folder.fold_match_money(path, self)
+	}
+}
@@ -1,7 +1,15 @@
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod compiled_match_contact;
+mod explain;
 mod from;
 mod match_contact_kind;
+mod visit;
 
-pub use match_contact_kind::MatchContactKind;
+pub use compiled_match_contact::CompiledMatchContact;
+pub use match_contact_kind::{CompiledMatchContactKind, MatchContactKind};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -28,6 +36,7 @@ use super::MatchStr;
 /// # "#).is_ok());
 /// ```
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[derive(Clone, Default, Debug, Eq, PartialEq)]
 pub struct MatchContact
 {
@@ -0,0 +1,23 @@
+use proptest::{
+	arbitrary::{any_with, Arbitrary},
+	prelude::{BoxedStrategy, Strategy},
+};
+
+use super::MatchContact;
+use crate::{MatchContactKind, MatchStr, Recursion};
+
+impl Arbitrary for MatchContact
+{
+	type Parameters = Recursion;
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with(recursion: Self::Parameters) -> Self::Strategy
+	{
+		(
+			any_with::<MatchContactKind>(recursion),
+			any_with::<MatchStr<String>>(recursion),
+		)
+			.prop_map(|(kind, label)| Self { kind, label })
+			.boxed()
+	}
+}
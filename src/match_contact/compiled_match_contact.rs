@@ -0,0 +1,34 @@
+use clinvoice_schema::Contact;
+
+use super::MatchContact;
+use crate::{Compile, CompiledMatchContactKind, CompiledMatchStr, IsMatch, Result};
+
+/// A [`MatchContact`] which has been [compiled](Compile).
+#[derive(Clone, Debug)]
+pub struct CompiledMatchContact
+{
+	kind: CompiledMatchContactKind,
+
+	label: CompiledMatchStr,
+}
+
+impl Compile for MatchContact
+{
+	type Compiled = CompiledMatchContact;
+
+	fn compile(&self) -> Result<Self::Compiled>
+	{
+		Ok(CompiledMatchContact {
+			kind: self.kind.compile()?,
+			label: self.label.compile()?,
+		})
+	}
+}
+
+impl IsMatch<Contact> for CompiledMatchContact
+{
+	fn is_match(&self, contact: &Contact) -> bool
+	{
+		self.kind.is_match(&contact.kind) && self.label.is_match(contact.label.as_str())
+	}
+}
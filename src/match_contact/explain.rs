@@ -0,0 +1,15 @@
+use clinvoice_schema::Contact;
+
+use super::MatchContact;
+use crate::{Explain, Explanation, Result};
+
+impl Explain<Contact> for MatchContact
+{
+	fn explain(&self, contact: &Contact) -> Result<Explanation>
+	{
+		Ok(Explanation::all("MatchContact", vec![
+			Explanation::field("kind", self.kind.explain(&contact.kind)?),
+			Explanation::field("label", self.label.explain(contact.label.as_str())?),
+		]))
+	}
+}
@@ -1,3 +1,5 @@
+use clinvoice_schema::Contact;
+
 use super::MatchContact;
 use crate::MatchStr;
 
@@ -19,3 +21,14 @@ impl From<MatchStr<String>> for MatchContact
 		}
 	}
 }
+
+impl From<&Contact> for MatchContact
+{
+	fn from(contact: &Contact) -> Self
+	{
+		Self {
+			kind: (&contact.kind).into(),
+			label: contact.label.clone().into(),
+		}
+	}
+}
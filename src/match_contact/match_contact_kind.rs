@@ -1,3 +1,13 @@
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod compiled_match_contact_kind;
+mod explain;
+mod from;
+mod visit;
+
+pub use compiled_match_contact_kind::CompiledMatchContactKind;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -56,6 +66,7 @@ use crate::MatchLocation;
 	derive(Deserialize, Serialize),
 	serde(rename_all = "snake_case")
 )]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub enum MatchContactKind
 {
@@ -0,0 +1,26 @@
+use proptest::{
+	arbitrary::{any_with, Arbitrary},
+	prelude::{BoxedStrategy, Just, Strategy},
+	prop_oneof,
+};
+
+use super::MatchContactKind;
+use crate::{MatchLocation, MatchStr, Recursion};
+
+impl Arbitrary for MatchContactKind
+{
+	type Parameters = Recursion;
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with(recursion: Self::Parameters) -> Self::Strategy
+	{
+		prop_oneof![
+			any_with::<MatchLocation>(recursion).prop_map(Self::Address),
+			Just(Self::Any),
+			any_with::<MatchStr<String>>(recursion).prop_map(Self::Email),
+			any_with::<MatchStr<String>>(recursion).prop_map(Self::Other),
+			any_with::<MatchStr<String>>(recursion).prop_map(Self::Phone),
+		]
+		.boxed()
+	}
+}
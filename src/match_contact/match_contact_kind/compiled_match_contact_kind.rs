@@ -0,0 +1,57 @@
+use clinvoice_schema::ContactKind;
+
+use super::MatchContactKind;
+use crate::{Compile, CompiledMatchLocation, CompiledMatchStr, IsMatch, Result};
+
+/// A [`MatchContactKind`] which has been [compiled](Compile).
+#[derive(Clone, Debug)]
+pub enum CompiledMatchContactKind
+{
+	/// Same as [`MatchContactKind::Address`].
+	Address(CompiledMatchLocation),
+
+	/// Same as [`MatchContactKind::Any`].
+	Any,
+
+	/// Same as [`MatchContactKind::Email`].
+	Email(CompiledMatchStr),
+
+	/// Same as [`MatchContactKind::Other`].
+	Other(CompiledMatchStr),
+
+	/// Same as [`MatchContactKind::Phone`].
+	Phone(CompiledMatchStr),
+}
+
+impl Compile for MatchContactKind
+{
+	type Compiled = CompiledMatchContactKind;
+
+	fn compile(&self) -> Result<Self::Compiled>
+	{
+		Ok(match self
+		{
+			Self::Address(condition) => CompiledMatchContactKind::Address(condition.compile()?),
+			Self::Any => CompiledMatchContactKind::Any,
+			Self::Email(condition) => CompiledMatchContactKind::Email(condition.compile()?),
+			Self::Other(condition) => CompiledMatchContactKind::Other(condition.compile()?),
+			Self::Phone(condition) => CompiledMatchContactKind::Phone(condition.compile()?),
+		})
+	}
+}
+
+impl IsMatch<ContactKind> for CompiledMatchContactKind
+{
+	fn is_match(&self, kind: &ContactKind) -> bool
+	{
+		match (self, kind)
+		{
+			(Self::Address(condition), ContactKind::Address(location)) => condition.is_match(location),
+			(Self::Any, _) => true,
+			(Self::Email(condition), ContactKind::Email(value)) |
+			(Self::Other(condition), ContactKind::Other(value)) |
+			(Self::Phone(condition), ContactKind::Phone(value)) => condition.is_match(value.as_str()),
+			_ => false,
+		}
+	}
+}
@@ -0,0 +1,35 @@
+use clinvoice_schema::ContactKind;
+
+use super::MatchContactKind;
+use crate::{Explain, Explanation, Result};
+
+impl Explain<ContactKind> for MatchContactKind
+{
+	fn explain(&self, kind: &ContactKind) -> Result<Explanation>
+	{
+		Ok(match (self, kind)
+		{
+			(Self::Address(condition), ContactKind::Address(location)) =>
+			{
+				condition.explain(location)?.with_label("address")
+			},
+			(Self::Any, _) => Explanation::constant("any", true),
+			(Self::Email(condition), ContactKind::Email(email)) =>
+			{
+				Explanation::field("email", condition.explain(email.as_str())?)
+			},
+			(Self::Other(condition), ContactKind::Other(other)) =>
+			{
+				Explanation::field("other", condition.explain(other.as_str())?)
+			},
+			(Self::Phone(condition), ContactKind::Phone(phone)) =>
+			{
+				Explanation::field("phone", condition.explain(phone.as_str())?)
+			},
+			(Self::Address(_), _) => Explanation::comparison("address", false, kind),
+			(Self::Email(_), _) => Explanation::comparison("email", false, kind),
+			(Self::Other(_), _) => Explanation::comparison("other", false, kind),
+			(Self::Phone(_), _) => Explanation::comparison("phone", false, kind),
+		})
+	}
+}
@@ -0,0 +1,17 @@
+use clinvoice_schema::ContactKind;
+
+use super::MatchContactKind;
+
+impl From<&ContactKind> for MatchContactKind
+{
+	fn from(kind: &ContactKind) -> Self
+	{
+		match kind
+		{
+			ContactKind::Address(location) => Self::Address(location.into()),
+			ContactKind::Email(email) => Self::Email(email.clone().into()),
+			ContactKind::Other(other) => Self::Other(other.clone().into()),
+			ContactKind::Phone(phone) => Self::Phone(phone.clone().into()),
+		}
+	}
+}
@@ -0,0 +1,53 @@
+use super::MatchContactKind;
+use crate::{FieldPath, Fold, Folder, Visit, VisitMut, Visitor, VisitorMut};
+
+impl Visit for MatchContactKind
+{
+	fn visit_at<V>(&self, path: &FieldPath, visitor: &mut V)
+	where
+		V: Visitor + ?Sized,
+	{
+		match self
+		{
+			Self::Address(location) => location.visit_at(&path.join("address"), visitor),
+			Self::Any => (),
+			Self::Email(email) => email.visit_at(&path.join("email"), visitor),
+			Self::Other(other) => other.visit_at(&path.join("other"), visitor),
+			Self::Phone(phone) => phone.visit_at(&path.join("phone"), visitor),
+		}
+	}
+}
+
+impl VisitMut for MatchContactKind
+{
+	fn visit_mut_at<V>(&mut self, path: &FieldPath, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized,
+	{
+		match self
+		{
+			Self::Address(location) => location.visit_mut_at(&path.join("address"), visitor),
+			Self::Any => (),
+			Self::Email(email) => email.visit_mut_at(&path.join("email"), visitor),
+			Self::Other(other) => other.visit_mut_at(&path.join("other"), visitor),
+			Self::Phone(phone) => phone.visit_mut_at(&path.join("phone"), visitor),
+		}
+	}
+}
+
+impl Fold for MatchContactKind
+{
+	fn fold_at<F>(self, path: &FieldPath, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized,
+	{
+		match self
+		{
+			Self::Address(location) => Self::Address(location.fold_at(&path.join("address"), folder)),
+			Self::Any => Self::Any,
+			Self::Email(email) => Self::Email(email.fold_at(&path.join("email"), folder)),
+			Self::Other(other) => Self::Other(other.fold_at(&path.join("other"), folder)),
+			Self::Phone(phone) => Self::Phone(phone.fold_at(&path.join("phone"), folder)),
+		}
+	}
+}
@@ -0,0 +1,37 @@
+use super::MatchContact;
+use crate::{FieldPath, Fold, Folder, Visit, VisitMut, Visitor, VisitorMut};
+
+impl Visit for MatchContact
+{
+	fn visit_at<V>(&self, path: &FieldPath, visitor: &mut V)
+	where
+		V: Visitor + ?Sized,
+	{
+		self.kind.visit_at(&path.join("kind"), visitor);
+		self.label.visit_at(&path.join("label"), visitor);
+	}
+}
+
+impl VisitMut for MatchContact
+{
+	fn visit_mut_at<V>(&mut self, path: &FieldPath, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized,
+	{
+		self.kind.visit_mut_at(&path.join("kind"), visitor);
+		self.label.visit_mut_at(&path.join("label"), visitor);
+	}
+}
+
+impl Fold for MatchContact
+{
+	fn fold_at<F>(self, path: &FieldPath, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized,
+	{
+		Self {
+			kind: self.kind.fold_at(&path.join("kind"), folder),
+			label: self.label.fold_at(&path.join("label"), folder),
+		}
+	}
+}
@@ -1,6 +1,20 @@
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod compiled_match_employee;
+mod explain;
 mod from;
+mod from_example;
+mod indexable;
+mod orderable;
+#[cfg(feature = "proptest")]
+mod record_strategy;
+mod satisfy;
+mod visit;
 
 use clinvoice_schema::Id;
+pub use compiled_match_employee::CompiledMatchEmployee;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -32,6 +46,7 @@ use super::{Match, MatchStr};
 /// # "#).is_ok());
 /// ```
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[derive(Clone, Default, Debug, Eq, PartialEq)]
 pub struct MatchEmployee
 {
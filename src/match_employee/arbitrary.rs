@@ -0,0 +1,31 @@
+use clinvoice_schema::Id;
+use proptest::{
+	arbitrary::{any_with, Arbitrary},
+	prelude::{BoxedStrategy, Strategy},
+};
+
+use super::MatchEmployee;
+use crate::{Match, MatchStr, Recursion};
+
+impl Arbitrary for MatchEmployee
+{
+	type Parameters = Recursion;
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with(recursion: Self::Parameters) -> Self::Strategy
+	{
+		(
+			any_with::<Match<Id>>(recursion),
+			any_with::<MatchStr<String>>(recursion),
+			any_with::<MatchStr<String>>(recursion),
+			any_with::<MatchStr<String>>(recursion),
+		)
+			.prop_map(|(id, name, status, title)| Self {
+				id,
+				name,
+				status,
+				title,
+			})
+			.boxed()
+	}
+}
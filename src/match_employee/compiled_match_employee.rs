@@ -0,0 +1,43 @@
+use clinvoice_schema::{Employee, Id};
+
+use super::MatchEmployee;
+use crate::{Compile, CompiledMatch, CompiledMatchStr, IsMatch, Result};
+
+/// A [`MatchEmployee`] which has been [compiled](Compile).
+#[derive(Clone, Debug)]
+pub struct CompiledMatchEmployee
+{
+	id: CompiledMatch<Id>,
+
+	name: CompiledMatchStr,
+
+	status: CompiledMatchStr,
+
+	title: CompiledMatchStr,
+}
+
+impl Compile for MatchEmployee
+{
+	type Compiled = CompiledMatchEmployee;
+
+	fn compile(&self) -> Result<Self::Compiled>
+	{
+		Ok(CompiledMatchEmployee {
+			id: self.id.compile()?,
+			name: self.name.compile()?,
+			status: self.status.compile()?,
+			title: self.title.compile()?,
+		})
+	}
+}
+
+impl IsMatch<Employee> for CompiledMatchEmployee
+{
+	fn is_match(&self, employee: &Employee) -> bool
+	{
+		self.id.is_match(&employee.id) &&
+			self.name.is_match(employee.name.as_str()) &&
+			self.status.is_match(employee.status.as_str()) &&
+			self.title.is_match(employee.title.as_str())
+	}
+}
@@ -0,0 +1,17 @@
+use clinvoice_schema::Employee;
+
+use super::MatchEmployee;
+use crate::{Explain, Explanation, Result};
+
+impl Explain<Employee> for MatchEmployee
+{
+	fn explain(&self, employee: &Employee) -> Result<Explanation>
+	{
+		Ok(Explanation::all("MatchEmployee", vec![
+			Explanation::field("id", self.id.explain(&employee.id)?),
+			Explanation::field("name", self.name.explain(employee.name.as_str())?),
+			Explanation::field("status", self.status.explain(employee.status.as_str())?),
+			Explanation::field("title", self.title.explain(employee.title.as_str())?),
+		]))
+	}
+}
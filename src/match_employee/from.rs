@@ -1,4 +1,4 @@
-use clinvoice_schema::Id;
+use clinvoice_schema::{Employee, Id};
 
 use super::MatchEmployee;
 use crate::Match;
@@ -21,3 +21,16 @@ impl From<Match<Id>> for MatchEmployee
 		}
 	}
 }
+
+impl From<&Employee> for MatchEmployee
+{
+	fn from(employee: &Employee) -> Self
+	{
+		Self {
+			id: employee.id.into(),
+			name: employee.name.clone().into(),
+			status: employee.status.clone().into(),
+			title: employee.title.clone().into(),
+		}
+	}
+}
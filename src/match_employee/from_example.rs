@@ -0,0 +1,17 @@
+use clinvoice_schema::Employee;
+
+use super::MatchEmployee;
+use crate::{FromExample, Match, MatchStr, TextSearch};
+
+impl FromExample<Employee> for MatchEmployee
+{
+	fn from_example(example: &Employee, search: TextSearch) -> Self
+	{
+		Self {
+			id: Match::from_example(&example.id, search),
+			name: MatchStr::from_example(&example.name, search),
+			status: MatchStr::from_example(&example.status, search),
+			title: MatchStr::from_example(&example.title, search),
+		}
+	}
+}
@@ -0,0 +1,21 @@
+use clinvoice_schema::Employee;
+
+use super::MatchEmployee;
+use crate::{FieldValue, Orderable};
+
+impl Orderable for MatchEmployee
+{
+	type Record = Employee;
+
+	fn field_value(record: Option<&Self::Record>, field: &[&str]) -> Option<FieldValue>
+	{
+		match field
+		{
+			["id"] => Some(record.map(|r| r.id).into()),
+			["name"] => Some(record.map(|r| r.name.as_str()).into()),
+			["status"] => Some(record.map(|r| r.status.as_str()).into()),
+			["title"] => Some(record.map(|r| r.title.as_str()).into()),
+			_ => None,
+		}
+	}
+}
@@ -0,0 +1,60 @@
+use clinvoice_schema::{Employee, Id};
+use proptest::prelude::{BoxedStrategy, Strategy};
+
+use super::MatchEmployee;
+use crate::{strategy, Match, MatchStr, RecordStrategy};
+
+impl RecordStrategy for MatchEmployee
+{
+	fn matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		Some(
+			(
+				self.id.matching()?,
+				self.name.matching()?,
+				self.status.matching()?,
+				self.title.matching()?,
+			)
+				.prop_map(|(id, name, status, title)| Employee {
+					id,
+					name,
+					status,
+					title,
+				})
+				.boxed(),
+		)
+	}
+
+	fn not_matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		strategy::union([
+			strategy::with(Self::records(), self.id.not_matching(), |e, id| e.id = id),
+			strategy::with(Self::records(), self.name.not_matching(), |e, name| {
+				e.name = name
+			}),
+			strategy::with(Self::records(), self.status.not_matching(), |e, status| {
+				e.status = status
+			}),
+			strategy::with(Self::records(), self.title.not_matching(), |e, title| {
+				e.title = title
+			}),
+		])
+	}
+
+	fn records() -> BoxedStrategy<Self::Record>
+	{
+		(
+			Match::<Id>::records(),
+			MatchStr::<String>::records(),
+			MatchStr::<String>::records(),
+			MatchStr::<String>::records(),
+		)
+			.prop_map(|(id, name, status, title)| Employee {
+				id,
+				name,
+				status,
+				title,
+			})
+			.boxed()
+	}
+}
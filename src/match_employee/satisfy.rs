@@ -0,0 +1,36 @@
+use clinvoice_schema::Employee;
+
+use super::MatchEmployee;
+use crate::{FieldPath, Satisfiability, Satisfy};
+
+impl Satisfy for MatchEmployee
+{
+	type Record = Employee;
+
+	fn satisfy_at(&self, path: &FieldPath) -> Satisfiability<Self::Record>
+	{
+		match (
+			self.id.satisfy_at(&path.join("id")),
+			self.name.satisfy_at(&path.join("name")),
+			self.status.satisfy_at(&path.join("status")),
+			self.title.satisfy_at(&path.join("title")),
+		)
+		{
+			(
+				Satisfiability::Satisfiable(id),
+				Satisfiability::Satisfiable(name),
+				Satisfiability::Satisfiable(status),
+				Satisfiability::Satisfiable(title),
+			) => Satisfiability::Satisfiable(Employee {
+				id,
+				name,
+				status,
+				title,
+			}),
+			(id, name, status, title) =>
+			{
+				Satisfiability::unsatisfied([id.proof(), name.proof(), status.proof(), title.proof()])
+			},
+		}
+	}
+}
@@ -0,0 +1,43 @@
+use super::MatchEmployee;
+use crate::{FieldPath, Fold, Folder, Visit, VisitMut, Visitor, VisitorMut};
+
+impl Visit for MatchEmployee
+{
+	fn visit_at<V>(&self, path: &FieldPath, visitor: &mut V)
+	where
+		V: Visitor + ?Sized,
+	{
+		self.id.visit_at(&path.join("id"), visitor);
+		self.name.visit_at(&path.join("name"), visitor);
+		self.status.visit_at(&path.join("status"), visitor);
+		self.title.visit_at(&path.join("title"), visitor);
+	}
+}
+
+impl VisitMut for MatchEmployee
+{
+	fn visit_mut_at<V>(&mut self, path: &FieldPath, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized,
+	{
+		self.id.visit_mut_at(&path.join("id"), visitor);
+		self.name.visit_mut_at(&path.join("name"), visitor);
+		self.status.visit_mut_at(&path.join("status"), visitor);
+		self.title.visit_mut_at(&path.join("title"), visitor);
+	}
+}
+
+impl Fold for MatchEmployee
+{
+	fn fold_at<F>(self, path: &FieldPath, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized,
+	{
+		Self {
+			id: self.id.fold_at(&path.join("id"), folder),
+			name: self.name.fold_at(&path.join("name"), folder),
+			status: self.status.fold_at(&path.join("status"), folder),
+			title: self.title.fold_at(&path.join("title"), folder),
+		}
+	}
+}
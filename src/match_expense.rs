@@ -1,7 +1,21 @@
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod compiled_match_expense;
 mod exchange;
+mod explain;
 mod from;
+mod from_example;
+mod indexable;
+mod orderable;
+#[cfg(feature = "proptest")]
+mod record_strategy;
+mod satisfy;
+mod visit;
 
 use clinvoice_schema::{Id, Money};
+pub use compiled_match_expense::CompiledMatchExpense;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -37,6 +51,7 @@ use super::{Match, MatchStr};
 /// # "#).is_ok());
 /// ```
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[derive(Clone, Default, Debug, Eq, PartialEq)]
 pub struct MatchExpense
 {
@@ -45,6 +60,10 @@ pub struct MatchExpense
 	pub category: MatchStr<String>,
 
 	#[allow(missing_docs)]
+	#[cfg_attr(
+		feature = "schemars",
+		schemars(with = "Match<crate::json_schema::Money>")
+	)]
 	#[cfg_attr(feature = "serde", serde(default))]
 	pub cost: Match<Money>,
 
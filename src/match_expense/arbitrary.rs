@@ -0,0 +1,33 @@
+use clinvoice_schema::{Id, Money};
+use proptest::{
+	arbitrary::{any_with, Arbitrary},
+	prelude::{BoxedStrategy, Strategy},
+};
+
+use super::MatchExpense;
+use crate::{Match, MatchStr, Recursion};
+
+impl Arbitrary for MatchExpense
+{
+	type Parameters = Recursion;
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with(recursion: Self::Parameters) -> Self::Strategy
+	{
+		(
+			any_with::<MatchStr<String>>(recursion),
+			any_with::<Match<Money>>(recursion),
+			any_with::<MatchStr<String>>(recursion),
+			any_with::<Match<Id>>(recursion),
+			any_with::<Match<Id>>(recursion),
+		)
+			.prop_map(|(category, cost, description, id, timesheet_id)| Self {
+				category,
+				cost,
+				description,
+				id,
+				timesheet_id,
+			})
+			.boxed()
+	}
+}
@@ -0,0 +1,47 @@
+use clinvoice_schema::{Expense, Id, Money};
+
+use super::MatchExpense;
+use crate::{Compile, CompiledMatch, CompiledMatchStr, IsMatch, Result};
+
+/// A [`MatchExpense`] which has been [compiled](Compile).
+#[derive(Clone, Debug)]
+pub struct CompiledMatchExpense
+{
+	category: CompiledMatchStr,
+
+	cost: CompiledMatch<Money>,
+
+	description: CompiledMatchStr,
+
+	id: CompiledMatch<Id>,
+
+	timesheet_id: CompiledMatch<Id>,
+}
+
+impl Compile for MatchExpense
+{
+	type Compiled = CompiledMatchExpense;
+
+	fn compile(&self) -> Result<Self::Compiled>
+	{
+		Ok(CompiledMatchExpense {
+			category: self.category.compile()?,
+			cost: self.cost.compile()?,
+			description: self.description.compile()?,
+			id: self.id.compile()?,
+			timesheet_id: self.timesheet_id.compile()?,
+		})
+	}
+}
+
+impl IsMatch<Expense> for CompiledMatchExpense
+{
+	fn is_match(&self, expense: &Expense) -> bool
+	{
+		self.category.is_match(expense.category.as_str()) &&
+			self.cost.is_match(&expense.cost) &&
+			self.description.is_match(expense.description.as_str()) &&
+			self.id.is_match(&expense.id) &&
+			self.timesheet_id.is_match(&expense.timesheet_id)
+	}
+}
@@ -0,0 +1,27 @@
+use clinvoice_schema::Expense;
+
+use super::MatchExpense;
+use crate::{Explain, Explanation, Result};
+
+impl Explain<Expense> for MatchExpense
+{
+	fn explain(&self, expense: &Expense) -> Result<Explanation>
+	{
+		Ok(Explanation::all("MatchExpense", vec![
+			Explanation::field(
+				"category",
+				self.category.explain(expense.category.as_str())?,
+			),
+			Explanation::field("cost", self.cost.explain(&expense.cost)?),
+			Explanation::field(
+				"description",
+				self.description.explain(expense.description.as_str())?,
+			),
+			Explanation::field("id", self.id.explain(&expense.id)?),
+			Explanation::field(
+				"timesheet_id",
+				self.timesheet_id.explain(&expense.timesheet_id)?,
+			),
+		]))
+	}
+}
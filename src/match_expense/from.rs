@@ -1,4 +1,4 @@
-use clinvoice_schema::Id;
+use clinvoice_schema::{Expense, Id};
 
 use super::MatchExpense;
 use crate::Match;
@@ -21,3 +21,17 @@ impl From<Match<Id>> for MatchExpense
 		}
 	}
 }
+
+impl From<&Expense> for MatchExpense
+{
+	fn from(expense: &Expense) -> Self
+	{
+		Self {
+			category: expense.category.clone().into(),
+			cost: expense.cost.into(),
+			description: expense.description.clone().into(),
+			id: expense.id.into(),
+			timesheet_id: expense.timesheet_id.into(),
+		}
+	}
+}
@@ -0,0 +1,18 @@
+use clinvoice_schema::Expense;
+
+use super::MatchExpense;
+use crate::{FromExample, Match, MatchStr, TextSearch};
+
+impl FromExample<Expense> for MatchExpense
+{
+	fn from_example(example: &Expense, search: TextSearch) -> Self
+	{
+		Self {
+			category: MatchStr::from_example(&example.category, search),
+			cost: Match::from_example(&example.cost, search),
+			description: MatchStr::from_example(&example.description, search),
+			id: Match::from_example(&example.id, search),
+			timesheet_id: Match::from_example(&example.timesheet_id, search),
+		}
+	}
+}
@@ -0,0 +1,39 @@
+use clinvoice_schema::{chrono::NaiveDateTime, Expense, Id};
+
+use super::MatchExpense;
+use crate::{Indexable, Match, MatchStr};
+
+impl Indexable for MatchExpense
+{
+	type Record = Expense;
+
+	fn date_conditions(&self) -> Vec<&Match<NaiveDateTime>>
+	{
+		vec![]
+	}
+
+	fn id_conditions(&self) -> Vec<&Match<Id>>
+	{
+		vec![&self.id, &self.timesheet_id]
+	}
+
+	fn record_dates(_record: &Self::Record) -> Vec<NaiveDateTime>
+	{
+		vec![]
+	}
+
+	fn record_ids(record: &Self::Record) -> Vec<Id>
+	{
+		vec![record.id, record.timesheet_id]
+	}
+
+	fn record_strs(record: &Self::Record) -> Vec<&str>
+	{
+		vec![record.category.as_str(), record.description.as_str()]
+	}
+
+	fn str_conditions(&self) -> Vec<&MatchStr<String>>
+	{
+		vec![&self.category, &self.description]
+	}
+}
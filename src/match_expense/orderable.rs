@@ -0,0 +1,27 @@
+use clinvoice_schema::Expense;
+
+use super::MatchExpense;
+use crate::{FieldValue, Orderable};
+
+impl Orderable for MatchExpense
+{
+	type Record = Expense;
+
+	fn field_value(record: Option<&Self::Record>, field: &[&str]) -> Option<FieldValue>
+	{
+		match field
+		{
+			["category"] => Some(record.map(|r| r.category.as_str()).into()),
+			["cost"] => Some(record.map(|r| r.cost).into()),
+			["description"] => Some(record.map(|r| r.description.as_str()).into()),
+			["id"] => Some(record.map(|r| r.id).into()),
+			["timesheet_id"] => Some(record.map(|r| r.timesheet_id).into()),
+			_ => None,
+		}
+	}
+
+	fn is_summable(field: &[&str]) -> bool
+	{
+		field == ["cost"]
+	}
+}
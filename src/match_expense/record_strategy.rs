@@ -0,0 +1,77 @@
+use clinvoice_schema::{Expense, Id, Money};
+use proptest::prelude::{BoxedStrategy, Strategy};
+
+use super::MatchExpense;
+use crate::{strategy, Match, MatchStr, RecordStrategy};
+
+impl RecordStrategy for MatchExpense
+{
+	fn matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		Some(
+			(
+				self.category.matching()?,
+				self.cost.matching()?,
+				self.description.matching()?,
+				self.id.matching()?,
+				self.timesheet_id.matching()?,
+			)
+				.prop_map(|(category, cost, description, id, timesheet_id)| Expense {
+					category,
+					cost,
+					description,
+					id,
+					timesheet_id,
+				})
+				.boxed(),
+		)
+	}
+
+	fn not_matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		strategy::union([
+			strategy::with(
+				Self::records(),
+				self.category.not_matching(),
+				|e, category| e.category = category,
+			),
+			strategy::with(Self::records(), self.cost.not_matching(), |e, cost| {
+				e.cost = cost
+			}),
+			strategy::with(
+				Self::records(),
+				self.description.not_matching(),
+				|e, description| {
+					e.description = description;
+				},
+			),
+			strategy::with(Self::records(), self.id.not_matching(), |e, id| e.id = id),
+			strategy::with(
+				Self::records(),
+				self.timesheet_id.not_matching(),
+				|e, timesheet_id| {
+					e.timesheet_id = timesheet_id;
+				},
+			),
+		])
+	}
+
+	fn records() -> BoxedStrategy<Self::Record>
+	{
+		(
+			MatchStr::<String>::records(),
+			Match::<Money>::records(),
+			MatchStr::<String>::records(),
+			Match::<Id>::records(),
+			Match::<Id>::records(),
+		)
+			.prop_map(|(category, cost, description, id, timesheet_id)| Expense {
+				category,
+				cost,
+				description,
+				id,
+				timesheet_id,
+			})
+			.boxed()
+	}
+}
@@ -0,0 +1,42 @@
+use clinvoice_schema::Expense;
+
+use super::MatchExpense;
+use crate::{FieldPath, Satisfiability, Satisfy};
+
+impl Satisfy for MatchExpense
+{
+	type Record = Expense;
+
+	fn satisfy_at(&self, path: &FieldPath) -> Satisfiability<Self::Record>
+	{
+		match (
+			self.category.satisfy_at(&path.join("category")),
+			self.cost.satisfy_at(&path.join("cost")),
+			self.description.satisfy_at(&path.join("description")),
+			self.id.satisfy_at(&path.join("id")),
+			self.timesheet_id.satisfy_at(&path.join("timesheet_id")),
+		)
+		{
+			(
+				Satisfiability::Satisfiable(category),
+				Satisfiability::Satisfiable(cost),
+				Satisfiability::Satisfiable(description),
+				Satisfiability::Satisfiable(id),
+				Satisfiability::Satisfiable(timesheet_id),
+			) => Satisfiability::Satisfiable(Expense {
+				category,
+				cost,
+				description,
+				id,
+				timesheet_id,
+			}),
+			(category, cost, description, id, timesheet_id) => Satisfiability::unsatisfied([
+				category.proof(),
+				cost.proof(),
+				description.proof(),
+				id.proof(),
+				timesheet_id.proof(),
+			]),
+		}
+	}
+}
@@ -0,0 +1,56 @@
+use super::MatchExpense;
+use crate::{FieldPath, Fold, Folder, Visit, VisitMut, Visitor, VisitorMut};
+
+impl Visit for MatchExpense
+{
+	fn visit_at<V>(&self, path: &FieldPath, visitor: &mut V)
+	where
+		V: Visitor + ?Sized,
+	{
+		self.category.visit_at(&path.join("category"), visitor);
+		self.cost.visit_at(&path.join("cost"), visitor);
+		self
+			.description
+			.visit_at(&path.join("description"), visitor);
+		self.id.visit_at(&path.join("id"), visitor);
+		self
+			.timesheet_id
+			.visit_at(&path.join("timesheet_id"), visitor);
+	}
+}
+
+impl VisitMut for MatchExpense
+{
+	fn visit_mut_at<V>(&mut self, path: &FieldPath, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized,
+	{
+		self.category.visit_mut_at(&path.join("category"), visitor);
+		self.cost.visit_mut_at(&path.join("cost"), visitor);
+		self
+			.description
+			.visit_mut_at(&path.join("description"), visitor);
+		self.id.visit_mut_at(&path.join("id"), visitor);
+		self
+			.timesheet_id
+			.visit_mut_at(&path.join("timesheet_id"), visitor);
+	}
+}
+
+impl Fold for MatchExpense
+{
+	fn fold_at<F>(self, path: &FieldPath, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized,
+	{
+		Self {
+			category: self.category.fold_at(&path.join("category"), folder),
+			cost: self.cost.fold_at(&path.join("cost"), folder),
+			description: self.description.fold_at(&path.join("description"), folder),
+			id: self.id.fold_at(&path.join("id"), folder),
+			timesheet_id: self
+				.timesheet_id
+				.fold_at(&path.join("timesheet_id"), folder),
+		}
+	}
+}
@@ -1,6 +1,20 @@
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod compiled_match_invoice;
 mod exchange;
+mod explain;
+mod from;
+mod from_example;
+mod orderable;
+#[cfg(feature = "proptest")]
+mod record_strategy;
+mod satisfy;
+mod visit;
 
 use clinvoice_schema::{chrono::NaiveDateTime, Money};
+pub use compiled_match_invoice::CompiledMatchInvoice;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -33,6 +47,7 @@ use crate::MatchOption;
 /// # "#).is_ok());
 /// ```
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[derive(Clone, Default, Debug, Eq, PartialEq)]
 pub struct MatchInvoice
 {
@@ -45,6 +60,10 @@ pub struct MatchInvoice
 	pub date_paid: MatchOption<NaiveDateTime>,
 
 	#[allow(missing_docs)]
+	#[cfg_attr(
+		feature = "schemars",
+		schemars(with = "Match<crate::json_schema::Money>")
+	)]
 	#[cfg_attr(feature = "serde", serde(default))]
 	pub hourly_rate: Match<Money>,
 }
@@ -0,0 +1,29 @@
+use clinvoice_schema::{chrono::NaiveDateTime, Money};
+use proptest::{
+	arbitrary::{any_with, Arbitrary},
+	prelude::{BoxedStrategy, Strategy},
+};
+
+use super::MatchInvoice;
+use crate::{Match, MatchOption, Recursion};
+
+impl Arbitrary for MatchInvoice
+{
+	type Parameters = Recursion;
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with(recursion: Self::Parameters) -> Self::Strategy
+	{
+		(
+			any_with::<MatchOption<NaiveDateTime>>(recursion),
+			any_with::<MatchOption<NaiveDateTime>>(recursion),
+			any_with::<Match<Money>>(recursion),
+		)
+			.prop_map(|(date_issued, date_paid, hourly_rate)| Self {
+				date_issued,
+				date_paid,
+				hourly_rate,
+			})
+			.boxed()
+	}
+}
@@ -0,0 +1,39 @@
+use clinvoice_schema::{chrono::NaiveDateTime, Invoice, Money};
+
+use super::MatchInvoice;
+use crate::{Compile, CompiledMatch, CompiledMatchOption, IsMatch, Result};
+
+/// A [`MatchInvoice`] which has been [compiled](Compile).
+#[derive(Clone, Debug)]
+pub struct CompiledMatchInvoice
+{
+	date_issued: CompiledMatchOption<NaiveDateTime>,
+
+	date_paid: CompiledMatchOption<NaiveDateTime>,
+
+	hourly_rate: CompiledMatch<Money>,
+}
+
+impl Compile for MatchInvoice
+{
+	type Compiled = CompiledMatchInvoice;
+
+	fn compile(&self) -> Result<Self::Compiled>
+	{
+		Ok(CompiledMatchInvoice {
+			date_issued: self.date_issued.compile()?,
+			date_paid: self.date_paid.compile()?,
+			hourly_rate: self.hourly_rate.compile()?,
+		})
+	}
+}
+
+impl IsMatch<Invoice> for CompiledMatchInvoice
+{
+	fn is_match(&self, invoice: &Invoice) -> bool
+	{
+		self.date_issued.is_match(&invoice.date.map(|d| d.issued)) &&
+			self.date_paid.is_match(&invoice.date.and_then(|d| d.paid)) &&
+			self.hourly_rate.is_match(&invoice.hourly_rate)
+	}
+}
@@ -0,0 +1,25 @@
+use clinvoice_schema::Invoice;
+
+use super::MatchInvoice;
+use crate::{Explain, Explanation, Result};
+
+impl Explain<Invoice> for MatchInvoice
+{
+	fn explain(&self, invoice: &Invoice) -> Result<Explanation>
+	{
+		Ok(Explanation::all("MatchInvoice", vec![
+			Explanation::field(
+				"date_issued",
+				self.date_issued.explain(&invoice.date.map(|d| d.issued))?,
+			),
+			Explanation::field(
+				"date_paid",
+				self.date_paid.explain(&invoice.date.and_then(|d| d.paid))?,
+			),
+			Explanation::field(
+				"hourly_rate",
+				self.hourly_rate.explain(&invoice.hourly_rate)?,
+			),
+		]))
+	}
+}
@@ -0,0 +1,15 @@
+use clinvoice_schema::Invoice;
+
+use super::MatchInvoice;
+
+impl From<&Invoice> for MatchInvoice
+{
+	fn from(invoice: &Invoice) -> Self
+	{
+		Self {
+			date_issued: invoice.date.map(|d| d.issued).into(),
+			date_paid: invoice.date.and_then(|d| d.paid).into(),
+			hourly_rate: invoice.hourly_rate.into(),
+		}
+	}
+}
@@ -0,0 +1,16 @@
+use clinvoice_schema::Invoice;
+
+use super::MatchInvoice;
+use crate::{FromExample, Match, MatchOption, TextSearch};
+
+impl FromExample<Invoice> for MatchInvoice
+{
+	fn from_example(example: &Invoice, search: TextSearch) -> Self
+	{
+		Self {
+			date_issued: MatchOption::from_example(&example.date.map(|d| d.issued), search),
+			date_paid: MatchOption::from_example(&example.date.and_then(|d| d.paid), search),
+			hourly_rate: Match::from_example(&example.hourly_rate, search),
+		}
+	}
+}
@@ -0,0 +1,25 @@
+use clinvoice_schema::Invoice;
+
+use super::MatchInvoice;
+use crate::{FieldValue, Orderable};
+
+impl Orderable for MatchInvoice
+{
+	type Record = Invoice;
+
+	fn field_value(record: Option<&Self::Record>, field: &[&str]) -> Option<FieldValue>
+	{
+		match field
+		{
+			["date_issued"] => Some(record.and_then(|r| r.date).map(|d| d.issued).into()),
+			["date_paid"] => Some(record.and_then(|r| r.date).and_then(|d| d.paid).into()),
+			["hourly_rate"] => Some(record.map(|r| r.hourly_rate).into()),
+			_ => None,
+		}
+	}
+
+	fn is_summable(field: &[&str]) -> bool
+	{
+		field == ["hourly_rate"]
+	}
+}
@@ -0,0 +1,85 @@
+use clinvoice_schema::{chrono::NaiveDateTime, Invoice, InvoiceDate, Money};
+use proptest::{
+	option,
+	prelude::{BoxedStrategy, Strategy},
+};
+
+use super::MatchInvoice;
+use crate::{strategy, Match, MatchOption, RecordStrategy, Satisfy};
+
+impl RecordStrategy for MatchInvoice
+{
+	/// An [`Invoice`] can only have a `date_paid` if it has a `date_issued`, so when only a
+	/// `date_paid` is generated, the [example](Satisfy) is used instead.
+	fn matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		let example = self.satisfy().example()?;
+		Some(
+			(
+				self.date_issued.matching()?,
+				self.date_paid.matching()?,
+				self.hourly_rate.matching()?,
+			)
+				.prop_map(
+					move |(date_issued, date_paid, hourly_rate)| match (date_issued, date_paid)
+					{
+						(None, Some(_)) => example,
+						(issued, paid) => Invoice {
+							date: issued.map(|issued| InvoiceDate { issued, paid }),
+							hourly_rate,
+						},
+					},
+				)
+				.boxed(),
+		)
+	}
+
+	fn not_matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		strategy::union([
+			strategy::with(
+				Self::records(),
+				self.date_issued.not_matching(),
+				|i, issued| {
+					i.date = issued.map(|issued| InvoiceDate {
+						issued,
+						paid: i.date.and_then(|d| d.paid),
+					});
+				},
+			),
+			strategy::with(Self::records(), self.date_paid.not_matching(), |i, paid| {
+				i.date = match (i.date, paid)
+				{
+					(date, Some(paid)) => Some(InvoiceDate {
+						issued: date.map_or(paid, |d| d.issued),
+						paid: Some(paid),
+					}),
+					(date, None) => date.map(|d| InvoiceDate { paid: None, ..d }),
+				};
+			}),
+			strategy::with(
+				Self::records(),
+				self.hourly_rate.not_matching(),
+				|i, hourly_rate| {
+					i.hourly_rate = hourly_rate;
+				},
+			),
+		])
+	}
+
+	fn records() -> BoxedStrategy<Self::Record>
+	{
+		(
+			option::of((
+				Match::<NaiveDateTime>::records(),
+				MatchOption::<NaiveDateTime>::records(),
+			)),
+			Match::<Money>::records(),
+		)
+			.prop_map(|(date, hourly_rate)| Invoice {
+				date: date.map(|(issued, paid)| InvoiceDate { issued, paid }),
+				hourly_rate,
+			})
+			.boxed()
+	}
+}
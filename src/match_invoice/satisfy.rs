@@ -0,0 +1,49 @@
+use clinvoice_schema::{Invoice, InvoiceDate};
+
+use super::MatchInvoice;
+use crate::{FieldPath, MatchOption, Satisfiability, Satisfy};
+
+impl Satisfy for MatchInvoice
+{
+	type Record = Invoice;
+
+	/// An [`Invoice`] can only have a `date_paid` if it has a `date_issued`, so when the
+	/// `date_paid` must be present, so must the `date_issued`.
+	fn satisfy_at(&self, path: &FieldPath) -> Satisfiability<Self::Record>
+	{
+		let date_paid = self.date_paid.satisfy_at(&path.join("date_paid"));
+		let date_issued = match date_paid
+		{
+			Satisfiability::Satisfiable(Some(_)) => MatchOption::And(vec![
+				self.date_issued.clone(),
+				MatchOption::Not(MatchOption::None.into()),
+			])
+			.satisfy_at(&path.join("date_issued")),
+			_ => self.date_issued.satisfy_at(&path.join("date_issued")),
+		};
+
+		match (
+			date_issued,
+			date_paid,
+			self.hourly_rate.satisfy_at(&path.join("hourly_rate")),
+		)
+		{
+			(
+				Satisfiability::Satisfiable(date_issued),
+				Satisfiability::Satisfiable(date_paid),
+				Satisfiability::Satisfiable(hourly_rate),
+			) => Satisfiability::Satisfiable(Invoice {
+				date: date_issued.map(|issued| InvoiceDate {
+					issued,
+					paid: date_paid,
+				}),
+				hourly_rate,
+			}),
+			(date_issued, date_paid, hourly_rate) => Satisfiability::unsatisfied([
+				date_issued.proof(),
+				date_paid.proof(),
+				hourly_rate.proof(),
+			]),
+		}
+	}
+}
@@ -0,0 +1,50 @@
+use super::MatchInvoice;
+use crate::{FieldPath, Fold, Folder, Visit, VisitMut, Visitor, VisitorMut};
+
+impl Visit for MatchInvoice
+{
+	fn visit_at<V>(&self, path: &FieldPath, visitor: &mut V)
+	where
+		V: Visitor + ?Sized,
+	{
+		self
+			.date_issued
+			.visit_at(&path.join("date_issued"), visitor);
+		self.date_paid.visit_at(&path.join("date_paid"), visitor);
+		self
+			.hourly_rate
+			.visit_at(&path.join("hourly_rate"), visitor);
+	}
+}
+
+impl VisitMut for MatchInvoice
+{
+	fn visit_mut_at<V>(&mut self, path: &FieldPath, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized,
+	{
+		self
+			.date_issued
+			.visit_mut_at(&path.join("date_issued"), visitor);
+		self
+			.date_paid
+			.visit_mut_at(&path.join("date_paid"), visitor);
+		self
+			.hourly_rate
+			.visit_mut_at(&path.join("hourly_rate"), visitor);
+	}
+}
+
+impl Fold for MatchInvoice
+{
+	fn fold_at<F>(self, path: &FieldPath, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized,
+	{
+		Self {
+			date_issued: self.date_issued.fold_at(&path.join("date_issued"), folder),
+			date_paid: self.date_paid.fold_at(&path.join("date_paid"), folder),
+			hourly_rate: self.hourly_rate.fold_at(&path.join("hourly_rate"), folder),
+		}
+	}
+}
@@ -1,10 +1,24 @@
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod compiled_match_job;
 mod exchange;
+mod explain;
 mod from;
+mod from_example;
+mod indexable;
+mod orderable;
+#[cfg(feature = "proptest")]
+mod record_strategy;
+mod satisfy;
+mod visit;
 
 use core::time::Duration;
 
 use clinvoice_schema::{chrono::NaiveDateTime, Id};
+pub use compiled_match_job::CompiledMatchJob;
 use humantime_serde::Serde;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -52,6 +66,7 @@ use crate::MatchOption;
 /// # "#).is_ok());
 /// ```
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct MatchJob
 {
@@ -72,6 +87,10 @@ pub struct MatchJob
 	pub id: Match<Id>,
 
 	#[allow(missing_docs)]
+	#[cfg_attr(
+		feature = "schemars",
+		schemars(with = "Match<crate::json_schema::Duration>")
+	)]
 	#[cfg_attr(feature = "serde", serde(default))]
 	pub increment: Match<Serde<Duration>>,
 
@@ -0,0 +1,44 @@
+use core::time::Duration;
+
+use clinvoice_schema::{chrono::NaiveDateTime, Id};
+use humantime_serde::Serde;
+use proptest::{
+	arbitrary::{any_with, Arbitrary},
+	prelude::{BoxedStrategy, Strategy},
+};
+
+use super::MatchJob;
+use crate::{Match, MatchInvoice, MatchOption, MatchOrganization, MatchStr, Recursion};
+
+impl Arbitrary for MatchJob
+{
+	type Parameters = Recursion;
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with(recursion: Self::Parameters) -> Self::Strategy
+	{
+		(
+			any_with::<MatchOrganization>(recursion),
+			any_with::<MatchOption<NaiveDateTime>>(recursion),
+			any_with::<Match<NaiveDateTime>>(recursion),
+			any_with::<Match<Id>>(recursion),
+			any_with::<Match<Serde<Duration>>>(recursion),
+			any_with::<MatchInvoice>(recursion),
+			any_with::<MatchStr<String>>(recursion),
+			any_with::<MatchStr<String>>(recursion),
+		)
+			.prop_map(
+				|(client, date_close, date_open, id, increment, invoice, notes, objectives)| Self {
+					client,
+					date_close,
+					date_open,
+					id,
+					increment,
+					invoice,
+					notes,
+					objectives,
+				},
+			)
+			.boxed()
+	}
+}
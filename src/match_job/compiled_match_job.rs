@@ -0,0 +1,70 @@
+use core::time::Duration;
+
+use clinvoice_schema::{chrono::NaiveDateTime, Id, Job};
+
+use super::MatchJob;
+use crate::{
+	Compile,
+	CompiledMatch,
+	CompiledMatchInvoice,
+	CompiledMatchOption,
+	CompiledMatchOrganization,
+	CompiledMatchStr,
+	IsMatch,
+	Result,
+};
+
+/// A [`MatchJob`] which has been [compiled](Compile).
+#[derive(Clone, Debug)]
+pub struct CompiledMatchJob
+{
+	client: CompiledMatchOrganization,
+
+	date_close: CompiledMatchOption<NaiveDateTime>,
+
+	date_open: CompiledMatch<NaiveDateTime>,
+
+	id: CompiledMatch<Id>,
+
+	increment: CompiledMatch<Duration>,
+
+	invoice: CompiledMatchInvoice,
+
+	notes: CompiledMatchStr,
+
+	objectives: CompiledMatchStr,
+}
+
+impl Compile for MatchJob
+{
+	type Compiled = CompiledMatchJob;
+
+	fn compile(&self) -> Result<Self::Compiled>
+	{
+		Ok(CompiledMatchJob {
+			client: self.client.compile()?,
+			date_close: self.date_close.compile()?,
+			date_open: self.date_open.compile()?,
+			id: self.id.compile()?,
+			increment: self.increment.map_ref(|i| **i).compile()?,
+			invoice: self.invoice.compile()?,
+			notes: self.notes.compile()?,
+			objectives: self.objectives.compile()?,
+		})
+	}
+}
+
+impl IsMatch<Job> for CompiledMatchJob
+{
+	fn is_match(&self, job: &Job) -> bool
+	{
+		self.client.is_match(&job.client) &&
+			self.date_close.is_match(&job.date_close) &&
+			self.date_open.is_match(&job.date_open) &&
+			self.id.is_match(&job.id) &&
+			self.increment.is_match(&job.increment) &&
+			self.invoice.is_match(&job.invoice) &&
+			self.notes.is_match(job.notes.as_str()) &&
+			self.objectives.is_match(job.objectives.as_str())
+	}
+}
@@ -0,0 +1,27 @@
+use clinvoice_schema::Job;
+
+use super::MatchJob;
+use crate::{Explain, Explanation, Result};
+
+impl Explain<Job> for MatchJob
+{
+	fn explain(&self, job: &Job) -> Result<Explanation>
+	{
+		Ok(Explanation::all("MatchJob", vec![
+			self.client.explain(&job.client)?.with_label("client"),
+			Explanation::field("date_close", self.date_close.explain(&job.date_close)?),
+			Explanation::field("date_open", self.date_open.explain(&job.date_open)?),
+			Explanation::field("id", self.id.explain(&job.id)?),
+			Explanation::field(
+				"increment",
+				self.increment.map_ref(|i| **i).explain(&job.increment)?,
+			),
+			self.invoice.explain(&job.invoice)?.with_label("invoice"),
+			Explanation::field("notes", self.notes.explain(job.notes.as_str())?),
+			Explanation::field(
+				"objectives",
+				self.objectives.explain(job.objectives.as_str())?,
+			),
+		]))
+	}
+}
@@ -1,7 +1,7 @@
-use clinvoice_schema::Id;
+use clinvoice_schema::{Id, Job};
 
 use super::MatchJob;
-use crate::Match;
+use crate::{Match, Serde};
 
 impl From<Id> for MatchJob
 {
@@ -21,3 +21,20 @@ impl From<Match<Id>> for MatchJob
 		}
 	}
 }
+
+impl From<&Job> for MatchJob
+{
+	fn from(job: &Job) -> Self
+	{
+		Self {
+			client: (&job.client).into(),
+			date_close: job.date_close.into(),
+			date_open: job.date_open.into(),
+			id: job.id.into(),
+			increment: Serde::from(job.increment).into(),
+			invoice: (&job.invoice).into(),
+			notes: job.notes.clone().into(),
+			objectives: job.objectives.clone().into(),
+		}
+	}
+}
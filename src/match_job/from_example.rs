@@ -0,0 +1,30 @@
+use clinvoice_schema::Job;
+
+use super::MatchJob;
+use crate::{
+	FromExample,
+	Match,
+	MatchInvoice,
+	MatchOption,
+	MatchOrganization,
+	MatchStr,
+	Serde,
+	TextSearch,
+};
+
+impl FromExample<Job> for MatchJob
+{
+	fn from_example(example: &Job, search: TextSearch) -> Self
+	{
+		Self {
+			client: MatchOrganization::from_example(&example.client, search),
+			date_close: MatchOption::from_example(&example.date_close, search),
+			date_open: Match::from_example(&example.date_open, search),
+			id: Match::from_example(&example.id, search),
+			increment: Match::from_example(&example.increment, search).map(Serde::from),
+			invoice: MatchInvoice::from_example(&example.invoice, search),
+			notes: MatchStr::from_example(&example.notes, search),
+			objectives: MatchStr::from_example(&example.objectives, search),
+		}
+	}
+}
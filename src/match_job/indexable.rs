@@ -0,0 +1,47 @@
+use clinvoice_schema::{chrono::NaiveDateTime, Id, Job};
+
+use super::MatchJob;
+use crate::{Indexable, Match, MatchOrganization, MatchStr};
+
+impl Indexable for MatchJob
+{
+	type Record = Job;
+
+	fn date_conditions(&self) -> Vec<&Match<NaiveDateTime>>
+	{
+		vec![&self.date_open]
+	}
+
+	fn id_conditions(&self) -> Vec<&Match<Id>>
+	{
+		let mut conditions = vec![&self.id];
+		conditions.extend(self.client.id_conditions());
+		conditions
+	}
+
+	fn record_dates(record: &Self::Record) -> Vec<NaiveDateTime>
+	{
+		vec![record.date_open]
+	}
+
+	fn record_ids(record: &Self::Record) -> Vec<Id>
+	{
+		let mut values = vec![record.id];
+		values.extend(MatchOrganization::record_ids(&record.client));
+		values
+	}
+
+	fn record_strs(record: &Self::Record) -> Vec<&str>
+	{
+		let mut values = vec![record.notes.as_str(), record.objectives.as_str()];
+		values.extend(MatchOrganization::record_strs(&record.client));
+		values
+	}
+
+	fn str_conditions(&self) -> Vec<&MatchStr<String>>
+	{
+		let mut conditions = vec![&self.notes, &self.objectives];
+		conditions.extend(self.client.str_conditions());
+		conditions
+	}
+}
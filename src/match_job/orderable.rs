@@ -0,0 +1,35 @@
+use clinvoice_schema::Job;
+
+use super::MatchJob;
+use crate::{FieldValue, MatchInvoice, MatchOrganization, Orderable};
+
+impl Orderable for MatchJob
+{
+	type Record = Job;
+
+	fn field_value(record: Option<&Self::Record>, field: &[&str]) -> Option<FieldValue>
+	{
+		match field
+		{
+			["client", rest @ ..] => MatchOrganization::field_value(record.map(|r| &r.client), rest),
+			["date_close"] => Some(record.and_then(|r| r.date_close).into()),
+			["date_open"] => Some(record.map(|r| r.date_open).into()),
+			["id"] => Some(record.map(|r| r.id).into()),
+			["increment"] => Some(record.map(|r| r.increment).into()),
+			["invoice", rest @ ..] => MatchInvoice::field_value(record.map(|r| &r.invoice), rest),
+			["notes"] => Some(record.map(|r| r.notes.as_str()).into()),
+			["objectives"] => Some(record.map(|r| r.objectives.as_str()).into()),
+			_ => None,
+		}
+	}
+
+	fn is_summable(field: &[&str]) -> bool
+	{
+		match field
+		{
+			["increment"] => true,
+			["invoice", rest @ ..] => MatchInvoice::is_summable(rest),
+			_ => false,
+		}
+	}
+}
@@ -0,0 +1,120 @@
+use core::time::Duration;
+
+use clinvoice_schema::{chrono::NaiveDateTime, Id, Job};
+use proptest::prelude::{BoxedStrategy, Strategy};
+
+use super::MatchJob;
+use crate::{
+	strategy,
+	Match,
+	MatchInvoice,
+	MatchOption,
+	MatchOrganization,
+	MatchStr,
+	RecordStrategy,
+};
+
+impl RecordStrategy for MatchJob
+{
+	fn matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		Some(
+			(
+				self.client.matching()?,
+				self.date_close.matching()?,
+				self.date_open.matching()?,
+				self.id.matching()?,
+				self.increment.map_ref(|i| **i).matching()?,
+				self.invoice.matching()?,
+				self.notes.matching()?,
+				self.objectives.matching()?,
+			)
+				.prop_map(
+					|(client, date_close, date_open, id, increment, invoice, notes, objectives)| Job {
+						client,
+						date_close,
+						date_open,
+						id,
+						increment,
+						invoice,
+						notes,
+						objectives,
+					},
+				)
+				.boxed(),
+		)
+	}
+
+	fn not_matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		strategy::union([
+			strategy::with(Self::records(), self.client.not_matching(), |j, client| {
+				j.client = client
+			}),
+			strategy::with(
+				Self::records(),
+				self.date_close.not_matching(),
+				|j, date_close| {
+					j.date_close = date_close;
+				},
+			),
+			strategy::with(
+				Self::records(),
+				self.date_open.not_matching(),
+				|j, date_open| {
+					j.date_open = date_open;
+				},
+			),
+			strategy::with(Self::records(), self.id.not_matching(), |j, id| j.id = id),
+			strategy::with(
+				Self::records(),
+				self.increment.map_ref(|i| **i).not_matching(),
+				|j, increment| {
+					j.increment = increment;
+				},
+			),
+			strategy::with(
+				Self::records(),
+				self.invoice.not_matching(),
+				|j, invoice| j.invoice = invoice,
+			),
+			strategy::with(Self::records(), self.notes.not_matching(), |j, notes| {
+				j.notes = notes
+			}),
+			strategy::with(
+				Self::records(),
+				self.objectives.not_matching(),
+				|j, objectives| {
+					j.objectives = objectives;
+				},
+			),
+		])
+	}
+
+	fn records() -> BoxedStrategy<Self::Record>
+	{
+		(
+			MatchOrganization::records(),
+			MatchOption::<NaiveDateTime>::records(),
+			Match::<NaiveDateTime>::records(),
+			Match::<Id>::records(),
+			Match::<Duration>::records(),
+			MatchInvoice::records(),
+			MatchStr::<String>::records(),
+			MatchStr::<String>::records(),
+		)
+			.prop_map(
+				|(client, date_close, date_open, id, increment, invoice, notes, objectives)| Job {
+					client,
+					date_close,
+					date_open,
+					id,
+					increment,
+					invoice,
+					notes,
+					objectives,
+				},
+			)
+			.boxed()
+	}
+}
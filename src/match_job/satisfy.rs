@@ -0,0 +1,60 @@
+use clinvoice_schema::Job;
+
+use super::MatchJob;
+use crate::{FieldPath, Satisfiability, Satisfy};
+
+impl Satisfy for MatchJob
+{
+	type Record = Job;
+
+	fn satisfy_at(&self, path: &FieldPath) -> Satisfiability<Self::Record>
+	{
+		match (
+			self.client.satisfy_at(&path.join("client")),
+			self.date_close.satisfy_at(&path.join("date_close")),
+			self.date_open.satisfy_at(&path.join("date_open")),
+			self.id.satisfy_at(&path.join("id")),
+			self
+				.increment
+				.map_ref(|i| **i)
+				.satisfy_at(&path.join("increment")),
+			self.invoice.satisfy_at(&path.join("invoice")),
+			self.notes.satisfy_at(&path.join("notes")),
+			self.objectives.satisfy_at(&path.join("objectives")),
+		)
+		{
+			(
+				Satisfiability::Satisfiable(client),
+				Satisfiability::Satisfiable(date_close),
+				Satisfiability::Satisfiable(date_open),
+				Satisfiability::Satisfiable(id),
+				Satisfiability::Satisfiable(increment),
+				Satisfiability::Satisfiable(invoice),
+				Satisfiability::Satisfiable(notes),
+				Satisfiability::Satisfiable(objectives),
+			) => Satisfiability::Satisfiable(Job {
+				client,
+				date_close,
+				date_open,
+				id,
+				increment,
+				invoice,
+				notes,
+				objectives,
+			}),
+			(client, date_close, date_open, id, increment, invoice, notes, objectives) =>
+			{
+				Satisfiability::unsatisfied([
+					client.proof(),
+					date_close.proof(),
+					date_open.proof(),
+					id.proof(),
+					increment.proof(),
+					invoice.proof(),
+					notes.proof(),
+					objectives.proof(),
+				])
+			},
+		}
+	}
+}
@@ -0,0 +1,63 @@
+use super::MatchJob;
+use crate::{FieldPath, Fold, Folder, Visit, VisitMut, Visitor, VisitorMut};
+
+impl Visit for MatchJob
+{
+	fn visit_at<V>(&self, path: &FieldPath, visitor: &mut V)
+	where
+		V: Visitor + ?Sized,
+	{
+		self.client.visit_at(&path.join("client"), visitor);
+		self.date_close.visit_at(&path.join("date_close"), visitor);
+		self.date_open.visit_at(&path.join("date_open"), visitor);
+		self.id.visit_at(&path.join("id"), visitor);
+		self.increment.visit_at(&path.join("increment"), visitor);
+		self.invoice.visit_at(&path.join("invoice"), visitor);
+		self.notes.visit_at(&path.join("notes"), visitor);
+		self.objectives.visit_at(&path.join("objectives"), visitor);
+	}
+}
+
+impl VisitMut for MatchJob
+{
+	fn visit_mut_at<V>(&mut self, path: &FieldPath, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized,
+	{
+		self.client.visit_mut_at(&path.join("client"), visitor);
+		self
+			.date_close
+			.visit_mut_at(&path.join("date_close"), visitor);
+		self
+			.date_open
+			.visit_mut_at(&path.join("date_open"), visitor);
+		self.id.visit_mut_at(&path.join("id"), visitor);
+		self
+			.increment
+			.visit_mut_at(&path.join("increment"), visitor);
+		self.invoice.visit_mut_at(&path.join("invoice"), visitor);
+		self.notes.visit_mut_at(&path.join("notes"), visitor);
+		self
+			.objectives
+			.visit_mut_at(&path.join("objectives"), visitor);
+	}
+}
+
+impl Fold for MatchJob
+{
+	fn fold_at<F>(self, path: &FieldPath, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized,
+	{
+		Self {
+			client: self.client.fold_at(&path.join("client"), folder),
+			date_close: self.date_close.fold_at(&path.join("date_close"), folder),
+			date_open: self.date_open.fold_at(&path.join("date_open"), folder),
+			id: self.id.fold_at(&path.join("id"), folder),
+			increment: self.increment.fold_at(&path.join("increment"), folder),
+			invoice: self.invoice.fold_at(&path.join("invoice"), folder),
+			notes: self.notes.fold_at(&path.join("notes"), folder),
+			objectives: self.objectives.fold_at(&path.join("objectives"), folder),
+		}
+	}
+}
@@ -1,8 +1,22 @@
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod compiled_match_location;
+mod explain;
 mod from;
+mod from_example;
+mod indexable;
 mod match_outer_location;
+mod orderable;
+#[cfg(feature = "proptest")]
+mod record_strategy;
+mod satisfy;
+mod visit;
 
 use clinvoice_schema::Id;
-pub use match_outer_location::MatchOuterLocation;
+pub use compiled_match_location::CompiledMatchLocation;
+pub use match_outer_location::{CompiledMatchOuterLocation, MatchOuterLocation};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -26,14 +40,14 @@ use super::{Match, MatchStr};
 /// # assert!(serde_yaml::from_str::<clinvoice_match::MatchLocation>(r#"
 /// id: any
 /// outer:
-///   some:
-///     name:
-///       equal_to: "Europe"
+///   name:
+///     equal_to: "Europe"
 /// name:
 ///   equal_to: "Sweden"
 /// # "#).is_ok());
 /// ```
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[derive(Clone, Default, Debug, Eq, PartialEq)]
 pub struct MatchLocation
 {
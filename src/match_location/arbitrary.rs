@@ -0,0 +1,25 @@
+use clinvoice_schema::Id;
+use proptest::{
+	arbitrary::{any_with, Arbitrary},
+	prelude::{BoxedStrategy, Strategy},
+};
+
+use super::MatchLocation;
+use crate::{Match, MatchOuterLocation, MatchStr, Recursion};
+
+impl Arbitrary for MatchLocation
+{
+	type Parameters = Recursion;
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with(recursion: Self::Parameters) -> Self::Strategy
+	{
+		(
+			any_with::<Match<Id>>(recursion),
+			any_with::<MatchStr<String>>(recursion),
+			any_with::<MatchOuterLocation>(recursion),
+		)
+			.prop_map(|(id, name, outer)| Self { id, name, outer })
+			.boxed()
+	}
+}
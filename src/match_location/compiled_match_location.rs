@@ -0,0 +1,46 @@
+use clinvoice_schema::{Id, Location};
+
+use super::MatchLocation;
+use crate::{
+	Compile,
+	CompiledMatch,
+	CompiledMatchOuterLocation,
+	CompiledMatchStr,
+	IsMatch,
+	Result,
+};
+
+/// A [`MatchLocation`] which has been [compiled](Compile).
+#[derive(Clone, Debug)]
+pub struct CompiledMatchLocation
+{
+	id: CompiledMatch<Id>,
+
+	name: CompiledMatchStr,
+
+	outer: CompiledMatchOuterLocation,
+}
+
+impl Compile for MatchLocation
+{
+	type Compiled = CompiledMatchLocation;
+
+	fn compile(&self) -> Result<Self::Compiled>
+	{
+		Ok(CompiledMatchLocation {
+			id: self.id.compile()?,
+			name: self.name.compile()?,
+			outer: self.outer.compile()?,
+		})
+	}
+}
+
+impl IsMatch<Location> for CompiledMatchLocation
+{
+	fn is_match(&self, location: &Location) -> bool
+	{
+		self.id.is_match(&location.id) &&
+			self.name.is_match(location.name.as_str()) &&
+			self.outer.is_match(&location.outer)
+	}
+}
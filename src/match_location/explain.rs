@@ -0,0 +1,16 @@
+use clinvoice_schema::Location;
+
+use super::MatchLocation;
+use crate::{Explain, Explanation, Result};
+
+impl Explain<Location> for MatchLocation
+{
+	fn explain(&self, location: &Location) -> Result<Explanation>
+	{
+		Ok(Explanation::all("MatchLocation", vec![
+			Explanation::field("id", self.id.explain(&location.id)?),
+			Explanation::field("name", self.name.explain(location.name.as_str())?),
+			Explanation::field("outer", self.outer.explain(&location.outer)?),
+		]))
+	}
+}
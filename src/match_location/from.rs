@@ -1,4 +1,4 @@
-use clinvoice_schema::Id;
+use clinvoice_schema::{Id, Location};
 
 use super::MatchLocation;
 use crate::Match;
@@ -21,3 +21,15 @@ impl From<Match<Id>> for MatchLocation
 		}
 	}
 }
+
+impl From<&Location> for MatchLocation
+{
+	fn from(location: &Location) -> Self
+	{
+		Self {
+			id: location.id.into(),
+			name: location.name.clone().into(),
+			outer: location.outer.as_deref().into(),
+		}
+	}
+}
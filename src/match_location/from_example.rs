@@ -0,0 +1,16 @@
+use clinvoice_schema::Location;
+
+use super::{MatchLocation, MatchOuterLocation};
+use crate::{FromExample, Match, MatchStr, TextSearch};
+
+impl FromExample<Location> for MatchLocation
+{
+	fn from_example(example: &Location, search: TextSearch) -> Self
+	{
+		Self {
+			id: Match::from_example(&example.id, search),
+			name: MatchStr::from_example(&example.name, search),
+			outer: MatchOuterLocation::from_example(&example.outer, search),
+		}
+	}
+}
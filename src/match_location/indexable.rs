@@ -0,0 +1,39 @@
+use clinvoice_schema::{chrono::NaiveDateTime, Id, Location};
+
+use super::MatchLocation;
+use crate::{Indexable, Match, MatchStr};
+
+impl Indexable for MatchLocation
+{
+	type Record = Location;
+
+	fn date_conditions(&self) -> Vec<&Match<NaiveDateTime>>
+	{
+		vec![]
+	}
+
+	fn id_conditions(&self) -> Vec<&Match<Id>>
+	{
+		vec![&self.id]
+	}
+
+	fn record_dates(_record: &Self::Record) -> Vec<NaiveDateTime>
+	{
+		vec![]
+	}
+
+	fn record_ids(record: &Self::Record) -> Vec<Id>
+	{
+		vec![record.id]
+	}
+
+	fn record_strs(record: &Self::Record) -> Vec<&str>
+	{
+		vec![record.name.as_str()]
+	}
+
+	fn str_conditions(&self) -> Vec<&MatchStr<String>>
+	{
+		vec![&self.name]
+	}
+}
@@ -1,5 +1,21 @@
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod compiled_match_outer_location;
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+mod deserialize;
+mod explain;
+mod from;
+mod from_example;
+#[cfg(feature = "schemars")]
+mod json_schema;
+#[cfg(feature = "proptest")]
+mod record_strategy;
+mod satisfy;
+#[cfg(feature = "serde")]
+mod serialize;
+mod visit;
+
+pub use compiled_match_outer_location::CompiledMatchOuterLocation;
 
 use super::MatchLocation;
 
@@ -30,16 +46,63 @@ use super::MatchLocation;
 /// // ------------------
 ///
 /// # assert!(from_str::<MatchOuterLocation>(r#"
-/// some:
+/// name:
+///   equal_to: "Antarctica"
+/// # "#).is_ok());
+///
+/// // ------------------
+///
+/// # assert!(from_str::<MatchOuterLocation>(r#"
+/// name:
+///   equal_to: "Europe"
+/// outer:
 ///   name:
-///     equal_to: "Antarctica"
+///     equal_to: "Earth"
+///   outer: none
 /// # "#).is_ok());
 /// ```
-#[cfg_attr(
-	feature = "serde",
-	derive(Deserialize, Serialize),
-	serde(rename_all = "snake_case")
-)]
+///
+/// ### `some`
+///
+/// Previously, [`MatchOuterLocation::Some`] had to be written with an explicit `some` key. That
+/// syntax is still accepted, although a [`MatchOuterLocation`] is always serialized without it.
+/// The two forms cannot be mixed, and unknown fields are rejected in either form.
+///
+/// ```rust
+/// # use clinvoice_match::{MatchLocation, MatchOuterLocation};
+/// # use pretty_assertions::assert_eq;
+/// # use serde_yaml::{from_str, to_string};
+/// let expected = MatchOuterLocation::Some(Box::new(MatchLocation {
+///   name: "Europe".to_owned().into(),
+///   outer: MatchOuterLocation::Some(Box::new(MatchLocation {
+///     name: "Earth".to_owned().into(),
+///     ..Default::default()
+///   })),
+///   ..Default::default()
+/// }));
+///
+/// # assert_eq!(from_str::<MatchOuterLocation>(r#"
+/// some:
+///   name:
+///     equal_to: "Europe"
+///   outer:
+///     some:
+///       name:
+///         equal_to: "Earth"
+/// # "#).unwrap(), expected);
+///
+/// // ------------------
+///
+/// # assert_eq!(from_str::<MatchOuterLocation>(r#"
+/// name:
+///   equal_to: "Europe"
+/// outer:
+///   name:
+///     equal_to: "Earth"
+/// # "#).unwrap(), expected);
+///
+/// assert_eq!(from_str::<MatchOuterLocation>(&to_string(&expected).unwrap()).unwrap(), expected);
+/// ```
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub enum MatchOuterLocation
 {
@@ -53,7 +116,5 @@ pub enum MatchOuterLocation
 
 	/// Match IFF the [`Location`](clinvoice_schema::Location)'s `outer` field
 	/// [`is_some`](Option::is_some) and matches the contained [`MatchLocation`].
-	///
-	/// TODO: [flatten this](https://github.com/serde-rs/serde/issues/1402)
 	Some(Box<MatchLocation>),
 }
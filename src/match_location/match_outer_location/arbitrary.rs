@@ -0,0 +1,30 @@
+use proptest::{
+	arbitrary::{any_with, Arbitrary},
+	prelude::{BoxedStrategy, Just, Strategy},
+	prop_oneof,
+};
+
+use super::MatchOuterLocation;
+use crate::{MatchLocation, Recursion};
+
+impl Arbitrary for MatchOuterLocation
+{
+	type Parameters = Recursion;
+	type Strategy = BoxedStrategy<Self>;
+
+	/// [`MatchOuterLocation::Some`] is only generated when the `depth` of the [`Recursion`] is not
+	/// zero, and the [`MatchLocation`] it contains has one less.
+	fn arbitrary_with(recursion: Self::Parameters) -> Self::Strategy
+	{
+		match recursion.depth
+		{
+			0 => prop_oneof![Just(Self::Any), Just(Self::None)].boxed(),
+			_ => prop_oneof![
+				Just(Self::Any),
+				Just(Self::None),
+				any_with::<MatchLocation>(recursion.deeper()).prop_map(|l| Self::Some(l.into())),
+			]
+			.boxed(),
+		}
+	}
+}
@@ -0,0 +1,46 @@
+use clinvoice_schema::Location;
+
+use super::MatchOuterLocation;
+use crate::{Compile, CompiledMatchLocation, IsMatch, Result};
+
+/// A [`MatchOuterLocation`] which has been [compiled](Compile).
+#[derive(Clone, Debug)]
+pub enum CompiledMatchOuterLocation
+{
+	/// Same as [`MatchOuterLocation::Any`].
+	Any,
+
+	/// Same as [`MatchOuterLocation::None`].
+	None,
+
+	/// Same as [`MatchOuterLocation::Some`].
+	Some(Box<CompiledMatchLocation>),
+}
+
+impl Compile for MatchOuterLocation
+{
+	type Compiled = CompiledMatchOuterLocation;
+
+	fn compile(&self) -> Result<Self::Compiled>
+	{
+		Ok(match self
+		{
+			Self::Any => CompiledMatchOuterLocation::Any,
+			Self::None => CompiledMatchOuterLocation::None,
+			Self::Some(condition) => CompiledMatchOuterLocation::Some(condition.compile()?.into()),
+		})
+	}
+}
+
+impl IsMatch<Option<Box<Location>>> for CompiledMatchOuterLocation
+{
+	fn is_match(&self, outer: &Option<Box<Location>>) -> bool
+	{
+		match self
+		{
+			Self::Any => true,
+			Self::None => outer.is_none(),
+			Self::Some(condition) => outer.as_ref().is_some_and(|o| condition.is_match(o)),
+		}
+	}
+}
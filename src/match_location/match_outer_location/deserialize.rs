@@ -0,0 +1,99 @@
+use clinvoice_schema::Id;
+use serde::{Deserialize, Deserializer};
+
+use super::{MatchLocation, MatchOuterLocation};
+use crate::{Match, MatchStr};
+
+/// The fields of a [`MatchLocation`], written directly in place of [`MatchOuterLocation::Some`].
+///
+/// Unlike [`MatchLocation`], unknown fields are rejected, so that a misspelled field (or one
+/// written alongside `some`) is not silently treated as a match on any location.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Flattened
+{
+	#[serde(default)]
+	id: Match<Id>,
+
+	#[serde(default)]
+	name: MatchStr<String>,
+
+	#[serde(default)]
+	outer: MatchOuterLocation,
+}
+
+/// The original form of [`MatchOuterLocation::Some`], which has since been flattened.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Nested
+{
+	some: Box<MatchLocation>,
+}
+
+/// The variants of a [`MatchOuterLocation`] which do not contain a value.
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum Unit
+{
+	Any,
+	None,
+}
+
+/// The form of a [`MatchOuterLocation`] in formats which do not describe their own structure.
+#[derive(Deserialize)]
+#[serde(rename = "MatchOuterLocation", rename_all = "snake_case")]
+enum Tagged
+{
+	Any,
+	None,
+	Some(Box<MatchLocation>),
+}
+
+/// The forms that a [`MatchOuterLocation`] can be written in, in
+/// [human-readable](Deserializer::is_human_readable) formats.
+#[derive(Deserialize)]
+#[serde(
+	expecting = "`any`, `none`, or the fields of a `MatchLocation`",
+	untagged
+)]
+enum Repr
+{
+	/// [`MatchOuterLocation::Any`] or [`MatchOuterLocation::None`].
+	Unit(Unit),
+
+	/// See [`Nested`].
+	Nested(Nested),
+
+	/// See [`Flattened`].
+	Flattened(Flattened),
+}
+
+impl<'de> Deserialize<'de> for MatchOuterLocation
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		if !deserializer.is_human_readable()
+		{
+			return Tagged::deserialize(deserializer).map(|tagged| match tagged
+			{
+				Tagged::Any => Self::Any,
+				Tagged::None => Self::None,
+				Tagged::Some(location) => Self::Some(location),
+			});
+		}
+
+		Repr::deserialize(deserializer).map(|repr| match repr
+		{
+			Repr::Unit(Unit::Any) => Self::Any,
+			Repr::Unit(Unit::None) => Self::None,
+			Repr::Nested(Nested { some: location }) => Self::Some(location),
+			Repr::Flattened(Flattened { id, name, outer }) =>
+			{
+				Self::Some(MatchLocation { id, name, outer }.into())
+			},
+		})
+	}
+}
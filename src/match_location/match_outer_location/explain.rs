@@ -0,0 +1,22 @@
+use clinvoice_schema::Location;
+
+use super::MatchOuterLocation;
+use crate::{Explain, Explanation, Result};
+
+impl Explain<Option<Box<Location>>> for MatchOuterLocation
+{
+	fn explain(&self, outer: &Option<Box<Location>>) -> Result<Explanation>
+	{
+		let outer_name = outer.as_ref().map(|o| &o.name);
+		Ok(match self
+		{
+			Self::Any => Explanation::constant("any", true),
+			Self::None => Explanation::comparison("none", outer.is_none(), &outer_name),
+			Self::Some(condition) => match outer
+			{
+				Some(location) => condition.explain(location)?.with_label("some"),
+				None => Explanation::comparison("some", false, &outer_name),
+			},
+		})
+	}
+}
@@ -0,0 +1,11 @@
+use clinvoice_schema::Location;
+
+use super::MatchOuterLocation;
+
+impl From<Option<&Location>> for MatchOuterLocation
+{
+	fn from(outer: Option<&Location>) -> Self
+	{
+		outer.map_or(Self::None, |o| Self::Some(Box::new(o.into())))
+	}
+}
@@ -0,0 +1,14 @@
+use clinvoice_schema::Location;
+
+use super::MatchOuterLocation;
+use crate::{FromExample, MatchLocation, TextSearch};
+
+impl FromExample<Option<Box<Location>>> for MatchOuterLocation
+{
+	fn from_example(example: &Option<Box<Location>>, search: TextSearch) -> Self
+	{
+		example.as_ref().map_or(Self::Any, |outer| {
+			Self::Some(MatchLocation::from_example(outer, search).into())
+		})
+	}
+}
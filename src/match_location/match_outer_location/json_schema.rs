@@ -0,0 +1,44 @@
+use schemars::{
+	gen::SchemaGenerator,
+	schema::{InstanceType, ObjectValidation, Schema, SchemaObject, SubschemaValidation},
+	JsonSchema,
+};
+
+use super::{deserialize::Unit, MatchLocation, MatchOuterLocation};
+
+impl JsonSchema for MatchOuterLocation
+{
+	fn schema_name() -> String
+	{
+		"MatchOuterLocation".into()
+	}
+
+	fn json_schema(gen: &mut SchemaGenerator) -> Schema
+	{
+		let some = SchemaObject {
+			instance_type: Some(InstanceType::Object.into()),
+			object: Some(Box::new(ObjectValidation {
+				additional_properties: Some(Box::new(false.into())),
+				properties: [("some".into(), gen.subschema_for::<MatchLocation>())]
+					.into_iter()
+					.collect(),
+				required: ["some".into()].into_iter().collect(),
+				..Default::default()
+			})),
+			..Default::default()
+		};
+
+		SchemaObject {
+			subschemas: Some(Box::new(SubschemaValidation {
+				any_of: Some(vec![
+					Unit::json_schema(gen),
+					some.into(),
+					gen.subschema_for::<MatchLocation>(),
+				]),
+				..Default::default()
+			})),
+			..Default::default()
+		}
+		.into()
+	}
+}
@@ -0,0 +1,46 @@
+use proptest::{
+	option,
+	prelude::{BoxedStrategy, Just, Strategy},
+};
+
+use super::MatchOuterLocation;
+use crate::{strategy, MatchLocation, RecordStrategy};
+
+impl RecordStrategy for MatchOuterLocation
+{
+	fn matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		match self
+		{
+			Self::Any => Some(Self::records()),
+			Self::None => Some(Just(None).boxed()),
+			Self::Some(condition) => condition
+				.matching()
+				.map(|s| s.prop_map(|outer| Some(outer.into())).boxed()),
+		}
+	}
+
+	fn not_matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		match self
+		{
+			Self::Any => None,
+			Self::None => Some(
+				MatchLocation::records()
+					.prop_map(|outer| Some(outer.into()))
+					.boxed(),
+			),
+			Self::Some(condition) => strategy::union([
+				Some(Just(None).boxed()),
+				condition
+					.not_matching()
+					.map(|s| s.prop_map(|outer| Some(outer.into())).boxed()),
+			]),
+		}
+	}
+
+	fn records() -> BoxedStrategy<Self::Record>
+	{
+		option::of(MatchLocation::records().prop_map(Box::new)).boxed()
+	}
+}
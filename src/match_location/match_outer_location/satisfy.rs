@@ -0,0 +1,18 @@
+use clinvoice_schema::Location;
+
+use super::MatchOuterLocation;
+use crate::{FieldPath, Satisfiability, Satisfy};
+
+impl Satisfy for MatchOuterLocation
+{
+	type Record = Option<Box<Location>>;
+
+	fn satisfy_at(&self, path: &FieldPath) -> Satisfiability<Self::Record>
+	{
+		match self
+		{
+			Self::Any | Self::None => Satisfiability::Satisfiable(None),
+			Self::Some(condition) => condition.satisfy_at(path).map(|outer| Some(outer.into())),
+		}
+	}
+}
@@ -0,0 +1,22 @@
+use serde::{Serialize, Serializer};
+
+use super::MatchOuterLocation;
+
+impl Serialize for MatchOuterLocation
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		match self
+		{
+			Self::Any => serializer.serialize_unit_variant("MatchOuterLocation", 0, "any"),
+			Self::None => serializer.serialize_unit_variant("MatchOuterLocation", 1, "none"),
+			Self::Some(location) if serializer.is_human_readable() => location.serialize(serializer),
+			Self::Some(location) =>
+			{
+				serializer.serialize_newtype_variant("MatchOuterLocation", 2, "some", location)
+			},
+		}
+	}
+}
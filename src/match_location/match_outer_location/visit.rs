@@ -0,0 +1,44 @@
+use super::MatchOuterLocation;
+use crate::{FieldPath, Fold, Folder, Visit, VisitMut, Visitor, VisitorMut};
+
+/// The [`MatchLocation`](crate::MatchLocation) inside of [`MatchOuterLocation::Some`] is visited
+/// at the same `path` as the [`MatchOuterLocation`] itself.
+impl Visit for MatchOuterLocation
+{
+	fn visit_at<V>(&self, path: &FieldPath, visitor: &mut V)
+	where
+		V: Visitor + ?Sized,
+	{
+		if let Self::Some(location) = self
+		{
+			location.visit_at(path, visitor);
+		}
+	}
+}
+
+impl VisitMut for MatchOuterLocation
+{
+	fn visit_mut_at<V>(&mut self, path: &FieldPath, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized,
+	{
+		if let Self::Some(location) = self
+		{
+			location.visit_mut_at(path, visitor);
+		}
+	}
+}
+
+impl Fold for MatchOuterLocation
+{
+	fn fold_at<F>(self, path: &FieldPath, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized,
+	{
+		match self
+		{
+			Self::Some(location) => Self::Some((*location).fold_at(path, folder).into()),
+			condition => condition,
+		}
+	}
+}
@@ -0,0 +1,20 @@
+use clinvoice_schema::Location;
+
+use super::MatchLocation;
+use crate::{FieldValue, Orderable};
+
+impl Orderable for MatchLocation
+{
+	type Record = Location;
+
+	fn field_value(record: Option<&Self::Record>, field: &[&str]) -> Option<FieldValue>
+	{
+		match field
+		{
+			["id"] => Some(record.map(|r| r.id).into()),
+			["name"] => Some(record.map(|r| r.name.as_str()).into()),
+			["outer", rest @ ..] => Self::field_value(record.and_then(|r| r.outer.as_deref()), rest),
+			_ => None,
+		}
+	}
+}
@@ -0,0 +1,61 @@
+use clinvoice_schema::{Id, Location};
+use proptest::{
+	option,
+	prelude::{BoxedStrategy, Strategy},
+};
+
+use super::MatchLocation;
+use crate::{strategy, Match, MatchStr, RecordStrategy};
+
+impl RecordStrategy for MatchLocation
+{
+	fn matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		Some(
+			(
+				self.id.matching()?,
+				self.name.matching()?,
+				self.outer.matching()?,
+			)
+				.prop_map(|(id, name, outer)| Location { id, name, outer })
+				.boxed(),
+		)
+	}
+
+	fn not_matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		strategy::union([
+			strategy::with(Self::records(), self.id.not_matching(), |l, id| l.id = id),
+			strategy::with(Self::records(), self.name.not_matching(), |l, name| {
+				l.name = name
+			}),
+			strategy::with(Self::records(), self.outer.not_matching(), |l, outer| {
+				l.outer = outer
+			}),
+		])
+	}
+
+	/// [`Location`]s are nested inside of at most two others.
+	fn records() -> BoxedStrategy<Self::Record>
+	{
+		(Match::<Id>::records(), MatchStr::<String>::records())
+			.prop_map(|(id, name)| Location {
+				id,
+				name,
+				outer: None,
+			})
+			.prop_recursive(2, 3, 1, |outer| {
+				(
+					Match::<Id>::records(),
+					MatchStr::<String>::records(),
+					option::of(outer),
+				)
+					.prop_map(|(id, name, outer)| Location {
+						id,
+						name,
+						outer: outer.map(Box::new),
+					})
+			})
+			.boxed()
+	}
+}
@@ -0,0 +1,29 @@
+use clinvoice_schema::Location;
+
+use super::MatchLocation;
+use crate::{FieldPath, Satisfiability, Satisfy};
+
+impl Satisfy for MatchLocation
+{
+	type Record = Location;
+
+	fn satisfy_at(&self, path: &FieldPath) -> Satisfiability<Self::Record>
+	{
+		match (
+			self.id.satisfy_at(&path.join("id")),
+			self.name.satisfy_at(&path.join("name")),
+			self.outer.satisfy_at(&path.join("outer")),
+		)
+		{
+			(
+				Satisfiability::Satisfiable(id),
+				Satisfiability::Satisfiable(name),
+				Satisfiability::Satisfiable(outer),
+			) => Satisfiability::Satisfiable(Location { id, name, outer }),
+			(id, name, outer) =>
+			{
+				Satisfiability::unsatisfied([id.proof(), name.proof(), outer.proof()])
+			},
+		}
+	}
+}
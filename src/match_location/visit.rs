@@ -0,0 +1,40 @@
+use super::MatchLocation;
+use crate::{FieldPath, Fold, Folder, Visit, VisitMut, Visitor, VisitorMut};
+
+impl Visit for MatchLocation
+{
+	fn visit_at<V>(&self, path: &FieldPath, visitor: &mut V)
+	where
+		V: Visitor + ?Sized,
+	{
+		self.id.visit_at(&path.join("id"), visitor);
+		self.name.visit_at(&path.join("name"), visitor);
+		self.outer.visit_at(&path.join("outer"), visitor);
+	}
+}
+
+impl VisitMut for MatchLocation
+{
+	fn visit_mut_at<V>(&mut self, path: &FieldPath, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized,
+	{
+		self.id.visit_mut_at(&path.join("id"), visitor);
+		self.name.visit_mut_at(&path.join("name"), visitor);
+		self.outer.visit_mut_at(&path.join("outer"), visitor);
+	}
+}
+
+impl Fold for MatchLocation
+{
+	fn fold_at<F>(self, path: &FieldPath, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized,
+	{
+		Self {
+			id: self.id.fold_at(&path.join("id"), folder),
+			name: self.name.fold_at(&path.join("name"), folder),
+			outer: self.outer.fold_at(&path.join("outer"), folder),
+		}
+	}
+}
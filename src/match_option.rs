@@ -1,10 +1,24 @@
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod compiled_match_option;
 mod default;
+#[cfg(feature = "serde")]
+mod deserialize;
+mod explain;
 mod from;
+mod from_example;
+#[cfg(feature = "schemars")]
+mod json_schema;
+#[cfg(feature = "proptest")]
+mod record_strategy;
+mod satisfy;
+mod visit;
 
-use core::{cmp::Eq, fmt::Debug};
+use core::{cmp::Eq, convert::Infallible, fmt::Debug};
 
+pub use compiled_match_option::CompiledMatchOption;
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 
 /// A value which describes the condition which some value of type [`Option<T>`] must meet in order
 /// to "_match_".
@@ -118,6 +132,36 @@ use serde::{Deserialize, Serialize};
 /// # ").is_ok());
 /// ```
 ///
+/// ### Shorthand
+///
+/// A bare value is shorthand for [`MatchOption::EqualTo`] (or [`MatchOption::None`] if it is
+/// null), and a list of values is shorthand for [`MatchOption::Or`] of those conditions.
+/// Serializing a [`MatchOption`] always uses the explicit form.
+///
+/// A map with a single key which names a condition (e.g. `equal_to`) is always read as that
+/// condition, so mistakes within it are reported rather than tried as shorthand.
+///
+/// ```rust
+/// # use clinvoice_match::MatchOption;
+/// # use pretty_assertions::assert_eq;
+/// # use serde_yaml::from_str;
+/// # assert_eq!(from_str::<MatchOption<isize>>("
+/// 3
+/// # ").unwrap(), MatchOption::EqualTo(3));
+///
+/// // ----------------------------
+///
+/// # assert_eq!(from_str::<MatchOption<isize>>("
+/// ~
+/// # ").unwrap(), MatchOption::None);
+///
+/// // ----------------------------
+///
+/// # assert_eq!(from_str::<MatchOption<isize>>("
+/// [1, ~]
+/// # ").unwrap(), MatchOption::Or(vec![MatchOption::EqualTo(1), MatchOption::None]));
+/// ```
+///
 /// ### Warnings
 ///
 /// Never use the following, as it is always `false` and often begets an error:
@@ -127,11 +171,7 @@ use serde::{Deserialize, Serialize};
 /// not: any
 /// # ").is_ok());
 /// ```
-#[cfg_attr(
-	feature = "serde",
-	derive(Deserialize, Serialize),
-	serde(rename_all = "snake_case")
-)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "snake_case"))]
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum MatchOption<T>
 {
@@ -183,28 +223,13 @@ impl<T> MatchOption<T>
 	///   MatchOption::EqualTo(5)
 	/// );
 	/// ```
-	pub fn map<F, MapTo>(self, f: F) -> MatchOption<MapTo>
+	pub fn map<F, MapTo>(self, mut f: F) -> MatchOption<MapTo>
 	where
-		F: Copy + Fn(T) -> MapTo,
+		F: FnMut(T) -> MapTo,
 	{
-		match self
-		{
-			Self::And(match_conditions) =>
-			{
-				MatchOption::And(match_conditions.into_iter().map(|m| m.map(f)).collect())
-			},
-			Self::Any => MatchOption::Any,
-			Self::EqualTo(x) => MatchOption::EqualTo(f(x)),
-			Self::GreaterThan(x) => MatchOption::GreaterThan(f(x)),
-			Self::InRange(low, high) => MatchOption::InRange(f(low), f(high)),
-			Self::LessThan(x) => MatchOption::LessThan(f(x)),
-			Self::None => MatchOption::None,
-			Self::Not(match_condition) => MatchOption::Not(match_condition.map(f).into()),
-			Self::Or(match_conditions) =>
-			{
-				MatchOption::Or(match_conditions.into_iter().map(|m| m.map(f)).collect())
-			},
-		}
+		self
+			.try_map(|x| Ok::<_, Infallible>(f(x)))
+			.unwrap_or_else(|e| match e {})
 	}
 
 	/// Transform some [`MatchOption`] of type `T` into another type `U` by providing a mapping `f`unction.
@@ -212,33 +237,120 @@ impl<T> MatchOption<T>
 	/// # See also
 	///
 	/// * [`MatchOption::map`]
-	pub fn map_ref<F, MapTo>(&self, f: F) -> MatchOption<MapTo>
+	pub fn map_ref<F, MapTo>(&self, mut f: F) -> MatchOption<MapTo>
+	where
+		F: FnMut(&T) -> MapTo,
+	{
+		self
+			.try_map_ref(|x| Ok::<_, Infallible>(f(x)))
+			.unwrap_or_else(|e| match e {})
+	}
+
+	/// Return a [`MatchOption`] which matches IFF some value is not [`None`](Self::None)
+	pub fn some() -> Self
+	{
+		Self::Not(Self::None.into())
+	}
+
+	/// Transform some [`MatchOption`] of type `T` into another type `U` by providing a fallible mapping
+	/// `f`unction. The first [`Err`] returned by `f` is returned immediately.
+	///
+	/// # See also
+	///
+	/// * [`MatchOption::map`]
+	/// * [`Iterator::try_fold`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use clinvoice_match::MatchOption;
+	/// # use pretty_assertions::assert_eq;
+	///
+	/// assert_eq!(
+	///   MatchOption::EqualTo("5").try_map(str::parse::<isize>),
+	///   Ok(MatchOption::EqualTo(5))
+	/// );
+	///
+	/// assert!(MatchOption::EqualTo("five").try_map(str::parse::<isize>).is_err());
+	/// ```
+	pub fn try_map<E, F, MapTo>(self, mut f: F) -> Result<MatchOption<MapTo>, E>
 	where
-		F: Copy + Fn(&T) -> MapTo,
+		F: FnMut(T) -> Result<MapTo, E>,
 	{
-		match self
+		self.try_map_with(&mut f)
+	}
+
+	/// Transform some [`MatchOption`] of type `T` into another type `U` by providing a fallible mapping
+	/// `f`unction. The first [`Err`] returned by `f` is returned immediately.
+	///
+	/// # See also
+	///
+	/// * [`MatchOption::try_map`]
+	pub fn try_map_ref<E, F, MapTo>(&self, mut f: F) -> Result<MatchOption<MapTo>, E>
+	where
+		F: FnMut(&T) -> Result<MapTo, E>,
+	{
+		self.try_map_ref_with(&mut f)
+	}
+
+	/// Implementation of [`MatchOption::try_map_ref`] which can be called recursively.
+	fn try_map_ref_with<E, F, MapTo>(&self, f: &mut F) -> Result<MatchOption<MapTo>, E>
+	where
+		F: FnMut(&T) -> Result<MapTo, E>,
+	{
+		Ok(match self
 		{
-			Self::And(match_conditions) =>
-			{
-				MatchOption::And(match_conditions.iter().map(|m| m.map_ref(f)).collect())
-			},
+			Self::And(match_conditions) => MatchOption::And(
+				match_conditions
+					.iter()
+					.map(|m| m.try_map_ref_with(f))
+					.collect::<Result<_, _>>()?,
+			),
 			Self::Any => MatchOption::Any,
-			Self::EqualTo(x) => MatchOption::EqualTo(f(x)),
-			Self::GreaterThan(x) => MatchOption::GreaterThan(f(x)),
-			Self::InRange(low, high) => MatchOption::InRange(f(low), f(high)),
-			Self::LessThan(x) => MatchOption::LessThan(f(x)),
+			Self::EqualTo(x) => MatchOption::EqualTo(f(x)?),
+			Self::GreaterThan(x) => MatchOption::GreaterThan(f(x)?),
+			Self::InRange(low, high) => MatchOption::InRange(f(low)?, f(high)?),
+			Self::LessThan(x) => MatchOption::LessThan(f(x)?),
 			Self::None => MatchOption::None,
-			Self::Not(match_condition) => MatchOption::Not(match_condition.map_ref(f).into()),
-			Self::Or(match_conditions) =>
+			Self::Not(match_condition) =>
 			{
-				MatchOption::Or(match_conditions.iter().map(|m| m.map_ref(f)).collect())
+				MatchOption::Not(match_condition.try_map_ref_with(f)?.into())
 			},
-		}
+			Self::Or(match_conditions) => MatchOption::Or(
+				match_conditions
+					.iter()
+					.map(|m| m.try_map_ref_with(f))
+					.collect::<Result<_, _>>()?,
+			),
+		})
 	}
 
-	/// Return a [`MatchOption`] which matches IFF some value is not [`None`](Self::None)
-	pub fn some() -> Self
+	/// Implementation of [`MatchOption::try_map`] which can be called recursively.
+	fn try_map_with<E, F, MapTo>(self, f: &mut F) -> Result<MatchOption<MapTo>, E>
+	where
+		F: FnMut(T) -> Result<MapTo, E>,
 	{
-		Self::Not(Self::None.into())
+		Ok(match self
+		{
+			Self::And(match_conditions) => MatchOption::And(
+				match_conditions
+					.into_iter()
+					.map(|m| m.try_map_with(f))
+					.collect::<Result<_, _>>()?,
+			),
+			Self::Any => MatchOption::Any,
+			Self::EqualTo(x) => MatchOption::EqualTo(f(x)?),
+			Self::GreaterThan(x) => MatchOption::GreaterThan(f(x)?),
+			Self::InRange(low, high) => MatchOption::InRange(f(low)?, f(high)?),
+			Self::LessThan(x) => MatchOption::LessThan(f(x)?),
+			Self::None => MatchOption::None,
+			Self::Not(match_condition) => MatchOption::Not((*match_condition).try_map_with(f)?.into()),
+			Self::Or(match_conditions) => MatchOption::Or(
+				match_conditions
+					.into_iter()
+					.map(|m| m.try_map_with(f))
+					.collect::<Result<_, _>>()?,
+			),
+		})
 	}
 }
@@ -0,0 +1,34 @@
+use proptest::{
+	arbitrary::Arbitrary,
+	prelude::{BoxedStrategy, Just, Strategy},
+	prop_oneof,
+};
+
+use super::MatchOption;
+use crate::{strategy, Recursion};
+
+impl<T> Arbitrary for MatchOption<T>
+where
+	T: strategy::Value,
+{
+	type Parameters = Recursion;
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with(recursion: Self::Parameters) -> Self::Strategy
+	{
+		recursion.nest(
+			prop_oneof![
+				Just(Self::Any),
+				T::strategy().prop_map(Self::EqualTo),
+				T::strategy().prop_map(Self::GreaterThan),
+				(T::strategy(), T::strategy()).prop_map(|(low, high)| Self::InRange(low, high)),
+				T::strategy().prop_map(Self::LessThan),
+				Just(Self::None),
+			]
+			.boxed(),
+			Self::And,
+			Self::Not,
+			Self::Or,
+		)
+	}
+}
@@ -0,0 +1,114 @@
+use super::MatchOption;
+use crate::{Compile, IsMatch, Result};
+
+/// A [`MatchOption`] which has been [compiled](Compile).
+///
+/// The only difference from [`MatchOption`] is that an [`Or`](MatchOption::Or) which only
+/// contains [`EqualTo`](MatchOption::EqualTo) conditions is compiled into [`In`](Self::In).
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CompiledMatchOption<T>
+{
+	/// Same as [`MatchOption::And`].
+	And(Vec<Self>),
+
+	/// Same as [`MatchOption::Any`].
+	Any,
+
+	/// Same as [`MatchOption::EqualTo`].
+	EqualTo(T),
+
+	/// Same as [`MatchOption::GreaterThan`].
+	GreaterThan(T),
+
+	/// Match IFF some value `v` is equal to any of the contained values, which are sorted and
+	/// deduplicated.
+	In(Box<[T]>),
+
+	/// Same as [`MatchOption::InRange`].
+	InRange(T, T),
+
+	/// Same as [`MatchOption::LessThan`].
+	LessThan(T),
+
+	/// Same as [`MatchOption::None`].
+	None,
+
+	/// Same as [`MatchOption::Not`].
+	Not(Box<Self>),
+
+	/// Same as [`MatchOption::Or`].
+	Or(Vec<Self>),
+}
+
+impl<T> Compile for MatchOption<T>
+where
+	T: Clone + Ord,
+{
+	type Compiled = CompiledMatchOption<T>;
+
+	fn compile(&self) -> Result<Self::Compiled>
+	{
+		Ok(match self
+		{
+			Self::And(conditions) => CompiledMatchOption::And(
+				conditions
+					.iter()
+					.map(Self::compile)
+					.collect::<Result<_>>()?,
+			),
+			Self::Any => CompiledMatchOption::Any,
+			Self::EqualTo(x) => CompiledMatchOption::EqualTo(x.clone()),
+			Self::GreaterThan(x) => CompiledMatchOption::GreaterThan(x.clone()),
+			Self::InRange(low, high) => CompiledMatchOption::InRange(low.clone(), high.clone()),
+			Self::LessThan(x) => CompiledMatchOption::LessThan(x.clone()),
+			Self::None => CompiledMatchOption::None,
+			Self::Not(condition) => CompiledMatchOption::Not(condition.compile()?.into()),
+			Self::Or(conditions) => match conditions
+				.iter()
+				.map(|c| match c
+				{
+					Self::EqualTo(x) => Some(x.clone()),
+					_ => None,
+				})
+				.collect::<Option<Vec<_>>>()
+			{
+				Some(mut values) =>
+				{
+					values.sort_unstable();
+					values.dedup();
+					CompiledMatchOption::In(values.into())
+				},
+				None => CompiledMatchOption::Or(
+					conditions
+						.iter()
+						.map(Self::compile)
+						.collect::<Result<_>>()?,
+				),
+			},
+		})
+	}
+}
+
+impl<T> IsMatch<Option<T>> for CompiledMatchOption<T>
+where
+	T: Ord,
+{
+	fn is_match(&self, value: &Option<T>) -> bool
+	{
+		match self
+		{
+			Self::And(conditions) => conditions.iter().all(|c| c.is_match(value)),
+			Self::Any => true,
+			Self::EqualTo(x) => value.as_ref().is_some_and(|v| v == x),
+			Self::GreaterThan(x) => value.as_ref().is_some_and(|v| v > x),
+			Self::In(values) => value
+				.as_ref()
+				.is_some_and(|v| values.binary_search(v).is_ok()),
+			Self::InRange(low, high) => value.as_ref().is_some_and(|v| low <= v && v < high),
+			Self::LessThan(x) => value.as_ref().is_some_and(|v| v < x),
+			Self::None => value.is_none(),
+			Self::Not(condition) => !condition.is_match(value),
+			Self::Or(conditions) => conditions.iter().any(|c| c.is_match(value)),
+		}
+	}
+}
@@ -0,0 +1,117 @@
+use serde::{de, Deserialize, Deserializer};
+
+use super::MatchOption;
+use crate::shorthand::{Condition, Conditions, Unknown};
+
+/// The explicit form of a [`MatchOption`], which is also how it is serialized.
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Deserialize)]
+#[serde(rename = "MatchOption", rename_all = "snake_case")]
+pub(super) enum Explicit<T>
+{
+	And(Vec<MatchOption<T>>),
+	Any,
+	EqualTo(T),
+	GreaterThan(T),
+	InRange(T, T),
+	LessThan(T),
+	None,
+	Not(Box<MatchOption<T>>),
+	Or(Vec<MatchOption<T>>),
+}
+
+impl<T> Conditions for Explicit<T>
+{
+	const CONDITIONS: &'static [&'static str] = &[
+		"and",
+		"any",
+		"equal_to",
+		"greater_than",
+		"in_range",
+		"less_than",
+		"none",
+		"not",
+		"or",
+	];
+}
+
+/// The forms that a [`MatchOption`] can be written in.
+///
+/// Only [human-readable](Deserializer::is_human_readable) formats can tell these apart, so the
+/// others always use the [`Explicit`] form.
+#[derive(Deserialize)]
+#[serde(
+	expecting = "a condition (e.g. `equal_to: 3`), a value, or a list of values",
+	untagged
+)]
+enum Shorthand<T>
+{
+	/// See [`Condition`].
+	Condition(Condition<Explicit<T>>),
+
+	/// Shorthand for [`MatchOption::Or`] of [`MatchOption::EqualTo`] (or [`MatchOption::None`]).
+	Many(Vec<Option<T>>),
+
+	/// Shorthand for [`MatchOption::EqualTo`] (or [`MatchOption::None`] if it is null).
+	One(Option<T>),
+
+	/// See [`Unknown`].
+	Unknown(Unknown<Explicit<T>>),
+}
+
+impl<T> From<Explicit<T>> for MatchOption<T>
+{
+	fn from(explicit: Explicit<T>) -> Self
+	{
+		match explicit
+		{
+			Explicit::And(conditions) => Self::And(conditions),
+			Explicit::Any => Self::Any,
+			Explicit::EqualTo(x) => Self::EqualTo(x),
+			Explicit::GreaterThan(x) => Self::GreaterThan(x),
+			Explicit::InRange(low, high) => Self::InRange(low, high),
+			Explicit::LessThan(x) => Self::LessThan(x),
+			Explicit::None => Self::None,
+			Explicit::Not(condition) => Self::Not(condition),
+			Explicit::Or(conditions) => Self::Or(conditions),
+		}
+	}
+}
+
+impl<T> Shorthand<T>
+{
+	/// Turn this shorthand into a [`MatchOption`], or explain why it is not one.
+	fn into_match<E>(self) -> Result<MatchOption<T>, E>
+	where
+		E: de::Error,
+	{
+		match self
+		{
+			Self::Condition(condition) => condition.into_result().map(Into::into),
+			Self::Many(values) => Ok(MatchOption::Or(
+				values.into_iter().map(MatchOption::from).collect(),
+			)),
+			Self::One(value) => Ok(value.into()),
+			Self::Unknown(unknown) => Err(unknown.into_error()),
+		}
+	}
+}
+
+impl<'de, T> Deserialize<'de> for MatchOption<T>
+where
+	T: Deserialize<'de>,
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		if deserializer.is_human_readable()
+		{
+			Shorthand::deserialize(deserializer)?.into_match()
+		}
+		else
+		{
+			Explicit::deserialize(deserializer).map(Into::into)
+		}
+	}
+}
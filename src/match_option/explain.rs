@@ -0,0 +1,53 @@
+use core::fmt::Debug;
+
+use super::MatchOption;
+use crate::{Explain, Explanation, Result};
+
+impl<T> Explain<Option<T>> for MatchOption<T>
+where
+	T: Debug + PartialOrd,
+{
+	fn explain(&self, value: &Option<T>) -> Result<Explanation>
+	{
+		Ok(match self
+		{
+			Self::And(conditions) => Explanation::all(
+				"and",
+				conditions
+					.iter()
+					.map(|c| c.explain(value))
+					.collect::<Result<_>>()?,
+			),
+			Self::Any => Explanation::constant("any", true),
+			Self::EqualTo(x) => Explanation::comparison(
+				format!("equal_to {x:?}"),
+				value.as_ref().is_some_and(|v| v == x),
+				value,
+			),
+			Self::GreaterThan(x) => Explanation::comparison(
+				format!("greater_than {x:?}"),
+				value.as_ref().is_some_and(|v| v > x),
+				value,
+			),
+			Self::InRange(low, high) => Explanation::comparison(
+				format!("in_range [{low:?}, {high:?}]"),
+				value.as_ref().is_some_and(|v| low <= v && v < high),
+				value,
+			),
+			Self::LessThan(x) => Explanation::comparison(
+				format!("less_than {x:?}"),
+				value.as_ref().is_some_and(|v| v < x),
+				value,
+			),
+			Self::None => Explanation::comparison("none", value.is_none(), value),
+			Self::Not(condition) => Explanation::not(condition.explain(value)?),
+			Self::Or(conditions) => Explanation::any(
+				"or",
+				conditions
+					.iter()
+					.map(|c| c.explain(value))
+					.collect::<Result<_>>()?,
+			),
+		})
+	}
+}
@@ -0,0 +1,16 @@
+use super::MatchOption;
+use crate::{FromExample, TextSearch};
+
+impl<T> FromExample<Option<T>> for MatchOption<T>
+where
+	T: Clone + Default + PartialEq,
+{
+	fn from_example(example: &Option<T>, _: TextSearch) -> Self
+	{
+		match example
+		{
+			Some(value) if *value != T::default() => Self::EqualTo(value.clone()),
+			_ => Self::Any,
+		}
+	}
+}
@@ -0,0 +1,33 @@
+use schemars::{
+	gen::SchemaGenerator,
+	schema::{Schema, SchemaObject, SubschemaValidation},
+	JsonSchema,
+};
+
+use super::{deserialize::Explicit, MatchOption};
+
+impl<T> JsonSchema for MatchOption<T>
+where
+	T: JsonSchema,
+{
+	fn schema_name() -> String
+	{
+		format!("MatchOption_for_{}", T::schema_name())
+	}
+
+	fn json_schema(gen: &mut SchemaGenerator) -> Schema
+	{
+		SchemaObject {
+			subschemas: Some(Box::new(SubschemaValidation {
+				any_of: Some(vec![
+					Explicit::<T>::json_schema(gen),
+					gen.subschema_for::<Vec<Option<T>>>(),
+					gen.subschema_for::<Option<T>>(),
+				]),
+				..Default::default()
+			})),
+			..Default::default()
+		}
+		.into()
+	}
+}
@@ -0,0 +1,24 @@
+use proptest::{option, prelude::BoxedStrategy, strategy::Strategy};
+
+use super::MatchOption;
+use crate::{satisfy, strategy, RecordStrategy};
+
+impl<T> RecordStrategy for MatchOption<T>
+where
+	T: satisfy::Domain + strategy::Value,
+{
+	fn matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		strategy::matching::<_, Option<T>>(self, Self::records())
+	}
+
+	fn not_matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		Self::Not(self.clone().into()).matching()
+	}
+
+	fn records() -> BoxedStrategy<Self::Record>
+	{
+		option::of(T::strategy()).boxed()
+	}
+}
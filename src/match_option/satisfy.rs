@@ -0,0 +1,52 @@
+use super::MatchOption;
+use crate::{satisfy, Compile, FieldPath, IsMatch, Satisfiability, Satisfy};
+
+/// Add every value which the `condition` compares against to the `constants`.
+fn constants<T>(condition: &MatchOption<T>, constants: &mut Vec<T>)
+where
+	T: Clone,
+{
+	match condition
+	{
+		MatchOption::And(conditions) | MatchOption::Or(conditions) =>
+		{
+			conditions
+				.iter()
+				.for_each(|c| self::constants(c, constants));
+		},
+		MatchOption::Any | MatchOption::None => (),
+		MatchOption::EqualTo(x) | MatchOption::GreaterThan(x) | MatchOption::LessThan(x) =>
+		{
+			constants.push(x.clone());
+		},
+		MatchOption::InRange(low, high) => constants.extend([low.clone(), high.clone()]),
+		MatchOption::Not(condition) => self::constants(condition, constants),
+	}
+}
+
+impl<T> Satisfy for MatchOption<T>
+where
+	T: satisfy::Domain,
+{
+	type Record = Option<T>;
+
+	fn satisfy_at(&self, path: &FieldPath) -> Satisfiability<Self::Record>
+	{
+		let Ok(compiled) = self.compile()
+		else
+		{
+			return Satisfiability::Unknown;
+		};
+
+		let mut values = Vec::new();
+		constants(self, &mut values);
+
+		let (candidates, exhaustive) = satisfy::representatives(values);
+		Satisfiability::search(
+			core::iter::once(None).chain(candidates.into_iter().map(Some)),
+			exhaustive,
+			path,
+			|c| compiled.is_match(c),
+		)
+	}
+}
@@ -0,0 +1,67 @@
+use core::time::Duration;
+
+use clinvoice_schema::chrono::NaiveDateTime;
+use humantime_serde::Serde;
+
+use super::MatchOption;
+use crate::{FieldPath, Fold, Folder, Visit, VisitMut, Visitor, VisitorMut};
+
+impl Visit for MatchOption<NaiveDateTime>
+{
+	fn visit_at<V>(&self, path: &FieldPath, visitor: &mut V)
+	where
+		V: Visitor + ?Sized,
+	{
+		visitor.visit_match_option_date(path, self);
+	}
+}
+
+impl VisitMut for MatchOption<NaiveDateTime>
+{
+	fn visit_mut_at<V>(&mut self, path: &FieldPath, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized,
+	{
+		visitor.visit_match_option_date_mut(path, self);
+	}
+}
+
+impl Fold for MatchOption<NaiveDateTime>
+{
+	fn fold_at<F>(self, path: &FieldPath, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized,
+	{
+		folder.fold_match_option_date(path, self)
+	}
+}
+
+impl Visit for MatchOption<Serde<Duration>>
+{
+	fn visit_at<V>(&self, path: &FieldPath, visitor: &mut V)
+	where
+		V: Visitor + ?Sized,
+	{
+		visitor.visit_match_option_duration(path, self);
+	}
+}
+
+impl VisitMut for MatchOption<Serde<Duration>>
+{
+	fn visit_mut_at<V>(&mut self, path: &FieldPath, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized,
+	{
+		visitor.visit_match_option_duration_mut(path, self);
+	}
+}
+
+impl Fold for MatchOption<Serde<Duration>>
+{
+	fn fold_at<F>(self, path: &FieldPath, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized,
+	{
+		folder.fold_match_option_duration(path, self)
+	}
+}
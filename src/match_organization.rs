@@ -1,6 +1,20 @@
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod compiled_match_organization;
+mod explain;
 mod from;
+mod from_example;
+mod indexable;
+mod orderable;
+#[cfg(feature = "proptest")]
+mod record_strategy;
+mod satisfy;
+mod visit;
 
 use clinvoice_schema::Id;
+pub use compiled_match_organization::CompiledMatchOrganization;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -25,14 +39,14 @@ use super::{Match, MatchLocation, MatchStr};
 /// id: any
 /// location:
 ///   outer:
-///     some:
-///       name:
-///         equal_to: "Mexico"
+///     name:
+///       equal_to: "Mexico"
 /// name:
 ///   equal_to: "Some Company"
 /// # "#).is_ok());
 /// ```
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct MatchOrganization
 {
@@ -0,0 +1,25 @@
+use clinvoice_schema::Id;
+use proptest::{
+	arbitrary::{any_with, Arbitrary},
+	prelude::{BoxedStrategy, Strategy},
+};
+
+use super::MatchOrganization;
+use crate::{Match, MatchLocation, MatchStr, Recursion};
+
+impl Arbitrary for MatchOrganization
+{
+	type Parameters = Recursion;
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with(recursion: Self::Parameters) -> Self::Strategy
+	{
+		(
+			any_with::<Match<Id>>(recursion),
+			any_with::<MatchLocation>(recursion),
+			any_with::<MatchStr<String>>(recursion),
+		)
+			.prop_map(|(id, location, name)| Self { id, location, name })
+			.boxed()
+	}
+}
@@ -0,0 +1,39 @@
+use clinvoice_schema::{Id, Organization};
+
+use super::MatchOrganization;
+use crate::{Compile, CompiledMatch, CompiledMatchLocation, CompiledMatchStr, IsMatch, Result};
+
+/// A [`MatchOrganization`] which has been [compiled](Compile).
+#[derive(Clone, Debug)]
+pub struct CompiledMatchOrganization
+{
+	id: CompiledMatch<Id>,
+
+	location: CompiledMatchLocation,
+
+	name: CompiledMatchStr,
+}
+
+impl Compile for MatchOrganization
+{
+	type Compiled = CompiledMatchOrganization;
+
+	fn compile(&self) -> Result<Self::Compiled>
+	{
+		Ok(CompiledMatchOrganization {
+			id: self.id.compile()?,
+			location: self.location.compile()?,
+			name: self.name.compile()?,
+		})
+	}
+}
+
+impl IsMatch<Organization> for CompiledMatchOrganization
+{
+	fn is_match(&self, organization: &Organization) -> bool
+	{
+		self.id.is_match(&organization.id) &&
+			self.location.is_match(&organization.location) &&
+			self.name.is_match(organization.name.as_str())
+	}
+}
@@ -0,0 +1,19 @@
+use clinvoice_schema::Organization;
+
+use super::MatchOrganization;
+use crate::{Explain, Explanation, Result};
+
+impl Explain<Organization> for MatchOrganization
+{
+	fn explain(&self, organization: &Organization) -> Result<Explanation>
+	{
+		Ok(Explanation::all("MatchOrganization", vec![
+			Explanation::field("id", self.id.explain(&organization.id)?),
+			self
+				.location
+				.explain(&organization.location)?
+				.with_label("location"),
+			Explanation::field("name", self.name.explain(organization.name.as_str())?),
+		]))
+	}
+}
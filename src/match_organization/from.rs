@@ -1,4 +1,4 @@
-use clinvoice_schema::Id;
+use clinvoice_schema::{Id, Organization};
 
 use super::MatchOrganization;
 use crate::Match;
@@ -21,3 +21,15 @@ impl From<Match<Id>> for MatchOrganization
 		}
 	}
 }
+
+impl From<&Organization> for MatchOrganization
+{
+	fn from(organization: &Organization) -> Self
+	{
+		Self {
+			id: organization.id.into(),
+			location: (&organization.location).into(),
+			name: organization.name.clone().into(),
+		}
+	}
+}
@@ -0,0 +1,16 @@
+use clinvoice_schema::Organization;
+
+use super::MatchOrganization;
+use crate::{FromExample, Match, MatchLocation, MatchStr, TextSearch};
+
+impl FromExample<Organization> for MatchOrganization
+{
+	fn from_example(example: &Organization, search: TextSearch) -> Self
+	{
+		Self {
+			id: Match::from_example(&example.id, search),
+			location: MatchLocation::from_example(&example.location, search),
+			name: MatchStr::from_example(&example.name, search),
+		}
+	}
+}
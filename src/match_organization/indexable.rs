@@ -0,0 +1,47 @@
+use clinvoice_schema::{chrono::NaiveDateTime, Id, Organization};
+
+use super::MatchOrganization;
+use crate::{Indexable, Match, MatchLocation, MatchStr};
+
+impl Indexable for MatchOrganization
+{
+	type Record = Organization;
+
+	fn date_conditions(&self) -> Vec<&Match<NaiveDateTime>>
+	{
+		vec![]
+	}
+
+	fn id_conditions(&self) -> Vec<&Match<Id>>
+	{
+		let mut conditions = vec![&self.id];
+		conditions.extend(self.location.id_conditions());
+		conditions
+	}
+
+	fn record_dates(_record: &Self::Record) -> Vec<NaiveDateTime>
+	{
+		vec![]
+	}
+
+	fn record_ids(record: &Self::Record) -> Vec<Id>
+	{
+		let mut values = vec![record.id];
+		values.extend(MatchLocation::record_ids(&record.location));
+		values
+	}
+
+	fn record_strs(record: &Self::Record) -> Vec<&str>
+	{
+		let mut values = vec![record.name.as_str()];
+		values.extend(MatchLocation::record_strs(&record.location));
+		values
+	}
+
+	fn str_conditions(&self) -> Vec<&MatchStr<String>>
+	{
+		let mut conditions = vec![&self.name];
+		conditions.extend(self.location.str_conditions());
+		conditions
+	}
+}
@@ -0,0 +1,20 @@
+use clinvoice_schema::Organization;
+
+use super::MatchOrganization;
+use crate::{FieldValue, MatchLocation, Orderable};
+
+impl Orderable for MatchOrganization
+{
+	type Record = Organization;
+
+	fn field_value(record: Option<&Self::Record>, field: &[&str]) -> Option<FieldValue>
+	{
+		match field
+		{
+			["id"] => Some(record.map(|r| r.id).into()),
+			["location", rest @ ..] => MatchLocation::field_value(record.map(|r| &r.location), rest),
+			["name"] => Some(record.map(|r| r.name.as_str()).into()),
+			_ => None,
+		}
+	}
+}
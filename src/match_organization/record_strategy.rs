@@ -0,0 +1,49 @@
+use clinvoice_schema::{Id, Organization};
+use proptest::prelude::{BoxedStrategy, Strategy};
+
+use super::MatchOrganization;
+use crate::{strategy, Match, MatchLocation, MatchStr, RecordStrategy};
+
+impl RecordStrategy for MatchOrganization
+{
+	fn matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		Some(
+			(
+				self.id.matching()?,
+				self.location.matching()?,
+				self.name.matching()?,
+			)
+				.prop_map(|(id, location, name)| Organization { id, location, name })
+				.boxed(),
+		)
+	}
+
+	fn not_matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		strategy::union([
+			strategy::with(Self::records(), self.id.not_matching(), |o, id| o.id = id),
+			strategy::with(
+				Self::records(),
+				self.location.not_matching(),
+				|o, location| {
+					o.location = location;
+				},
+			),
+			strategy::with(Self::records(), self.name.not_matching(), |o, name| {
+				o.name = name
+			}),
+		])
+	}
+
+	fn records() -> BoxedStrategy<Self::Record>
+	{
+		(
+			Match::<Id>::records(),
+			MatchLocation::records(),
+			MatchStr::<String>::records(),
+		)
+			.prop_map(|(id, location, name)| Organization { id, location, name })
+			.boxed()
+	}
+}
@@ -0,0 +1,29 @@
+use clinvoice_schema::Organization;
+
+use super::MatchOrganization;
+use crate::{FieldPath, Satisfiability, Satisfy};
+
+impl Satisfy for MatchOrganization
+{
+	type Record = Organization;
+
+	fn satisfy_at(&self, path: &FieldPath) -> Satisfiability<Self::Record>
+	{
+		match (
+			self.id.satisfy_at(&path.join("id")),
+			self.location.satisfy_at(&path.join("location")),
+			self.name.satisfy_at(&path.join("name")),
+		)
+		{
+			(
+				Satisfiability::Satisfiable(id),
+				Satisfiability::Satisfiable(location),
+				Satisfiability::Satisfiable(name),
+			) => Satisfiability::Satisfiable(Organization { id, location, name }),
+			(id, location, name) =>
+			{
+				Satisfiability::unsatisfied([id.proof(), location.proof(), name.proof()])
+			},
+		}
+	}
+}
@@ -0,0 +1,40 @@
+use super::MatchOrganization;
+use crate::{FieldPath, Fold, Folder, Visit, VisitMut, Visitor, VisitorMut};
+
+impl Visit for MatchOrganization
+{
+	fn visit_at<V>(&self, path: &FieldPath, visitor: &mut V)
+	where
+		V: Visitor + ?Sized,
+	{
+		self.id.visit_at(&path.join("id"), visitor);
+		self.location.visit_at(&path.join("location"), visitor);
+		self.name.visit_at(&path.join("name"), visitor);
+	}
+}
+
+impl VisitMut for MatchOrganization
+{
+	fn visit_mut_at<V>(&mut self, path: &FieldPath, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized,
+	{
+		self.id.visit_mut_at(&path.join("id"), visitor);
+		self.location.visit_mut_at(&path.join("location"), visitor);
+		self.name.visit_mut_at(&path.join("name"), visitor);
+	}
+}
+
+impl Fold for MatchOrganization
+{
+	fn fold_at<F>(self, path: &FieldPath, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized,
+	{
+		Self {
+			id: self.id.fold_at(&path.join("id"), folder),
+			location: self.location.fold_at(&path.join("location"), folder),
+			name: self.name.fold_at(&path.join("name"), folder),
+		}
+	}
+}
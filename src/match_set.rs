@@ -1,9 +1,21 @@
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod compiled_match_set;
 mod default;
 mod exchange;
+mod explain;
 mod from;
+mod from_example;
+#[cfg(feature = "proptest")]
+mod record_strategy;
+mod satisfy;
+mod visit;
 
-use core::{cmp::Eq, fmt::Debug};
+use core::{cmp::Eq, convert::Infallible, fmt::Debug};
 
+pub use compiled_match_set::CompiledMatchSet;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -124,6 +136,7 @@ use serde::{Deserialize, Serialize};
 	derive(Deserialize, Serialize),
 	serde(rename_all = "snake_case")
 )]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum MatchSet<T>
 {
@@ -162,24 +175,13 @@ impl<T> MatchSet<T>
 	///   MatchSet::Contains(Match::GreaterThan(5)),
 	/// );
 	/// ```
-	pub fn map<F, MapTo>(self, f: F) -> MatchSet<MapTo>
+	pub fn map<F, MapTo>(self, mut f: F) -> MatchSet<MapTo>
 	where
-		F: Copy + Fn(T) -> MapTo,
+		F: FnMut(T) -> MapTo,
 	{
-		match self
-		{
-			Self::And(match_conditions) =>
-			{
-				MatchSet::And(match_conditions.into_iter().map(|m| m.map(f)).collect())
-			},
-			Self::Any => MatchSet::Any,
-			Self::Contains(x) => MatchSet::Contains(f(x)),
-			Self::Not(match_condition) => MatchSet::Not(match_condition.map(f).into()),
-			Self::Or(match_conditions) =>
-			{
-				MatchSet::Or(match_conditions.into_iter().map(|m| m.map(f)).collect())
-			},
-		}
+		self
+			.try_map(|x| Ok::<_, Infallible>(f(x)))
+			.unwrap_or_else(|e| match e {})
 	}
 
 	/// Transform some [`Match`] of type `T` into another type `U` by providing a mapping `f`unction.
@@ -187,23 +189,103 @@ impl<T> MatchSet<T>
 	/// # See also
 	///
 	/// * [`Match::map`]
-	pub fn map_ref<F, MapTo>(&self, f: F) -> MatchSet<MapTo>
+	pub fn map_ref<F, MapTo>(&self, mut f: F) -> MatchSet<MapTo>
+	where
+		F: FnMut(&T) -> MapTo,
+	{
+		self
+			.try_map_ref(|x| Ok::<_, Infallible>(f(x)))
+			.unwrap_or_else(|e| match e {})
+	}
+
+	/// Transform some [`MatchSet`] of type `T` into another type `U` by providing a fallible mapping
+	/// `f`unction. The first [`Err`] returned by `f` is returned immediately.
+	///
+	/// # See also
+	///
+	/// * [`MatchSet::map`]
+	/// * [`Iterator::try_fold`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use clinvoice_match::{Match, MatchSet};
+	/// # use pretty_assertions::assert_eq;
+	///
+	/// assert_eq!(
+	///   MatchSet::Contains(Match::EqualTo("5")).try_map(|m| m.try_map(str::parse::<isize>)),
+	///   Ok(MatchSet::Contains(Match::EqualTo(5)))
+	/// );
+	///
+	/// assert!(MatchSet::Contains("five").try_map(str::parse::<isize>).is_err());
+	/// ```
+	pub fn try_map<E, F, MapTo>(self, mut f: F) -> Result<MatchSet<MapTo>, E>
+	where
+		F: FnMut(T) -> Result<MapTo, E>,
+	{
+		self.try_map_with(&mut f)
+	}
+
+	/// Transform some [`MatchSet`] of type `T` into another type `U` by providing a fallible mapping
+	/// `f`unction. The first [`Err`] returned by `f` is returned immediately.
+	///
+	/// # See also
+	///
+	/// * [`MatchSet::try_map`]
+	pub fn try_map_ref<E, F, MapTo>(&self, mut f: F) -> Result<MatchSet<MapTo>, E>
+	where
+		F: FnMut(&T) -> Result<MapTo, E>,
+	{
+		self.try_map_ref_with(&mut f)
+	}
+
+	/// Implementation of [`MatchSet::try_map_ref`] which can be called recursively.
+	fn try_map_ref_with<E, F, MapTo>(&self, f: &mut F) -> Result<MatchSet<MapTo>, E>
+	where
+		F: FnMut(&T) -> Result<MapTo, E>,
+	{
+		Ok(match self
+		{
+			Self::And(match_conditions) => MatchSet::And(
+				match_conditions
+					.iter()
+					.map(|m| m.try_map_ref_with(f))
+					.collect::<Result<_, _>>()?,
+			),
+			Self::Any => MatchSet::Any,
+			Self::Contains(x) => MatchSet::Contains(f(x)?),
+			Self::Not(match_condition) => MatchSet::Not(match_condition.try_map_ref_with(f)?.into()),
+			Self::Or(match_conditions) => MatchSet::Or(
+				match_conditions
+					.iter()
+					.map(|m| m.try_map_ref_with(f))
+					.collect::<Result<_, _>>()?,
+			),
+		})
+	}
+
+	/// Implementation of [`MatchSet::try_map`] which can be called recursively.
+	fn try_map_with<E, F, MapTo>(self, f: &mut F) -> Result<MatchSet<MapTo>, E>
 	where
-		F: Copy + Fn(&T) -> MapTo,
+		F: FnMut(T) -> Result<MapTo, E>,
 	{
-		match self
+		Ok(match self
 		{
-			Self::And(match_conditions) =>
-			{
-				MatchSet::And(match_conditions.iter().map(|m| m.map_ref(f)).collect())
-			},
+			Self::And(match_conditions) => MatchSet::And(
+				match_conditions
+					.into_iter()
+					.map(|m| m.try_map_with(f))
+					.collect::<Result<_, _>>()?,
+			),
 			Self::Any => MatchSet::Any,
-			Self::Contains(x) => MatchSet::Contains(f(x)),
-			Self::Not(match_condition) => MatchSet::Not(match_condition.map_ref(f).into()),
-			Self::Or(match_conditions) =>
-			{
-				MatchSet::Or(match_conditions.iter().map(|m| m.map_ref(f)).collect())
-			},
-		}
+			Self::Contains(x) => MatchSet::Contains(f(x)?),
+			Self::Not(match_condition) => MatchSet::Not((*match_condition).try_map_with(f)?.into()),
+			Self::Or(match_conditions) => MatchSet::Or(
+				match_conditions
+					.into_iter()
+					.map(|m| m.try_map_with(f))
+					.collect::<Result<_, _>>()?,
+			),
+		})
 	}
 }
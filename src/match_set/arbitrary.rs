@@ -0,0 +1,32 @@
+use core::fmt::Debug;
+
+use proptest::{
+	arbitrary::{any_with, Arbitrary},
+	prelude::{BoxedStrategy, Just, Strategy},
+	prop_oneof,
+};
+
+use super::MatchSet;
+use crate::Recursion;
+
+impl<T> Arbitrary for MatchSet<T>
+where
+	T: Arbitrary<Parameters = Recursion> + Clone + Debug + 'static,
+{
+	type Parameters = Recursion;
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with(recursion: Self::Parameters) -> Self::Strategy
+	{
+		recursion.nest(
+			prop_oneof![
+				Just(Self::Any),
+				any_with::<T>(recursion.deeper()).prop_map(Self::Contains)
+			]
+			.boxed(),
+			Self::And,
+			Self::Not,
+			Self::Or,
+		)
+	}
+}
@@ -0,0 +1,68 @@
+use super::MatchSet;
+use crate::{Compile, IsMatch, Result};
+
+/// A [`MatchSet`] which has been [compiled](Compile).
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CompiledMatchSet<T>
+{
+	/// Same as [`MatchSet::And`].
+	And(Vec<Self>),
+
+	/// Same as [`MatchSet::Any`].
+	Any,
+
+	/// Same as [`MatchSet::Contains`].
+	Contains(T),
+
+	/// Same as [`MatchSet::Not`].
+	Not(Box<Self>),
+
+	/// Same as [`MatchSet::Or`].
+	Or(Vec<Self>),
+}
+
+impl<T> Compile for MatchSet<T>
+where
+	T: Compile,
+{
+	type Compiled = CompiledMatchSet<T::Compiled>;
+
+	fn compile(&self) -> Result<Self::Compiled>
+	{
+		Ok(match self
+		{
+			Self::And(conditions) => CompiledMatchSet::And(
+				conditions
+					.iter()
+					.map(Self::compile)
+					.collect::<Result<_>>()?,
+			),
+			Self::Any => CompiledMatchSet::Any,
+			Self::Contains(condition) => CompiledMatchSet::Contains(condition.compile()?),
+			Self::Not(condition) => CompiledMatchSet::Not(condition.compile()?.into()),
+			Self::Or(conditions) => CompiledMatchSet::Or(
+				conditions
+					.iter()
+					.map(Self::compile)
+					.collect::<Result<_>>()?,
+			),
+		})
+	}
+}
+
+impl<T, V> IsMatch<[V]> for CompiledMatchSet<T>
+where
+	T: IsMatch<V>,
+{
+	fn is_match(&self, value: &[V]) -> bool
+	{
+		match self
+		{
+			Self::And(conditions) => conditions.iter().all(|c| c.is_match(value)),
+			Self::Any => true,
+			Self::Contains(condition) => value.iter().any(|v| condition.is_match(v)),
+			Self::Not(condition) => !condition.is_match(value),
+			Self::Or(conditions) => conditions.iter().any(|c| c.is_match(value)),
+		}
+	}
+}
@@ -0,0 +1,38 @@
+use super::MatchSet;
+use crate::{Explain, Explanation, Result};
+
+impl<T, V> Explain<[V]> for MatchSet<T>
+where
+	T: Explain<V>,
+{
+	fn explain(&self, value: &[V]) -> Result<Explanation>
+	{
+		Ok(match self
+		{
+			Self::And(conditions) => Explanation::all(
+				"and",
+				conditions
+					.iter()
+					.map(|c| c.explain(value))
+					.collect::<Result<_>>()?,
+			),
+			Self::Any => Explanation::constant("any", true),
+			Self::Contains(condition) => Explanation::any(
+				"contains",
+				value
+					.iter()
+					.enumerate()
+					.map(|(i, v)| condition.explain(v).map(|e| e.with_label(format!("[{i}]"))))
+					.collect::<Result<_>>()?,
+			),
+			Self::Not(condition) => Explanation::not(condition.explain(value)?),
+			Self::Or(conditions) => Explanation::any(
+				"or",
+				conditions
+					.iter()
+					.map(|c| c.explain(value))
+					.collect::<Result<_>>()?,
+			),
+		})
+	}
+}
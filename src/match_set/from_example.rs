@@ -0,0 +1,22 @@
+use super::MatchSet;
+use crate::{FromExample, TextSearch};
+
+impl<M, T> FromExample<Vec<T>> for MatchSet<M>
+where
+	M: FromExample<T>,
+{
+	/// The set must contain a value like each value of the `example`.
+	fn from_example(example: &Vec<T>, search: TextSearch) -> Self
+	{
+		match example.is_empty()
+		{
+			true => Self::Any,
+			false => Self::And(
+				example
+					.iter()
+					.map(|value| Self::Contains(M::from_example(value, search)))
+					.collect(),
+			),
+		}
+	}
+}
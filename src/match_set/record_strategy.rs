@@ -0,0 +1,47 @@
+use core::fmt::Debug;
+
+use proptest::{
+	collection,
+	prelude::{BoxedStrategy, Strategy},
+	prop_oneof,
+};
+
+use super::MatchSet;
+use crate::{strategy, Compile, IsMatch, RecordStrategy, Satisfy};
+
+impl<T> RecordStrategy for MatchSet<T>
+where
+	T: Clone + Compile + PartialEq + RecordStrategy,
+	T::Compiled: IsMatch<T::Record> + 'static,
+	T::Record: Clone + Debug + 'static,
+{
+	/// Sets are generated both at random, and by adding random values to an
+	/// [example](crate::Satisfy), since a random set rarely contains the values that a
+	/// [`MatchSet::Contains`] requires.
+	fn matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		let example = self.satisfy().example()?;
+		strategy::matching::<_, [T::Record]>(
+			self,
+			prop_oneof![
+				Self::records(),
+				Self::records().prop_map(move |records| example
+					.iter()
+					.cloned()
+					.chain(records)
+					.collect()),
+			]
+			.boxed(),
+		)
+	}
+
+	fn not_matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		Self::Not(self.clone().into()).matching()
+	}
+
+	fn records() -> BoxedStrategy<Self::Record>
+	{
+		collection::vec(T::records(), 0..3).boxed()
+	}
+}
@@ -0,0 +1,114 @@
+use super::MatchSet;
+use crate::{satisfy, Compile, FieldPath, IsMatch, Satisfiability, Satisfy};
+
+/// Add each distinct [`MatchSet::Contains`] condition of the `condition` to the `atoms`.
+fn atoms<'condition, T>(condition: &'condition MatchSet<T>, atoms: &mut Vec<&'condition T>)
+where
+	T: PartialEq,
+{
+	match condition
+	{
+		MatchSet::And(conditions) | MatchSet::Or(conditions) =>
+		{
+			conditions.iter().for_each(|c| self::atoms(c, atoms));
+		},
+		MatchSet::Any => (),
+		MatchSet::Contains(value) =>
+		{
+			if !atoms.contains(&value)
+			{
+				atoms.push(value);
+			}
+		},
+		MatchSet::Not(condition) => self::atoms(condition, atoms),
+	}
+}
+
+/// Evaluate the `condition` for a set in which some value matches each of the `atoms` whose
+/// corresponding bit of the `assignment` is set, and no value matches the others.
+fn evaluate<T>(condition: &MatchSet<T>, atoms: &[&T], assignment: u32) -> bool
+where
+	T: PartialEq,
+{
+	match condition
+	{
+		MatchSet::And(conditions) => conditions.iter().all(|c| evaluate(c, atoms, assignment)),
+		MatchSet::Any => true,
+		MatchSet::Contains(value) => atoms
+			.iter()
+			.position(|atom| *atom == value)
+			.is_some_and(|i| assignment & (1 << i) != 0),
+		MatchSet::Not(condition) => !evaluate(condition, atoms, assignment),
+		MatchSet::Or(conditions) => conditions.iter().any(|c| evaluate(c, atoms, assignment)),
+	}
+}
+
+impl<T> Satisfy for MatchSet<T>
+where
+	T: Compile + PartialEq + Satisfy,
+	T::Compiled: IsMatch<T::Record>,
+	T::Record: Clone,
+{
+	type Record = Vec<T::Record>;
+
+	/// Each combination of the [`MatchSet::Contains`] conditions being true or false is tried. A
+	/// combination is satisfied by an example of each true condition, as long as those examples do
+	/// not match any of the false conditions.
+	fn satisfy_at(&self, path: &FieldPath) -> Satisfiability<Self::Record>
+	{
+		let mut atoms = Vec::new();
+		self::atoms(self, &mut atoms);
+
+		let Ok(compiled) = atoms
+			.iter()
+			.map(|atom| atom.compile())
+			.collect::<crate::Result<Vec<_>>>()
+		else
+		{
+			return Satisfiability::Unknown;
+		};
+
+		if atoms.len() > satisfy::MAX_CONDITIONS
+		{
+			return Satisfiability::Unknown;
+		}
+
+		let examples: Vec<_> = atoms.iter().map(|atom| atom.satisfy_at(path)).collect();
+		let is_set = |assignment: u32, i: usize| assignment & (1 << i) != 0;
+
+		let mut unknown = false;
+		for assignment in (0..1 << atoms.len()).filter(|a| evaluate(self, &atoms, *a))
+		{
+			let records = examples
+				.iter()
+				.enumerate()
+				.filter(|(i, _)| is_set(assignment, *i))
+				.map(|(_, example)| match example
+				{
+					Satisfiability::Satisfiable(record)
+						if compiled
+							.iter()
+							.enumerate()
+							.all(|(j, c)| is_set(assignment, j) || !c.is_match(record)) =>
+					{
+						Ok(record.clone())
+					},
+					Satisfiability::Unsatisfiable(_) => Err(false),
+					_ => Err(true),
+				})
+				.collect::<Result<Vec<_>, _>>();
+
+			match records
+			{
+				Ok(records) => return Satisfiability::Satisfiable(records),
+				Err(u) => unknown |= u,
+			}
+		}
+
+		match unknown
+		{
+			true => Satisfiability::Unknown,
+			false => Satisfiability::Unsatisfiable(path.clone()),
+		}
+	}
+}
@@ -0,0 +1,77 @@
+use super::MatchSet;
+use crate::{FieldPath, Fold, Folder, Visit, VisitMut, Visitor, VisitorMut};
+
+/// Every value which is [`Contains`](MatchSet::Contains)ed by the [`MatchSet`] is visited at the
+/// same `path` as the [`MatchSet`] itself.
+impl<T> Visit for MatchSet<T>
+where
+	T: Visit,
+{
+	fn visit_at<V>(&self, path: &FieldPath, visitor: &mut V)
+	where
+		V: Visitor + ?Sized,
+	{
+		match self
+		{
+			Self::And(conditions) | Self::Or(conditions) =>
+			{
+				conditions.iter().for_each(|c| c.visit_at(path, visitor));
+			},
+			Self::Any => (),
+			Self::Contains(value) => value.visit_at(path, visitor),
+			Self::Not(condition) => condition.visit_at(path, visitor),
+		}
+	}
+}
+
+impl<T> VisitMut for MatchSet<T>
+where
+	T: VisitMut,
+{
+	fn visit_mut_at<V>(&mut self, path: &FieldPath, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized,
+	{
+		match self
+		{
+			Self::And(conditions) | Self::Or(conditions) =>
+			{
+				conditions
+					.iter_mut()
+					.for_each(|c| c.visit_mut_at(path, visitor));
+			},
+			Self::Any => (),
+			Self::Contains(value) => value.visit_mut_at(path, visitor),
+			Self::Not(condition) => condition.visit_mut_at(path, visitor),
+		}
+	}
+}
+
+impl<T> Fold for MatchSet<T>
+where
+	T: Fold,
+{
+	fn fold_at<F>(self, path: &FieldPath, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized,
+	{
+		match self
+		{
+			Self::And(conditions) => Self::And(
+				conditions
+					.into_iter()
+					.map(|c| c.fold_at(path, folder))
+					.collect(),
+			),
+			Self::Any => Self::Any,
+			Self::Contains(value) => Self::Contains(value.fold_at(path, folder)),
+			Self::Not(condition) => Self::Not((*condition).fold_at(path, folder).into()),
+			Self::Or(conditions) => Self::Or(
+				conditions
+					.into_iter()
+					.map(|c| c.fold_at(path, folder))
+					.collect(),
+			),
+		}
+	}
+}
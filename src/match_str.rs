@@ -1,8 +1,24 @@
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod compiled_match_str;
 mod default;
+#[cfg(feature = "serde")]
+mod deserialize;
+mod explain;
 mod from;
+mod from_example;
+#[cfg(feature = "schemars")]
+mod json_schema;
+#[cfg(feature = "proptest")]
+mod record_strategy;
+mod satisfy;
+mod visit;
+
+use core::convert::Infallible;
 
+pub use compiled_match_str::CompiledMatchStr;
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 
 /// A value which describes the condition which some string of type `T` must meet in order to
 /// "_match_".
@@ -98,6 +114,33 @@ use serde::{Deserialize, Serialize};
 /// # ").is_ok());
 /// ```
 ///
+/// ### Shorthand
+///
+/// A bare string is shorthand for [`MatchStr::EqualTo`], and a list of strings is shorthand for
+/// [`MatchStr::Or`] of [`MatchStr::EqualTo`]. Serializing a [`MatchStr`] always uses the explicit
+/// form.
+///
+/// A map with a single key which names a condition (e.g. `equal_to`) is always read as that
+/// condition, so mistakes within it are reported rather than tried as shorthand.
+///
+/// Note that `any` is always read as [`MatchStr::Any`]. Use `equal_to: "any"` to match the string
+/// "any".
+///
+/// ```rust
+/// # use clinvoice_match::MatchStr;
+/// # use pretty_assertions::assert_eq;
+/// # use serde_yaml::from_str;
+/// # assert_eq!(from_str::<MatchStr<String>>(r#"
+/// "Hired"
+/// # "#).unwrap(), MatchStr::EqualTo("Hired".into()));
+///
+/// // -------------------
+///
+/// # assert_eq!(from_str::<MatchStr<String>>(r#"
+/// ["Hired", "Promoted"]
+/// # "#).unwrap(), MatchStr::Or(vec!["Hired".to_owned().into(), "Promoted".to_owned().into()]));
+/// ```
+///
 /// ### Warnings
 ///
 /// Never use the following, as it is always `false` and often begets an error:
@@ -107,11 +150,7 @@ use serde::{Deserialize, Serialize};
 /// not: any
 /// # ").is_ok());
 /// ```
-#[cfg_attr(
-	feature = "serde",
-	derive(Deserialize, Serialize),
-	serde(rename_all = "snake_case")
-)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "snake_case"))]
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum MatchStr<T>
 {
@@ -139,9 +178,14 @@ pub enum MatchStr<T>
 	///
 	/// # Warnings
 	///
-	/// The syntax of a regular expression is highly dependent on the adapter which is being used:
+	/// The regular expression is written in the syntax of the [`regex`] crate, which is used to
+	/// evaluate it in memory. Adapters translate it into their own syntax with [`Pattern`](crate::Pattern),
+	/// but some [`Construct`](crate::Construct)s cannot be translated:
 	///
 	/// * [Postgres](https://www.postgresql.org/docs/current/functions-matching.html#FUNCTIONS-POSIX-TABLE)
+	///
+	/// Use [`Dialect::check`](crate::Dialect::check) to find out whether a matcher can be evaluated
+	/// by an adapter.
 	Regex(T),
 }
 
@@ -164,26 +208,13 @@ impl<T> MatchStr<T>
 	///   MatchStr::EqualTo("5".to_string())
 	/// );
 	/// ```
-	pub fn map<F, MapTo>(self, f: F) -> MatchStr<MapTo>
+	pub fn map<F, MapTo>(self, mut f: F) -> MatchStr<MapTo>
 	where
-		F: Copy + Fn(T) -> MapTo,
+		F: FnMut(T) -> MapTo,
 	{
-		match self
-		{
-			Self::And(match_conditions) =>
-			{
-				MatchStr::And(match_conditions.into_iter().map(|m| m.map(f)).collect())
-			},
-			Self::Any => MatchStr::Any,
-			Self::Contains(x) => MatchStr::Contains(f(x)),
-			Self::EqualTo(x) => MatchStr::EqualTo(f(x)),
-			Self::Not(match_condition) => MatchStr::Not(match_condition.map(f).into()),
-			Self::Or(match_conditions) =>
-			{
-				MatchStr::Or(match_conditions.into_iter().map(|m| m.map(f)).collect())
-			},
-			Self::Regex(x) => MatchStr::Regex(f(x)),
-		}
+		self
+			.try_map(|x| Ok::<_, Infallible>(f(x)))
+			.unwrap_or_else(|e| match e {})
 	}
 
 	/// Transform some [`MatchStr`] of type `T` into another type `U` by providing a mapping function.
@@ -191,25 +222,107 @@ impl<T> MatchStr<T>
 	/// # See also
 	///
 	/// * [`MatchStr::map`]
-	pub fn map_ref<F, MapTo>(&self, f: F) -> MatchStr<MapTo>
+	pub fn map_ref<F, MapTo>(&self, mut f: F) -> MatchStr<MapTo>
+	where
+		F: FnMut(&T) -> MapTo,
+	{
+		self
+			.try_map_ref(|x| Ok::<_, Infallible>(f(x)))
+			.unwrap_or_else(|e| match e {})
+	}
+
+	/// Transform some [`MatchStr`] of type `T` into another type `U` by providing a fallible mapping
+	/// `f`unction. The first [`Err`] returned by `f` is returned immediately.
+	///
+	/// # See also
+	///
+	/// * [`MatchStr::map`]
+	/// * [`Iterator::try_fold`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use clinvoice_match::MatchStr;
+	/// # use pretty_assertions::assert_eq;
+	///
+	/// assert_eq!(
+	///   MatchStr::EqualTo("5").try_map(str::parse::<isize>),
+	///   Ok(MatchStr::EqualTo(5))
+	/// );
+	///
+	/// assert!(MatchStr::EqualTo("five").try_map(str::parse::<isize>).is_err());
+	/// ```
+	pub fn try_map<E, F, MapTo>(self, mut f: F) -> Result<MatchStr<MapTo>, E>
+	where
+		F: FnMut(T) -> Result<MapTo, E>,
+	{
+		self.try_map_with(&mut f)
+	}
+
+	/// Transform some [`MatchStr`] of type `T` into another type `U` by providing a fallible mapping
+	/// `f`unction. The first [`Err`] returned by `f` is returned immediately.
+	///
+	/// # See also
+	///
+	/// * [`MatchStr::try_map`]
+	pub fn try_map_ref<E, F, MapTo>(&self, mut f: F) -> Result<MatchStr<MapTo>, E>
+	where
+		F: FnMut(&T) -> Result<MapTo, E>,
+	{
+		self.try_map_ref_with(&mut f)
+	}
+
+	/// Implementation of [`MatchStr::try_map_ref`] which can be called recursively.
+	fn try_map_ref_with<E, F, MapTo>(&self, f: &mut F) -> Result<MatchStr<MapTo>, E>
+	where
+		F: FnMut(&T) -> Result<MapTo, E>,
+	{
+		Ok(match self
+		{
+			Self::And(match_conditions) => MatchStr::And(
+				match_conditions
+					.iter()
+					.map(|m| m.try_map_ref_with(f))
+					.collect::<Result<_, _>>()?,
+			),
+			Self::Any => MatchStr::Any,
+			Self::Contains(x) => MatchStr::Contains(f(x)?),
+			Self::EqualTo(x) => MatchStr::EqualTo(f(x)?),
+			Self::Not(match_condition) => MatchStr::Not(match_condition.try_map_ref_with(f)?.into()),
+			Self::Or(match_conditions) => MatchStr::Or(
+				match_conditions
+					.iter()
+					.map(|m| m.try_map_ref_with(f))
+					.collect::<Result<_, _>>()?,
+			),
+			Self::Regex(x) => MatchStr::Regex(f(x)?),
+		})
+	}
+
+	/// Implementation of [`MatchStr::try_map`] which can be called recursively.
+	fn try_map_with<E, F, MapTo>(self, f: &mut F) -> Result<MatchStr<MapTo>, E>
 	where
-		F: Copy + Fn(&T) -> MapTo,
+		F: FnMut(T) -> Result<MapTo, E>,
 	{
-		match self
+		Ok(match self
 		{
-			Self::And(match_conditions) =>
-			{
-				MatchStr::And(match_conditions.iter().map(|m| m.map_ref(f)).collect())
-			},
+			Self::And(match_conditions) => MatchStr::And(
+				match_conditions
+					.into_iter()
+					.map(|m| m.try_map_with(f))
+					.collect::<Result<_, _>>()?,
+			),
 			Self::Any => MatchStr::Any,
-			Self::Contains(x) => MatchStr::Contains(f(x)),
-			Self::EqualTo(x) => MatchStr::EqualTo(f(x)),
-			Self::Not(match_condition) => MatchStr::Not(match_condition.map_ref(f).into()),
-			Self::Or(match_conditions) =>
-			{
-				MatchStr::Or(match_conditions.iter().map(|m| m.map_ref(f)).collect())
-			},
-			Self::Regex(x) => MatchStr::Regex(f(x)),
-		}
+			Self::Contains(x) => MatchStr::Contains(f(x)?),
+			Self::EqualTo(x) => MatchStr::EqualTo(f(x)?),
+			Self::Not(match_condition) => MatchStr::Not((*match_condition).try_map_with(f)?.into()),
+			Self::Or(match_conditions) => MatchStr::Or(
+				match_conditions
+					.into_iter()
+					.map(|m| m.try_map_with(f))
+					.collect::<Result<_, _>>()?,
+			),
+			Self::Regex(x) => MatchStr::Regex(f(x)?),
+		})
 	}
 }
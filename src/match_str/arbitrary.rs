@@ -0,0 +1,32 @@
+use proptest::{
+	arbitrary::Arbitrary,
+	prelude::{BoxedStrategy, Just, Strategy},
+	prop_oneof,
+};
+
+use super::MatchStr;
+use crate::{strategy::Value, Recursion};
+
+impl Arbitrary for MatchStr<String>
+{
+	type Parameters = Recursion;
+	type Strategy = BoxedStrategy<Self>;
+
+	/// [`MatchStr::Regex`]es are made of literals, `[ab]+`, and anchors, so that they are valid in
+	/// every [`Dialect`](crate::Dialect).
+	fn arbitrary_with(recursion: Self::Parameters) -> Self::Strategy
+	{
+		recursion.nest(
+			prop_oneof![
+				Just(Self::Any),
+				String::strategy().prop_map(Self::Contains),
+				String::strategy().prop_map(Self::EqualTo),
+				r"\^?[a-c]{0,2}(\[ab\]\+)?[a-c]{0,2}\$?".prop_map(Self::Regex),
+			]
+			.boxed(),
+			Self::And,
+			Self::Not,
+			Self::Or,
+		)
+	}
+}
@@ -0,0 +1,105 @@
+use regex::Regex;
+
+use super::MatchStr;
+use crate::{Compile, IsMatch, Result};
+
+/// A [`MatchStr`] which has been [compiled](Compile).
+///
+/// The differences from [`MatchStr`] are that:
+///
+/// * each [`Regex`](MatchStr::Regex) has been built ahead of time, and
+/// * an [`Or`](MatchStr::Or) which only contains [`EqualTo`](MatchStr::EqualTo) conditions is
+///   compiled into [`In`](Self::In).
+#[derive(Clone, Debug)]
+pub enum CompiledMatchStr
+{
+	/// Same as [`MatchStr::And`].
+	And(Vec<Self>),
+
+	/// Same as [`MatchStr::Any`].
+	Any,
+
+	/// Same as [`MatchStr::Contains`].
+	Contains(String),
+
+	/// Same as [`MatchStr::EqualTo`].
+	EqualTo(String),
+
+	/// Match IFF some string `s` is equal to any of the contained values, which are sorted and
+	/// deduplicated.
+	In(Box<[String]>),
+
+	/// Same as [`MatchStr::Not`].
+	Not(Box<Self>),
+
+	/// Same as [`MatchStr::Or`].
+	Or(Vec<Self>),
+
+	/// Same as [`MatchStr::Regex`].
+	Regex(Regex),
+}
+
+impl<T> Compile for MatchStr<T>
+where
+	T: AsRef<str>,
+{
+	type Compiled = CompiledMatchStr;
+
+	fn compile(&self) -> Result<Self::Compiled>
+	{
+		Ok(match self
+		{
+			Self::And(conditions) => CompiledMatchStr::And(
+				conditions
+					.iter()
+					.map(Self::compile)
+					.collect::<Result<_>>()?,
+			),
+			Self::Any => CompiledMatchStr::Any,
+			Self::Contains(x) => CompiledMatchStr::Contains(x.as_ref().into()),
+			Self::EqualTo(x) => CompiledMatchStr::EqualTo(x.as_ref().into()),
+			Self::Not(condition) => CompiledMatchStr::Not(condition.compile()?.into()),
+			Self::Or(conditions) => match conditions
+				.iter()
+				.map(|c| match c
+				{
+					Self::EqualTo(x) => Some(String::from(x.as_ref())),
+					_ => None,
+				})
+				.collect::<Option<Vec<_>>>()
+			{
+				Some(mut values) =>
+				{
+					values.sort_unstable();
+					values.dedup();
+					CompiledMatchStr::In(values.into())
+				},
+				None => CompiledMatchStr::Or(
+					conditions
+						.iter()
+						.map(Self::compile)
+						.collect::<Result<_>>()?,
+				),
+			},
+			Self::Regex(x) => CompiledMatchStr::Regex(Regex::new(x.as_ref())?),
+		})
+	}
+}
+
+impl IsMatch<str> for CompiledMatchStr
+{
+	fn is_match(&self, value: &str) -> bool
+	{
+		match self
+		{
+			Self::And(conditions) => conditions.iter().all(|c| c.is_match(value)),
+			Self::Any => true,
+			Self::Contains(x) => value.contains(x.as_str()),
+			Self::EqualTo(x) => value == x,
+			Self::In(values) => values.binary_search_by(|v| v.as_str().cmp(value)).is_ok(),
+			Self::Not(condition) => !condition.is_match(value),
+			Self::Or(conditions) => conditions.iter().any(|c| c.is_match(value)),
+			Self::Regex(regex) => regex.is_match(value),
+		}
+	}
+}
@@ -0,0 +1,104 @@
+use serde::{de, Deserialize, Deserializer};
+
+use super::MatchStr;
+use crate::shorthand::{Condition, Conditions, Unknown};
+
+/// The explicit form of a [`MatchStr`], which is also how it is serialized.
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Deserialize)]
+#[serde(rename = "MatchStr", rename_all = "snake_case")]
+pub(super) enum Explicit<T>
+{
+	And(Vec<MatchStr<T>>),
+	Any,
+	Contains(T),
+	EqualTo(T),
+	Not(Box<MatchStr<T>>),
+	Or(Vec<MatchStr<T>>),
+	Regex(T),
+}
+
+impl<T> Conditions for Explicit<T>
+{
+	const CONDITIONS: &'static [&'static str] =
+		&["and", "any", "contains", "equal_to", "not", "or", "regex"];
+}
+
+/// The forms that a [`MatchStr`] can be written in.
+///
+/// Only [human-readable](Deserializer::is_human_readable) formats can tell these apart, so the
+/// others always use the [`Explicit`] form.
+#[derive(Deserialize)]
+#[serde(
+	expecting = "a condition (e.g. `equal_to: 3`), a value, or a list of values",
+	untagged
+)]
+enum Shorthand<T>
+{
+	/// See [`Condition`].
+	Condition(Condition<Explicit<T>>),
+
+	/// Shorthand for [`MatchStr::Or`] of [`MatchStr::EqualTo`].
+	Many(Vec<T>),
+
+	/// Shorthand for [`MatchStr::EqualTo`].
+	One(T),
+
+	/// See [`Unknown`].
+	Unknown(Unknown<Explicit<T>>),
+}
+
+impl<T> From<Explicit<T>> for MatchStr<T>
+{
+	fn from(explicit: Explicit<T>) -> Self
+	{
+		match explicit
+		{
+			Explicit::And(conditions) => Self::And(conditions),
+			Explicit::Any => Self::Any,
+			Explicit::Contains(x) => Self::Contains(x),
+			Explicit::EqualTo(x) => Self::EqualTo(x),
+			Explicit::Not(condition) => Self::Not(condition),
+			Explicit::Or(conditions) => Self::Or(conditions),
+			Explicit::Regex(x) => Self::Regex(x),
+		}
+	}
+}
+
+impl<T> Shorthand<T>
+{
+	/// Turn this shorthand into a [`MatchStr`], or explain why it is not one.
+	fn into_match<E>(self) -> Result<MatchStr<T>, E>
+	where
+		E: de::Error,
+	{
+		match self
+		{
+			Self::Condition(condition) => condition.into_result().map(Into::into),
+			Self::Many(values) => Ok(MatchStr::Or(
+				values.into_iter().map(MatchStr::EqualTo).collect(),
+			)),
+			Self::One(value) => Ok(MatchStr::EqualTo(value)),
+			Self::Unknown(unknown) => Err(unknown.into_error()),
+		}
+	}
+}
+
+impl<'de, T> Deserialize<'de> for MatchStr<T>
+where
+	T: Deserialize<'de>,
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		if deserializer.is_human_readable()
+		{
+			Shorthand::deserialize(deserializer)?.into_match()
+		}
+		else
+		{
+			Explicit::deserialize(deserializer).map(Into::into)
+		}
+	}
+}
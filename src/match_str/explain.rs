@@ -0,0 +1,48 @@
+use core::fmt::Debug;
+
+use regex::Regex;
+
+use super::MatchStr;
+use crate::{Explain, Explanation, Result};
+
+impl<T> Explain<str> for MatchStr<T>
+where
+	T: AsRef<str> + Debug,
+{
+	fn explain(&self, value: &str) -> Result<Explanation>
+	{
+		Ok(match self
+		{
+			Self::And(conditions) => Explanation::all(
+				"and",
+				conditions
+					.iter()
+					.map(|c| c.explain(value))
+					.collect::<Result<_>>()?,
+			),
+			Self::Any => Explanation::constant("any", true),
+			Self::Contains(x) => Explanation::comparison(
+				format!("contains {x:?}"),
+				value.contains(x.as_ref()),
+				&value,
+			),
+			Self::EqualTo(x) =>
+			{
+				Explanation::comparison(format!("equal_to {x:?}"), value == x.as_ref(), &value)
+			},
+			Self::Not(condition) => Explanation::not(condition.explain(value)?),
+			Self::Or(conditions) => Explanation::any(
+				"or",
+				conditions
+					.iter()
+					.map(|c| c.explain(value))
+					.collect::<Result<_>>()?,
+			),
+			Self::Regex(x) => Explanation::comparison(
+				format!("regex {x:?}"),
+				Regex::new(x.as_ref())?.is_match(value),
+				&value,
+			),
+		})
+	}
+}
@@ -0,0 +1,10 @@
+use super::MatchStr;
+use crate::{FromExample, TextSearch};
+
+impl FromExample<String> for MatchStr<String>
+{
+	fn from_example(example: &String, search: TextSearch) -> Self
+	{
+		search.condition(example)
+	}
+}
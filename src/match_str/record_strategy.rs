@@ -0,0 +1,24 @@
+use proptest::prelude::BoxedStrategy;
+
+use super::MatchStr;
+use crate::{strategy, strategy::Value, RecordStrategy};
+
+impl<T> RecordStrategy for MatchStr<T>
+where
+	T: AsRef<str> + Clone,
+{
+	fn matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		strategy::matching::<_, str>(self, Self::records())
+	}
+
+	fn not_matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		Self::Not(self.clone().into()).matching()
+	}
+
+	fn records() -> BoxedStrategy<Self::Record>
+	{
+		String::strategy()
+	}
+}
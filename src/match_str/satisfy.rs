@@ -0,0 +1,136 @@
+use super::MatchStr;
+use crate::{satisfy, Compile, Dialect, FieldPath, IsMatch, Pattern, Satisfiability, Satisfy};
+
+/// A condition of a [`MatchStr`] which does not contain other conditions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Atom<'condition>
+{
+	/// See [`MatchStr::Contains`].
+	Contains(&'condition str),
+
+	/// See [`MatchStr::EqualTo`].
+	EqualTo(&'condition str),
+
+	/// See [`MatchStr::Regex`].
+	Regex(&'condition str),
+}
+
+impl<'condition> Atom<'condition>
+{
+	/// Get the [`Atom`] which `condition` is, if it is one.
+	fn new<T>(condition: &'condition MatchStr<T>) -> Option<Self>
+	where
+		T: AsRef<str>,
+	{
+		match condition
+		{
+			MatchStr::Contains(x) => Some(Self::Contains(x.as_ref())),
+			MatchStr::EqualTo(x) => Some(Self::EqualTo(x.as_ref())),
+			MatchStr::Regex(x) => Some(Self::Regex(x.as_ref())),
+			_ => None,
+		}
+	}
+}
+
+/// Add each distinct [`Atom`] of the `condition` to the `atoms`.
+fn atoms<'condition, T>(condition: &'condition MatchStr<T>, atoms: &mut Vec<Atom<'condition>>)
+where
+	T: AsRef<str>,
+{
+	match condition
+	{
+		MatchStr::And(conditions) | MatchStr::Or(conditions) =>
+		{
+			conditions.iter().for_each(|c| self::atoms(c, atoms));
+		},
+		MatchStr::Any => (),
+		MatchStr::Not(condition) => self::atoms(condition, atoms),
+		condition => atoms.extend(Atom::new(condition).filter(|atom| !atoms.contains(atom))),
+	}
+}
+
+/// Evaluate the `condition` for a string which is not equal to any [`MatchStr::EqualTo`], and for
+/// which each of the `free` atoms is true if the corresponding bit of the `assignment` is set.
+fn evaluate<T>(condition: &MatchStr<T>, free: &[Atom<'_>], assignment: u32) -> bool
+where
+	T: AsRef<str>,
+{
+	match condition
+	{
+		MatchStr::And(conditions) => conditions.iter().all(|c| evaluate(c, free, assignment)),
+		MatchStr::Any => true,
+		MatchStr::EqualTo(_) => false,
+		MatchStr::Not(condition) => !evaluate(condition, free, assignment),
+		MatchStr::Or(conditions) => conditions.iter().any(|c| evaluate(c, free, assignment)),
+		condition => Atom::new(condition)
+			.and_then(|atom| free.iter().position(|f| *f == atom))
+			.is_some_and(|i| assignment & (1 << i) != 0),
+	}
+}
+
+impl<T> Satisfy for MatchStr<T>
+where
+	T: AsRef<str>,
+{
+	type Record = String;
+
+	/// Every string is either equal to one of the [`MatchStr::EqualTo`] values, or none of them.
+	/// Each of the former is tried, along with strings built from the [`MatchStr::Contains`] and
+	/// [`MatchStr::Regex`] values. If none match, the condition is unsatisfiable when no combination
+	/// of the latter conditions being true or false could make it match.
+	fn satisfy_at(&self, path: &FieldPath) -> Satisfiability<Self::Record>
+	{
+		let Ok(compiled) = self.compile()
+		else
+		{
+			return Satisfiability::Unknown;
+		};
+
+		let mut atoms = Vec::new();
+		self::atoms(self, &mut atoms);
+
+		let mut candidates = vec![String::new()];
+		let mut contains = Vec::new();
+		atoms.iter().for_each(|atom| match atom
+		{
+			Atom::Contains(x) =>
+			{
+				candidates.push((*x).into());
+				contains.push(*x);
+			},
+			Atom::EqualTo(x) => candidates.push((*x).into()),
+			Atom::Regex(x) => candidates.extend(
+				Pattern::parse(x, Dialect::Rust)
+					.ok()
+					.and_then(|pattern| pattern.example()),
+			),
+		});
+
+		candidates.push(contains.concat());
+		candidates.push(contains.join(" "));
+
+		let longest = atoms.iter().map(|atom| match atom
+		{
+			Atom::Contains(x) | Atom::EqualTo(x) | Atom::Regex(x) => x.len(),
+		});
+		candidates.push("x".repeat(longest.max().unwrap_or_default() + 1));
+
+		let free: Vec<_> = atoms
+			.into_iter()
+			.filter(|atom| !matches!(atom, Atom::EqualTo(_)))
+			.collect();
+
+		match Satisfiability::search(candidates, free.is_empty(), path, |c| compiled.is_match(c))
+		{
+			Satisfiability::Unknown if free.len() <= satisfy::MAX_CONDITIONS =>
+			{
+				match (0..1 << free.len()).any(|assignment| evaluate(self, &free, assignment))
+				{
+					true => Satisfiability::Unknown,
+					false => Satisfiability::Unsatisfiable(path.clone()),
+				}
+			},
+			satisfiability => satisfiability,
+		}
+	}
+}
@@ -0,0 +1,32 @@
+use super::MatchStr;
+use crate::{FieldPath, Fold, Folder, Visit, VisitMut, Visitor, VisitorMut};
+
+impl Visit for MatchStr<String>
+{
+	fn visit_at<V>(&self, path: &FieldPath, visitor: &mut V)
+	where
+		V: Visitor + ?Sized,
+	{
+		visitor.visit_match_str(path, self);
+	}
+}
+
+impl VisitMut for MatchStr<String>
+{
+	fn visit_mut_at<V>(&mut self, path: &FieldPath, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized,
+	{
+		visitor.visit_match_str_mut(path, self);
+	}
+}
+
+impl Fold for MatchStr<String>
+{
+	fn fold_at<F>(self, path: &FieldPath, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized,
+	{
+		folder.fold_match_str(path, self)
+	}
+}
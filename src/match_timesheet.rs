@@ -1,7 +1,25 @@
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod compiled_match_timesheet;
+mod duration;
 mod exchange;
+mod explain;
 mod from;
+mod from_example;
+mod indexable;
+mod orderable;
+#[cfg(feature = "proptest")]
+mod record_strategy;
+mod satisfy;
+mod visit;
+
+use core::time::Duration;
 
 use clinvoice_schema::{chrono::NaiveDateTime, Id};
+pub use compiled_match_timesheet::CompiledMatchTimesheet;
+use humantime_serde::Serde;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -12,6 +30,13 @@ use crate::MatchOption;
 ///
 /// [`MatchTimesheet`] matches IFF all of its fields also match.
 ///
+/// # Notes
+///
+/// * The `duration` is not a field of a [`Timesheet`](clinvoice_schema::Timesheet), but is
+///   computed from its `time_begin` and `time_end` instead. See
+///   [`MatchTimesheet::duration_sql`] for how to evaluate it in a database.
+/// * See [`humantime_serde`] for the syntax of matched data in the `duration` field.
+///
 /// # Examples
 ///
 /// ## YAML
@@ -42,7 +67,17 @@ use crate::MatchOption;
 /// work_notes: any
 /// # "#).is_ok());
 /// ```
+///
+/// Timesheets which are longer than eight hours:
+///
+/// ```rust
+/// # assert!(serde_yaml::from_str::<clinvoice_match::MatchTimesheet>(r#"
+/// duration:
+///   greater_than: "8h"
+/// # "#).is_ok());
+/// ```
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct MatchTimesheet
 {
@@ -70,6 +105,15 @@ pub struct MatchTimesheet
 	#[cfg_attr(feature = "serde", serde(default))]
 	pub time_end: MatchOption<NaiveDateTime>,
 
+	/// The time between the `time_begin` and `time_end`, which is [`None`] when the `time_end`
+	/// is, and zero when the `time_end` is before the `time_begin`.
+	#[cfg_attr(
+		feature = "schemars",
+		schemars(with = "MatchOption<crate::json_schema::Duration>")
+	)]
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub duration: MatchOption<Serde<Duration>>,
+
 	#[allow(missing_docs)]
 	#[cfg_attr(feature = "serde", serde(default))]
 	pub work_notes: MatchStr<String>,
@@ -0,0 +1,53 @@
+use core::time::Duration;
+
+use clinvoice_schema::{chrono::NaiveDateTime, Id};
+use humantime_serde::Serde;
+use proptest::{
+	arbitrary::{any_with, Arbitrary},
+	prelude::{BoxedStrategy, Strategy},
+};
+
+use super::MatchTimesheet;
+use crate::{
+	Match,
+	MatchEmployee,
+	MatchExpense,
+	MatchJob,
+	MatchOption,
+	MatchSet,
+	MatchStr,
+	Recursion,
+};
+
+impl Arbitrary for MatchTimesheet
+{
+	type Parameters = Recursion;
+	type Strategy = BoxedStrategy<Self>;
+
+	fn arbitrary_with(recursion: Self::Parameters) -> Self::Strategy
+	{
+		(
+			any_with::<MatchOption<Serde<Duration>>>(recursion),
+			any_with::<MatchEmployee>(recursion),
+			any_with::<MatchSet<MatchExpense>>(recursion),
+			any_with::<Match<Id>>(recursion),
+			any_with::<MatchJob>(recursion),
+			any_with::<Match<NaiveDateTime>>(recursion),
+			any_with::<MatchOption<NaiveDateTime>>(recursion),
+			any_with::<MatchStr<String>>(recursion),
+		)
+			.prop_map(
+				|(duration, employee, expenses, id, job, time_begin, time_end, work_notes)| Self {
+					duration,
+					employee,
+					expenses,
+					id,
+					job,
+					time_begin,
+					time_end,
+					work_notes,
+				},
+			)
+			.boxed()
+	}
+}
@@ -0,0 +1,72 @@
+use core::time::Duration;
+
+use clinvoice_schema::{chrono::NaiveDateTime, Id, Timesheet};
+
+use super::{duration::duration, MatchTimesheet};
+use crate::{
+	Compile,
+	CompiledMatch,
+	CompiledMatchEmployee,
+	CompiledMatchExpense,
+	CompiledMatchJob,
+	CompiledMatchOption,
+	CompiledMatchSet,
+	CompiledMatchStr,
+	IsMatch,
+	Result,
+};
+
+/// A [`MatchTimesheet`] which has been [compiled](Compile).
+#[derive(Clone, Debug)]
+pub struct CompiledMatchTimesheet
+{
+	id: CompiledMatch<Id>,
+
+	employee: CompiledMatchEmployee,
+
+	expenses: CompiledMatchSet<CompiledMatchExpense>,
+
+	job: CompiledMatchJob,
+
+	time_begin: CompiledMatch<NaiveDateTime>,
+
+	time_end: CompiledMatchOption<NaiveDateTime>,
+
+	duration: CompiledMatchOption<Duration>,
+
+	work_notes: CompiledMatchStr,
+}
+
+impl Compile for MatchTimesheet
+{
+	type Compiled = CompiledMatchTimesheet;
+
+	fn compile(&self) -> Result<Self::Compiled>
+	{
+		Ok(CompiledMatchTimesheet {
+			id: self.id.compile()?,
+			employee: self.employee.compile()?,
+			expenses: self.expenses.compile()?,
+			job: self.job.compile()?,
+			time_begin: self.time_begin.compile()?,
+			time_end: self.time_end.compile()?,
+			duration: self.duration.map_ref(|d| **d).compile()?,
+			work_notes: self.work_notes.compile()?,
+		})
+	}
+}
+
+impl IsMatch<Timesheet> for CompiledMatchTimesheet
+{
+	fn is_match(&self, timesheet: &Timesheet) -> bool
+	{
+		self.id.is_match(&timesheet.id) &&
+			self.employee.is_match(&timesheet.employee) &&
+			self.expenses.is_match(timesheet.expenses.as_slice()) &&
+			self.job.is_match(&timesheet.job) &&
+			self.time_begin.is_match(&timesheet.time_begin) &&
+			self.time_end.is_match(&timesheet.time_end) &&
+			self.duration.is_match(&duration(timesheet)) &&
+			self.work_notes.is_match(timesheet.work_notes.as_str())
+	}
+}
@@ -0,0 +1,124 @@
+use core::time::Duration;
+
+use clinvoice_schema::{
+	chrono::{self, NaiveDateTime},
+	Timesheet,
+};
+use humantime_serde::Serde;
+
+use super::MatchTimesheet;
+use crate::MatchOption;
+
+/// The `duration` of the `timesheet`, which is [`None`] when it has not ended. A `time_end` which
+/// is before the `time_begin` has a duration of zero.
+pub(super) fn duration(timesheet: &Timesheet) -> Option<Duration>
+{
+	timesheet
+		.time_end
+		.map(|end| (end - timesheet.time_begin).to_std().unwrap_or_default())
+}
+
+/// The `time_begin` of a timesheet which ended at `time_end` and lasted for the `duration`, or
+/// [`None`] if it is out of range.
+pub(super) fn begin(time_end: NaiveDateTime, duration: Duration) -> Option<NaiveDateTime>
+{
+	chrono::Duration::from_std(duration)
+		.ok()
+		.and_then(|d| time_end.checked_sub_signed(d))
+}
+
+/// The `time_end` of a timesheet which began at `time_begin` and lasted for the `duration`, or
+/// [`None`] if it is out of range.
+pub(super) fn end(time_begin: NaiveDateTime, duration: Duration) -> Option<NaiveDateTime>
+{
+	chrono::Duration::from_std(duration)
+		.ok()
+		.and_then(|d| time_begin.checked_add_signed(d))
+}
+
+/// A Postgres `interval` literal which is equal to the `duration`, to the microsecond.
+fn interval(duration: Duration) -> String
+{
+	match duration.subsec_micros()
+	{
+		0 => format!("INTERVAL '{} seconds'", duration.as_secs()),
+		micros => format!("INTERVAL '{}.{micros:06} seconds'", duration.as_secs()),
+	}
+}
+
+/// Render the `condition` as a Postgres boolean expression over the `interval` expression.
+fn sql(condition: &MatchOption<Serde<Duration>>, interval: &str) -> String
+{
+	let join =
+		|conditions: &[MatchOption<Serde<Duration>>], operator: &str, empty: &str| match conditions
+			.is_empty()
+		{
+			true => empty.to_owned(),
+			false => format!(
+				"({})",
+				conditions
+					.iter()
+					.map(|c| sql(c, interval))
+					.collect::<Vec<_>>()
+					.join(operator)
+			),
+		};
+
+	match condition
+	{
+		MatchOption::And(conditions) => join(conditions, " AND ", "TRUE"),
+		MatchOption::Any => "TRUE".into(),
+		MatchOption::EqualTo(d) => format!("COALESCE({interval} = {}, FALSE)", self::interval(**d)),
+		MatchOption::GreaterThan(d) =>
+		{
+			format!("COALESCE({interval} > {}, FALSE)", self::interval(**d))
+		},
+		MatchOption::InRange(low, high) => format!(
+			"COALESCE({interval} >= {} AND {interval} < {}, FALSE)",
+			self::interval(**low),
+			self::interval(**high),
+		),
+		MatchOption::LessThan(d) => format!("COALESCE({interval} < {}, FALSE)", self::interval(**d)),
+		MatchOption::None => format!("{interval} IS NULL"),
+		MatchOption::Not(condition) => format!("NOT {}", sql(condition, interval)),
+		MatchOption::Or(conditions) => join(conditions, " OR ", "FALSE"),
+	}
+}
+
+impl MatchTimesheet
+{
+	/// Render the `duration` condition as a Postgres boolean expression, given the expressions for
+	/// the `time_begin` and `time_end` of a timesheet (e.g. the names of their columns).
+	///
+	/// As in memory, the interval is `NULL` when the `time_end` is, and zero when the `time_end` is
+	/// before the `time_begin`. The expression itself is never `NULL`, so that negating it or
+	/// combining it with other conditions has the same result as [`IsMatch`](crate::IsMatch).
+	/// Durations are rounded down to the microsecond, since that is the precision of an `interval`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use core::time::Duration;
+	///
+	/// use clinvoice_match::{MatchOption, MatchTimesheet};
+	/// # use pretty_assertions::assert_eq;
+	///
+	/// let condition = MatchTimesheet {
+	///   duration: MatchOption::Not(MatchOption::GreaterThan(Duration::from_secs(8 * 60 * 60).into()).into()),
+	///   ..Default::default()
+	/// };
+	///
+	/// assert_eq!(
+	///   condition.duration_sql("T.time_begin", "T.time_end"),
+	///   "NOT COALESCE((T.time_end - LEAST(T.time_begin, T.time_end)) > INTERVAL '28800 seconds', \
+	///    FALSE)",
+	/// );
+	/// ```
+	pub fn duration_sql(&self, time_begin: &str, time_end: &str) -> String
+	{
+		sql(
+			&self.duration,
+			&format!("({time_end} - LEAST({time_begin}, {time_end}))"),
+		)
+	}
+}
@@ -0,0 +1,39 @@
+use clinvoice_schema::Timesheet;
+
+use super::{duration::duration, MatchTimesheet};
+use crate::{Explain, Explanation, Result};
+
+impl Explain<Timesheet> for MatchTimesheet
+{
+	fn explain(&self, timesheet: &Timesheet) -> Result<Explanation>
+	{
+		Ok(Explanation::all("MatchTimesheet", vec![
+			Explanation::field("id", self.id.explain(&timesheet.id)?),
+			self
+				.employee
+				.explain(&timesheet.employee)?
+				.with_label("employee"),
+			Explanation::field(
+				"expenses",
+				self.expenses.explain(timesheet.expenses.as_slice())?,
+			),
+			self.job.explain(&timesheet.job)?.with_label("job"),
+			Explanation::field(
+				"time_begin",
+				self.time_begin.explain(&timesheet.time_begin)?,
+			),
+			Explanation::field("time_end", self.time_end.explain(&timesheet.time_end)?),
+			Explanation::field(
+				"duration",
+				self
+					.duration
+					.map_ref(|d| **d)
+					.explain(&duration(timesheet))?,
+			),
+			Explanation::field(
+				"work_notes",
+				self.work_notes.explain(timesheet.work_notes.as_str())?,
+			),
+		]))
+	}
+}
@@ -1,7 +1,7 @@
-use clinvoice_schema::Id;
+use clinvoice_schema::{Id, Timesheet};
 
 use super::MatchTimesheet;
-use crate::Match;
+use crate::{Match, MatchExpense, MatchOption, MatchSet};
 
 impl From<Id> for MatchTimesheet
 {
@@ -21,3 +21,34 @@ impl From<Match<Id>> for MatchTimesheet
 		}
 	}
 }
+
+impl From<&Timesheet> for MatchTimesheet
+{
+	/// The `expenses` must contain each of the [`Timesheet`]'s expenses, and no others. The
+	/// `duration` is not matched, since the `time_begin` and `time_end` already determine it.
+	fn from(timesheet: &Timesheet) -> Self
+	{
+		let others = MatchExpense {
+			id: Match::Not(Match::Or(timesheet.expenses.iter().map(|e| e.id.into()).collect()).into()),
+			..Default::default()
+		};
+
+		Self {
+			duration: MatchOption::Any,
+			employee: (&timesheet.employee).into(),
+			expenses: MatchSet::And(
+				timesheet
+					.expenses
+					.iter()
+					.map(|e| MatchSet::Contains(e.into()))
+					.chain([MatchSet::Not(MatchSet::Contains(others).into())])
+					.collect(),
+			),
+			id: timesheet.id.into(),
+			job: (&timesheet.job).into(),
+			time_begin: timesheet.time_begin.into(),
+			time_end: timesheet.time_end.into(),
+			work_notes: timesheet.work_notes.clone().into(),
+		}
+	}
+}
@@ -0,0 +1,31 @@
+use clinvoice_schema::Timesheet;
+
+use super::MatchTimesheet;
+use crate::{
+	FromExample,
+	Match,
+	MatchEmployee,
+	MatchJob,
+	MatchOption,
+	MatchSet,
+	MatchStr,
+	TextSearch,
+};
+
+impl FromExample<Timesheet> for MatchTimesheet
+{
+	/// The `duration` is not matched, since the `time_begin` and `time_end` already determine it.
+	fn from_example(example: &Timesheet, search: TextSearch) -> Self
+	{
+		Self {
+			duration: MatchOption::Any,
+			employee: MatchEmployee::from_example(&example.employee, search),
+			expenses: MatchSet::from_example(&example.expenses, search),
+			id: Match::from_example(&example.id, search),
+			job: MatchJob::from_example(&example.job, search),
+			time_begin: Match::from_example(&example.time_begin, search),
+			time_end: MatchOption::from_example(&example.time_end, search),
+			work_notes: MatchStr::from_example(&example.work_notes, search),
+		}
+	}
+}
@@ -0,0 +1,55 @@
+use clinvoice_schema::{chrono::NaiveDateTime, Id, Timesheet};
+
+use super::MatchTimesheet;
+use crate::{Indexable, Match, MatchEmployee, MatchJob, MatchStr};
+
+impl Indexable for MatchTimesheet
+{
+	type Record = Timesheet;
+
+	fn date_conditions(&self) -> Vec<&Match<NaiveDateTime>>
+	{
+		let mut conditions = vec![&self.time_begin];
+		conditions.extend(self.job.date_conditions());
+		conditions
+	}
+
+	fn id_conditions(&self) -> Vec<&Match<Id>>
+	{
+		let mut conditions = vec![&self.id];
+		conditions.extend(self.employee.id_conditions());
+		conditions.extend(self.job.id_conditions());
+		conditions
+	}
+
+	fn record_dates(record: &Self::Record) -> Vec<NaiveDateTime>
+	{
+		let mut values = vec![record.time_begin];
+		values.extend(MatchJob::record_dates(&record.job));
+		values
+	}
+
+	fn record_ids(record: &Self::Record) -> Vec<Id>
+	{
+		let mut values = vec![record.id];
+		values.extend(MatchEmployee::record_ids(&record.employee));
+		values.extend(MatchJob::record_ids(&record.job));
+		values
+	}
+
+	fn record_strs(record: &Self::Record) -> Vec<&str>
+	{
+		let mut values = vec![record.work_notes.as_str()];
+		values.extend(MatchEmployee::record_strs(&record.employee));
+		values.extend(MatchJob::record_strs(&record.job));
+		values
+	}
+
+	fn str_conditions(&self) -> Vec<&MatchStr<String>>
+	{
+		let mut conditions = vec![&self.work_notes];
+		conditions.extend(self.employee.str_conditions());
+		conditions.extend(self.job.str_conditions());
+		conditions
+	}
+}
@@ -0,0 +1,34 @@
+use clinvoice_schema::Timesheet;
+
+use super::{duration::duration, MatchTimesheet};
+use crate::{FieldValue, MatchEmployee, MatchJob, Orderable};
+
+impl Orderable for MatchTimesheet
+{
+	type Record = Timesheet;
+
+	fn field_value(record: Option<&Self::Record>, field: &[&str]) -> Option<FieldValue>
+	{
+		match field
+		{
+			["duration"] => Some(record.and_then(duration).into()),
+			["employee", rest @ ..] => MatchEmployee::field_value(record.map(|r| &r.employee), rest),
+			["id"] => Some(record.map(|r| r.id).into()),
+			["job", rest @ ..] => MatchJob::field_value(record.map(|r| &r.job), rest),
+			["time_begin"] => Some(record.map(|r| r.time_begin).into()),
+			["time_end"] => Some(record.and_then(|r| r.time_end).into()),
+			["work_notes"] => Some(record.map(|r| r.work_notes.as_str()).into()),
+			_ => None,
+		}
+	}
+
+	fn is_summable(field: &[&str]) -> bool
+	{
+		match field
+		{
+			["duration"] => true,
+			["job", rest @ ..] => MatchJob::is_summable(rest),
+			_ => false,
+		}
+	}
+}
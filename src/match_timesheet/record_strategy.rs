@@ -0,0 +1,144 @@
+use clinvoice_schema::{chrono::NaiveDateTime, Id, Timesheet};
+use proptest::{
+	arbitrary::any,
+	prelude::{BoxedStrategy, Strategy},
+};
+
+use super::{duration::end, MatchTimesheet};
+use crate::{
+	strategy,
+	Match,
+	MatchEmployee,
+	MatchExpense,
+	MatchJob,
+	MatchOption,
+	MatchSet,
+	MatchStr,
+	RecordStrategy,
+};
+
+impl RecordStrategy for MatchTimesheet
+{
+	/// Since the `duration` depends on the `time_begin` and `time_end`, the `time_end` is sometimes
+	/// set to a `duration` which matches after the `time_begin`, and records which do not match as a
+	/// whole are replaced by an example which does.
+	fn matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		let records = (
+			self.duration.map_ref(|d| **d).matching()?,
+			self.employee.matching()?,
+			self.expenses.matching()?,
+			self.id.matching()?,
+			self.job.matching()?,
+			self.time_begin.matching()?,
+			self.time_end.matching()?,
+			self.work_notes.matching()?,
+			any::<bool>(),
+		)
+			.prop_map(
+				|(
+					duration,
+					employee,
+					expenses,
+					id,
+					job,
+					time_begin,
+					time_end,
+					work_notes,
+					from_duration,
+				)| Timesheet {
+					employee,
+					expenses,
+					id,
+					job,
+					time_begin,
+					time_end: match from_duration
+					{
+						true => duration.and_then(|d| end(time_begin, d)),
+						false => time_end,
+					},
+					work_notes,
+				},
+			)
+			.boxed();
+
+		strategy::matching::<_, Timesheet>(self, records)
+	}
+
+	fn not_matching(&self) -> Option<BoxedStrategy<Self::Record>>
+	{
+		strategy::union([
+			strategy::with(
+				Self::records(),
+				self.duration.map_ref(|d| **d).not_matching(),
+				|t, duration| {
+					t.time_end = duration.and_then(|d| end(t.time_begin, d));
+				},
+			),
+			strategy::with(
+				Self::records(),
+				self.employee.not_matching(),
+				|t, employee| {
+					t.employee = employee;
+				},
+			),
+			strategy::with(
+				Self::records(),
+				self.expenses.not_matching(),
+				|t, expenses| {
+					t.expenses = expenses;
+				},
+			),
+			strategy::with(Self::records(), self.id.not_matching(), |t, id| t.id = id),
+			strategy::with(Self::records(), self.job.not_matching(), |t, job| {
+				t.job = job
+			}),
+			strategy::with(
+				Self::records(),
+				self.time_begin.not_matching(),
+				|t, time_begin| {
+					t.time_begin = time_begin;
+				},
+			),
+			strategy::with(
+				Self::records(),
+				self.time_end.not_matching(),
+				|t, time_end| {
+					t.time_end = time_end;
+				},
+			),
+			strategy::with(
+				Self::records(),
+				self.work_notes.not_matching(),
+				|t, work_notes| {
+					t.work_notes = work_notes;
+				},
+			),
+		])
+	}
+
+	fn records() -> BoxedStrategy<Self::Record>
+	{
+		(
+			MatchEmployee::records(),
+			MatchSet::<MatchExpense>::records(),
+			Match::<Id>::records(),
+			MatchJob::records(),
+			Match::<NaiveDateTime>::records(),
+			MatchOption::<NaiveDateTime>::records(),
+			MatchStr::<String>::records(),
+		)
+			.prop_map(
+				|(employee, expenses, id, job, time_begin, time_end, work_notes)| Timesheet {
+					employee,
+					expenses,
+					id,
+					job,
+					time_begin,
+					time_end,
+					work_notes,
+				},
+			)
+			.boxed()
+	}
+}
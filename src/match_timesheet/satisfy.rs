@@ -0,0 +1,123 @@
+use core::time::Duration;
+
+use clinvoice_schema::{chrono::NaiveDateTime, Timesheet};
+
+use super::{
+	duration::{begin, duration, end},
+	MatchTimesheet,
+};
+use crate::{Compile, FieldPath, IsMatch, Satisfiability, Satisfy};
+
+/// Find a `time_begin` and `time_end` which match the `condition`, given an example of each which
+/// matches on its own, and an example of the `duration` which matches on its own.
+///
+/// Since the `duration` depends on the other two, each example is tried along with whatever the
+/// other examples imply about it (e.g. a `time_end` which is the `duration` after the
+/// `time_begin`).
+fn times(
+	condition: &MatchTimesheet,
+	time_begin: NaiveDateTime,
+	time_end: Option<NaiveDateTime>,
+	length: Option<Duration>,
+) -> Satisfiability<(NaiveDateTime, Option<NaiveDateTime>)>
+{
+	let (Ok(begins), Ok(ends), Ok(durations)) = (
+		condition.time_begin.compile(),
+		condition.time_end.compile(),
+		condition.duration.map_ref(|d| **d).compile(),
+	)
+	else
+	{
+		return Satisfiability::Unknown;
+	};
+
+	let mut candidates = vec![(time_begin, time_end)];
+	if let Some(length) = length
+	{
+		candidates.push((time_begin, end(time_begin, length)));
+		if let Some(begin) = time_end.and_then(|e| begin(e, length))
+		{
+			candidates.push((begin, time_end));
+		}
+	}
+
+	Satisfiability::search(
+		candidates,
+		false,
+		&FieldPath::default(),
+		|(time_begin, time_end)| {
+			let timesheet = Timesheet {
+				time_begin: *time_begin,
+				time_end: *time_end,
+				..Default::default()
+			};
+
+			begins.is_match(time_begin) &&
+				ends.is_match(time_end) &&
+				durations.is_match(&duration(&timesheet))
+		},
+	)
+}
+
+impl Satisfy for MatchTimesheet
+{
+	type Record = Timesheet;
+
+	fn satisfy_at(&self, path: &FieldPath) -> Satisfiability<Self::Record>
+	{
+		let times = match (
+			self.time_begin.satisfy_at(&path.join("time_begin")),
+			self.time_end.satisfy_at(&path.join("time_end")),
+			self
+				.duration
+				.map_ref(|d| **d)
+				.satisfy_at(&path.join("duration")),
+		)
+		{
+			(
+				Satisfiability::Satisfiable(time_begin),
+				Satisfiability::Satisfiable(time_end),
+				Satisfiability::Satisfiable(duration),
+			) => times(self, time_begin, time_end, duration),
+			(time_begin, time_end, duration) =>
+			{
+				Satisfiability::unsatisfied([time_begin.proof(), time_end.proof(), duration.proof()])
+			},
+		};
+
+		match (
+			self.id.satisfy_at(&path.join("id")),
+			self.employee.satisfy_at(&path.join("employee")),
+			self.expenses.satisfy_at(&path.join("expenses")),
+			self.job.satisfy_at(&path.join("job")),
+			times,
+			self.work_notes.satisfy_at(&path.join("work_notes")),
+		)
+		{
+			(
+				Satisfiability::Satisfiable(id),
+				Satisfiability::Satisfiable(employee),
+				Satisfiability::Satisfiable(expenses),
+				Satisfiability::Satisfiable(job),
+				Satisfiability::Satisfiable((time_begin, time_end)),
+				Satisfiability::Satisfiable(work_notes),
+			) => Satisfiability::Satisfiable(Timesheet {
+				employee,
+				expenses,
+				id,
+				job,
+				time_begin,
+				time_end,
+				work_notes,
+			}),
+			(id, employee, expenses, job, times, work_notes) => Satisfiability::unsatisfied([
+				id.proof(),
+				employee.proof(),
+				expenses.proof(),
+				job.proof(),
+				times.proof(),
+				work_notes.proof(),
+			]),
+		}
+	}
+}
@@ -0,0 +1,59 @@
+use super::MatchTimesheet;
+use crate::{FieldPath, Fold, Folder, Visit, VisitMut, Visitor, VisitorMut};
+
+impl Visit for MatchTimesheet
+{
+	fn visit_at<V>(&self, path: &FieldPath, visitor: &mut V)
+	where
+		V: Visitor + ?Sized,
+	{
+		self.id.visit_at(&path.join("id"), visitor);
+		self.employee.visit_at(&path.join("employee"), visitor);
+		self.expenses.visit_at(&path.join("expenses"), visitor);
+		self.job.visit_at(&path.join("job"), visitor);
+		self.time_begin.visit_at(&path.join("time_begin"), visitor);
+		self.time_end.visit_at(&path.join("time_end"), visitor);
+		self.duration.visit_at(&path.join("duration"), visitor);
+		self.work_notes.visit_at(&path.join("work_notes"), visitor);
+	}
+}
+
+impl VisitMut for MatchTimesheet
+{
+	fn visit_mut_at<V>(&mut self, path: &FieldPath, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized,
+	{
+		self.id.visit_mut_at(&path.join("id"), visitor);
+		self.employee.visit_mut_at(&path.join("employee"), visitor);
+		self.expenses.visit_mut_at(&path.join("expenses"), visitor);
+		self.job.visit_mut_at(&path.join("job"), visitor);
+		self
+			.time_begin
+			.visit_mut_at(&path.join("time_begin"), visitor);
+		self.time_end.visit_mut_at(&path.join("time_end"), visitor);
+		self.duration.visit_mut_at(&path.join("duration"), visitor);
+		self
+			.work_notes
+			.visit_mut_at(&path.join("work_notes"), visitor);
+	}
+}
+
+impl Fold for MatchTimesheet
+{
+	fn fold_at<F>(self, path: &FieldPath, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized,
+	{
+		Self {
+			id: self.id.fold_at(&path.join("id"), folder),
+			employee: self.employee.fold_at(&path.join("employee"), folder),
+			expenses: self.expenses.fold_at(&path.join("expenses"), folder),
+			job: self.job.fold_at(&path.join("job"), folder),
+			time_begin: self.time_begin.fold_at(&path.join("time_begin"), folder),
+			time_end: self.time_end.fold_at(&path.join("time_end"), folder),
+			duration: self.duration.fold_at(&path.join("duration"), folder),
+			work_notes: self.work_notes.fold_at(&path.join("work_notes"), folder),
+		}
+	}
+}
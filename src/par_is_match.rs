@@ -0,0 +1,66 @@
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
+
+use crate::IsMatch;
+
+/// An extension trait for [`IsMatch`] which evaluates a [compiled](crate::Compile) matcher against
+/// many records in parallel using [`rayon`].
+///
+/// # Examples
+///
+/// ```rust
+/// use clinvoice_match::{Compile, Match, MatchExpense, ParIsMatch};
+/// use clinvoice_schema::{Currency, Expense, Money};
+/// # use pretty_assertions::assert_eq;
+///
+/// let expenses: Vec<_> = (0..5)
+///   .map(|id| Expense {
+///     category: "Travel".into(),
+///     cost: Money::new(id * 10_00, 2, Currency::Usd),
+///     description: "Flight".into(),
+///     id,
+///     timesheet_id: 0,
+///   })
+///   .collect();
+///
+/// let compiled = MatchExpense {
+///   cost: Match::GreaterThan(Money::new(20_00, 2, Currency::Usd)),
+///   ..Default::default()
+/// }
+/// .compile()
+/// .unwrap();
+///
+/// assert_eq!(compiled.par_match_bitmap(&expenses), [false, false, false, true, true]);
+/// assert_eq!(compiled.par_match_indices(&expenses), [3, 4]);
+/// ```
+pub trait ParIsMatch<T>: IsMatch<T> + Sync
+where
+	T: Sync,
+{
+	/// Evaluate `self` against each of the `records` in parallel, returning whether or not the
+	/// record at each index matched.
+	fn par_match_bitmap(&self, records: &[T]) -> Vec<bool>
+	{
+		records
+			.par_iter()
+			.map(|record| self.is_match(record))
+			.collect()
+	}
+
+	/// Evaluate `self` against each of the `records` in parallel, returning the index of each record
+	/// which matched (in ascending order).
+	fn par_match_indices(&self, records: &[T]) -> Vec<usize>
+	{
+		records
+			.par_iter()
+			.enumerate()
+			.filter_map(|(index, record)| self.is_match(record).then_some(index))
+			.collect()
+	}
+}
+
+impl<C, T> ParIsMatch<T> for C
+where
+	C: IsMatch<T> + Sync,
+	T: Sync,
+{
+}
@@ -0,0 +1,169 @@
+mod construct;
+mod dialect;
+mod node;
+mod parser;
+mod writer;
+
+pub use construct::Construct;
+pub use dialect::Dialect;
+use node::{ClassItem, Node};
+use parser::Parser;
+use writer::Writer;
+
+use crate::{Error, Result};
+
+/// A regular expression (e.g. from a [`MatchStr::Regex`](crate::MatchStr::Regex)) which has been
+/// parsed from one [`Dialect`], so that it can be checked for [`Construct`]s which another dialect
+/// does not support, and translated into that dialect.
+///
+/// [`MatchStr::Regex`](crate::MatchStr::Regex) conditions are written in [`Dialect::Rust`], which is
+/// how they are evaluated in memory. Adapters which evaluate them elsewhere (e.g. in Postgres) should
+/// translate them first, so that they behave the same. Translation accounts for differences such as:
+///
+/// * `\b` being a word boundary in Rust, but a backspace in Postgres (where `\y` is used instead).
+/// * `.` matching a line feed in Postgres, but not in Rust.
+///
+/// Only `(?i)` at the start of a pattern, and `(?s:…)` in Rust, are supported as flags. Classes
+/// which are nested within brackets or combined with set operations (e.g. `[a-z&&[^aeiou]]`) are
+/// not supported either. The [`Construct`]s which are not supported by the other dialect must be
+/// avoided. Note that shorthand classes such as `\w` match any Unicode word character in Rust,
+/// whereas Postgres decides based on the locale of the database.
+///
+/// # Examples
+///
+/// ```rust
+/// use clinvoice_match::{Construct, Dialect, Error, Pattern};
+/// # use pretty_assertions::assert_eq;
+///
+/// assert_eq!(
+///   Pattern::translate(r"(?i)\bfix(ed)?\b.*", Dialect::Rust, Dialect::Postgres).unwrap(),
+///   r"(?i)\yfix(ed)?\y[^\n]*",
+/// );
+///
+/// let pattern = Pattern::parse(r"^(\w+) \1(?=!)", Dialect::Postgres).unwrap();
+/// assert_eq!(
+///   pattern.unsupported(Dialect::Rust),
+///   [Construct::Backreference, Construct::Lookaround],
+/// );
+/// assert_eq!(
+///   pattern.to_dialect(Dialect::Rust).unwrap_err().to_string(),
+///   "backreferences are not supported by Rust regular expressions",
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pattern
+{
+	/// Whether letters match regardless of their case.
+	case_insensitive: bool,
+
+	/// The parsed regular expression.
+	node: Node,
+}
+
+impl Pattern
+{
+	/// Check that this pattern can be written in some `dialect`.
+	///
+	/// # Errors
+	///
+	/// * When this pattern contains a [`Construct`] which the `dialect` does not support.
+	pub fn check(&self, dialect: Dialect) -> Result<()>
+	{
+		self
+			.unsupported(dialect)
+			.first()
+			.map_or(Ok(()), |construct| {
+				Err(Error::RegexUnsupported {
+					construct: *construct,
+					dialect,
+				})
+			})
+	}
+
+	/// Create a string which this pattern matches, if it is simple enough to do so.
+	///
+	/// The string is not guaranteed to match, so it should be checked before it is used.
+	pub(crate) fn example(&self) -> Option<String>
+	{
+		let mut example = String::new();
+		self.node.example(&mut example).then_some(example)
+	}
+
+	/// Parse some `pattern` which is written in the `dialect`.
+	///
+	/// # Errors
+	///
+	/// * When the `pattern` is not a valid regular expression of the `dialect`, or uses syntax which
+	///   is not supported by this crate (e.g. flags other than a leading `(?i)`).
+	pub fn parse(pattern: &str, dialect: Dialect) -> Result<Self>
+	{
+		Parser::new(pattern, dialect)
+			.parse()
+			.map(|(case_insensitive, node)| Self {
+				case_insensitive,
+				node,
+			})
+	}
+
+	/// Write this pattern in some `dialect`.
+	///
+	/// # Errors
+	///
+	/// * See [`Pattern::check`].
+	pub fn to_dialect(&self, dialect: Dialect) -> Result<String>
+	{
+		self.check(dialect)?;
+		Ok(Writer {
+			dialect,
+			pattern: self,
+		}
+		.to_string())
+	}
+
+	/// [Parse](Pattern::parse) some `pattern` which is written in one dialect, and then write it
+	/// [in another](Pattern::to_dialect).
+	///
+	/// # Errors
+	///
+	/// * See [`Pattern::parse`] and [`Pattern::to_dialect`].
+	pub fn translate(pattern: &str, from: Dialect, to: Dialect) -> Result<String>
+	{
+		Self::parse(pattern, from).and_then(|p| p.to_dialect(to))
+	}
+
+	/// Get the [`Construct`]s in this pattern which are not supported by the `dialect`, in order.
+	pub fn unsupported(&self, dialect: Dialect) -> Vec<Construct>
+	{
+		let mut constructs = Vec::new();
+		self.node.walk(&mut |node| match (dialect, node)
+		{
+			(Dialect::Postgres, Node::Class(class)) =>
+			{
+				if class
+					.items
+					.iter()
+					.any(|item| matches!(item, ClassItem::Unicode { .. }))
+				{
+					constructs.push(Construct::UnicodeClass);
+				}
+
+				let bracketed = class.negated || class.items.len() > 1;
+				if bracketed &&
+					class
+						.items
+						.iter()
+						.any(|item| matches!(item, ClassItem::Shorthand(s) if s.is_negated()))
+				{
+					constructs.push(Construct::NegatedShorthandInBrackets);
+				}
+			},
+			(Dialect::Rust, Node::Backreference(_)) => constructs.push(Construct::Backreference),
+			(Dialect::Rust, Node::Lookaround { .. }) => constructs.push(Construct::Lookaround),
+			_ => (),
+		});
+
+		constructs.sort_unstable();
+		constructs.dedup();
+		constructs
+	}
+}
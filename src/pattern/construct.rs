@@ -0,0 +1,22 @@
+mod display;
+
+/// A feature of regular expressions which is only supported by some [`Dialect`](super::Dialect)s.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Construct
+{
+	/// A reference to the text matched by a capturing group (e.g. `\1`), which is not supported by
+	/// [`Dialect::Rust`](super::Dialect::Rust).
+	Backreference,
+
+	/// A look-ahead or look-behind assertion (e.g. `(?=a)`), which is not supported by
+	/// [`Dialect::Rust`](super::Dialect::Rust).
+	Lookaround,
+
+	/// A negated shorthand class within brackets (e.g. `[\D]`), which is not supported by
+	/// [`Dialect::Postgres`](super::Dialect::Postgres).
+	NegatedShorthandInBrackets,
+
+	/// A Unicode property class (e.g. `\p{Greek}`), which is not supported by
+	/// [`Dialect::Postgres`](super::Dialect::Postgres).
+	UnicodeClass,
+}
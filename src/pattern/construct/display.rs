@@ -0,0 +1,17 @@
+use core::fmt::{Display, Formatter, Result};
+
+use super::Construct;
+
+impl Display for Construct
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result
+	{
+		f.write_str(match self
+		{
+			Self::Backreference => "backreferences",
+			Self::Lookaround => "look-around assertions",
+			Self::NegatedShorthandInBrackets => "negated shorthand classes within brackets",
+			Self::UnicodeClass => "Unicode property classes",
+		})
+	}
+}
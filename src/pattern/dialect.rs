@@ -0,0 +1,92 @@
+mod display;
+
+use super::Pattern;
+use crate::{FieldPath, MatchStr, Result, Visit, Visitor};
+
+/// A syntax for regular expressions (e.g. in a [`MatchStr::Regex`]).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Dialect
+{
+	/// The [advanced regular expressions](https://www.postgresql.org/docs/current/functions-matching.html#POSIX-SYNTAX-DETAILS)
+	/// of Postgres, which are used by the `~` operator.
+	Postgres,
+
+	/// The syntax of the [`regex`] crate, which is used to evaluate matchers in memory.
+	Rust,
+}
+
+impl Dialect
+{
+	/// Check that every [`MatchStr::Regex`] in some `matcher`, which are written in
+	/// [`Dialect::Rust`], can be [translated](Pattern::to_dialect) to this dialect.
+	///
+	/// # Errors
+	///
+	/// * When a regular expression is not valid, or cannot be translated.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use clinvoice_match::{Dialect, MatchEmployee, MatchStr};
+	///
+	/// let mut condition = MatchEmployee {
+	///   name: MatchStr::Regex(r"^\w+ \w+$".into()),
+	///   ..Default::default()
+	/// };
+	/// assert!(Dialect::Postgres.check(&condition).is_ok());
+	///
+	/// condition.title = MatchStr::Not(MatchStr::Regex(r"\p{Greek}".into()).into());
+	/// assert!(Dialect::Postgres.check(&condition).is_err());
+	/// ```
+	pub fn check<M>(self, matcher: &M) -> Result<()>
+	where
+		M: Visit,
+	{
+		let mut checker = Checker {
+			dialect: self,
+			result: Ok(()),
+		};
+
+		matcher.visit(&mut checker);
+		checker.result
+	}
+}
+
+/// A [`Visitor`] which [checks](Dialect::check) every [`MatchStr::Regex`] that it visits.
+struct Checker
+{
+	/// The dialect which the regular expressions must be translated to.
+	dialect: Dialect,
+
+	/// The first error which was encountered, if any.
+	result: Result<()>,
+}
+
+impl Checker
+{
+	/// Check every [`MatchStr::Regex`] in the `condition`.
+	fn check(&self, condition: &MatchStr<String>) -> Result<()>
+	{
+		match condition
+		{
+			MatchStr::And(conditions) | MatchStr::Or(conditions) =>
+			{
+				conditions.iter().try_for_each(|c| self.check(c))
+			},
+			MatchStr::Any | MatchStr::Contains(_) | MatchStr::EqualTo(_) => Ok(()),
+			MatchStr::Not(condition) => self.check(condition),
+			MatchStr::Regex(pattern) => Pattern::parse(pattern, Dialect::Rust)?.check(self.dialect),
+		}
+	}
+}
+
+impl Visitor for Checker
+{
+	fn visit_match_str(&mut self, _path: &FieldPath, condition: &MatchStr<String>)
+	{
+		if self.result.is_ok()
+		{
+			self.result = self.check(condition);
+		}
+	}
+}
@@ -0,0 +1,15 @@
+use core::fmt::{Display, Formatter, Result};
+
+use super::Dialect;
+
+impl Display for Dialect
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result
+	{
+		f.write_str(match self
+		{
+			Self::Postgres => "Postgres",
+			Self::Rust => "Rust",
+		})
+	}
+}
@@ -0,0 +1,295 @@
+/// A zero-width assertion about the position in a string.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum Assertion
+{
+	/// The end of the string.
+	End,
+
+	/// A position which is not a word boundary.
+	NotWordBoundary,
+
+	/// The start of the string.
+	Start,
+
+	/// A position between a word character and a non-word character.
+	WordBoundary,
+
+	/// A position which is preceded by a word character, but not followed by one.
+	WordEnd,
+
+	/// A position which is followed by a word character, but not preceded by one.
+	WordStart,
+}
+
+/// A set of characters.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct Class
+{
+	/// The characters of the set.
+	pub(super) items: Vec<ClassItem>,
+
+	/// Whether the set matches every character _except_ the `items`.
+	pub(super) negated: bool,
+}
+
+impl Class
+{
+	/// Characters which are tried when looking for an [example](Self::example) of a class.
+	const EXAMPLES: [char; 9] = ['a', 'A', '0', ' ', '_', '-', '.', '!', '\n'];
+
+	/// Get a character which this class matches, if one can be found.
+	fn example(&self) -> Option<char>
+	{
+		let own = self.items.iter().filter_map(|item| match item
+		{
+			ClassItem::Char(c) | ClassItem::Range(c, _) => Some(*c),
+			_ => None,
+		});
+
+		own.chain(Self::EXAMPLES).find(|c| {
+			self
+				.items
+				.iter()
+				.map(|item| item.contains(*c))
+				.try_fold(false, |found, contains| {
+					contains.map(|contains| found || contains)
+				})
+				.is_some_and(|found| found != self.negated)
+		})
+	}
+}
+
+/// Some characters within a [`Class`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) enum ClassItem
+{
+	/// A single character.
+	Char(char),
+
+	/// A POSIX character class (e.g. `[:alpha:]`), by name.
+	Posix(String),
+
+	/// Every character from the first to the second, inclusive.
+	Range(char, char),
+
+	/// A shorthand class (e.g. `\d`).
+	Shorthand(Shorthand),
+
+	/// A Unicode property class (e.g. `\p{Greek}`), by name.
+	Unicode
+	{
+		/// The name of the property.
+		name: String,
+
+		/// Whether the class matches every character _without_ the property.
+		negated: bool,
+	},
+}
+
+impl ClassItem
+{
+	/// Whether this item contains the character `c`, if it can be determined.
+	fn contains(&self, c: char) -> Option<bool>
+	{
+		Some(match self
+		{
+			Self::Char(x) => c == *x,
+			Self::Posix(name) => match name.as_str()
+			{
+				"alnum" => c.is_ascii_alphanumeric(),
+				"alpha" => c.is_ascii_alphabetic(),
+				"blank" => c == ' ' || c == '\t',
+				"cntrl" => c.is_ascii_control(),
+				"digit" => c.is_ascii_digit(),
+				"graph" => c.is_ascii_graphic(),
+				"lower" => c.is_ascii_lowercase(),
+				"print" => c.is_ascii_graphic() || c == ' ',
+				"punct" => c.is_ascii_punctuation(),
+				"space" => c.is_ascii_whitespace() || c == '\x0B',
+				"upper" => c.is_ascii_uppercase(),
+				"xdigit" => c.is_ascii_hexdigit(),
+				_ => return None,
+			},
+			Self::Range(start, end) => (*start..=*end).contains(&c),
+			Self::Shorthand(shorthand) =>
+			{
+				let contains = match shorthand
+				{
+					Shorthand::Digit | Shorthand::NotDigit => c.is_numeric(),
+					Shorthand::NotSpace | Shorthand::Space => c.is_whitespace(),
+					Shorthand::NotWord | Shorthand::Word => c.is_alphanumeric() || c == '_',
+				};
+
+				contains != shorthand.is_negated()
+			},
+			Self::Unicode { .. } => return None,
+		})
+	}
+}
+
+/// A parsed regular expression.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) enum Node
+{
+	/// Match any of the nodes.
+	Alternation(Vec<Self>),
+
+	/// Match any character, including a line feed if `newline` is `true`.
+	AnyChar
+	{
+		/// Whether a line feed is matched.
+		newline: bool,
+	},
+
+	/// Match an [`Assertion`].
+	Assertion(Assertion),
+
+	/// Match the same text as the capturing group with some index did.
+	Backreference(u32),
+
+	/// Match one character from a [`Class`].
+	Class(Class),
+
+	/// Match each of the nodes in order.
+	Concat(Vec<Self>),
+
+	/// Match the `node`, optionally capturing the text it matched.
+	Group
+	{
+		/// Whether the text is captured.
+		capturing: bool,
+
+		/// The contents of the group.
+		node: Box<Self>,
+	},
+
+	/// Match a single character.
+	Literal(char),
+
+	/// Assert that the `node` does (or does not) match before (or after) the current position.
+	Lookaround
+	{
+		/// Whether the `node` follows the current position.
+		ahead: bool,
+
+		/// Whether the `node` must _not_ match.
+		negated: bool,
+
+		/// The condition.
+		node: Box<Self>,
+	},
+
+	/// Match the `node` between `min` and `max` times.
+	Repeat
+	{
+		/// Whether to match as many times as possible.
+		greedy: bool,
+
+		/// The maximum number of repetitions, if any.
+		max: Option<u32>,
+
+		/// The minimum number of repetitions.
+		min: u32,
+
+		/// The node which is repeated.
+		node: Box<Self>,
+	},
+}
+
+impl Node
+{
+	/// Add a string which this node matches to the `example`, returning whether one could be found.
+	pub(super) fn example(&self, example: &mut String) -> bool
+	{
+		match self
+		{
+			Self::Alternation(branches) => branches.iter().any(|branch| {
+				let len = example.len();
+				let found = branch.example(example);
+				if !found
+				{
+					example.truncate(len);
+				}
+
+				found
+			}),
+			Self::AnyChar { .. } =>
+			{
+				example.push('a');
+				true
+			},
+			Self::Assertion(_) => true,
+			Self::Backreference(_) | Self::Lookaround { .. } => false,
+			Self::Class(class) => class.example().map(|c| example.push(c)).is_some(),
+			Self::Concat(nodes) => nodes.iter().all(|node| node.example(example)),
+			Self::Group { node, .. } => node.example(example),
+			Self::Literal(c) =>
+			{
+				example.push(*c);
+				true
+			},
+			Self::Repeat { min, node, .. } => (0..*min).all(|_| node.example(example)),
+		}
+	}
+
+	/// Call `f` on this node and every node within it.
+	pub(super) fn walk(&self, f: &mut impl FnMut(&Self))
+	{
+		f(self);
+		match self
+		{
+			Self::Alternation(nodes) | Self::Concat(nodes) => nodes.iter().for_each(|n| n.walk(f)),
+			Self::Group { node, .. } | Self::Lookaround { node, .. } | Self::Repeat { node, .. } =>
+			{
+				node.walk(f);
+			},
+			_ => (),
+		}
+	}
+}
+
+/// A shorthand [`Class`], such as `\d`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum Shorthand
+{
+	/// `\d`
+	Digit,
+
+	/// `\D`
+	NotDigit,
+
+	/// `\S`
+	NotSpace,
+
+	/// `\W`
+	NotWord,
+
+	/// `\s`
+	Space,
+
+	/// `\w`
+	Word,
+}
+
+impl Shorthand
+{
+	/// Whether this class matches the characters that _aren't_ part of some other class.
+	pub(super) const fn is_negated(self) -> bool
+	{
+		matches!(self, Self::NotDigit | Self::NotSpace | Self::NotWord)
+	}
+
+	/// The letter which follows the `\` of this class.
+	pub(super) const fn letter(self) -> char
+	{
+		match self
+		{
+			Self::Digit => 'd',
+			Self::NotDigit => 'D',
+			Self::NotSpace => 'S',
+			Self::NotWord => 'W',
+			Self::Space => 's',
+			Self::Word => 'w',
+		}
+	}
+}
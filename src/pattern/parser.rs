@@ -0,0 +1,569 @@
+use super::{
+	node::{Assertion, Class, ClassItem, Node, Shorthand},
+	Construct,
+	Dialect,
+};
+use crate::{Error, Result};
+
+/// The greatest number of groups which may be nested within each other, so that parsing a pattern
+/// from an untrusted source cannot overflow the stack.
+const MAX_DEPTH: usize = 64;
+
+/// Reads a regular expression of some [`Dialect`] into a [`Node`].
+pub(super) struct Parser
+{
+	/// The characters of the regular expression.
+	chars: Vec<char>,
+
+	/// The number of groups which contain the next character.
+	depth: usize,
+
+	/// The syntax of the regular expression.
+	dialect: Dialect,
+
+	/// Whether `.` matches a line feed, as it does within `(?s:…)` in [`Dialect::Rust`].
+	dot_all: bool,
+
+	/// The index of the next character to read.
+	position: usize,
+}
+
+impl Parser
+{
+	/// Create a [`Parser`] for the `pattern`, which is written in the `dialect`.
+	pub(super) fn new(pattern: &str, dialect: Dialect) -> Self
+	{
+		Self {
+			chars: pattern.chars().collect(),
+			depth: 0,
+			dialect,
+			dot_all: dialect == Dialect::Postgres,
+			position: 0,
+		}
+	}
+
+	/// Read an alternation of one or more [concatenations](Self::concat).
+	fn alternation(&mut self) -> Result<Node>
+	{
+		let mut branches = vec![self.concat()?];
+		while self.eat("|")
+		{
+			branches.push(self.concat()?);
+		}
+
+		Ok(match branches.len()
+		{
+			1 => branches.remove(0),
+			_ => Node::Alternation(branches),
+		})
+	}
+
+	/// Read a single item, such as a character or a group.
+	fn atom(&mut self) -> Result<Node>
+	{
+		Ok(match self.next()?
+		{
+			'(' => self.group()?,
+			'[' => Node::Class(self.class()?),
+			'.' => Node::AnyChar {
+				newline: self.dot_all,
+			},
+			'^' => Node::Assertion(Assertion::Start),
+			'$' => Node::Assertion(Assertion::End),
+			'\\' => self.escape()?,
+			'*' | '+' | '?' => return Err(self.error("there is nothing to repeat")),
+			c => Node::Literal(c),
+		})
+	}
+
+	/// Read the bounds of a repetition (e.g. `{2,5}`), after the `{`.
+	fn bounds(&mut self) -> Result<(u32, Option<u32>)>
+	{
+		let min = self.number()?;
+		let max = match self.eat(",")
+		{
+			true if self.peek().is_some_and(|c| c.is_ascii_digit()) => Some(self.number()?),
+			true => None,
+			false => Some(min),
+		};
+
+		if !self.eat("}")
+		{
+			return Err(self.error("expected `}` to close the repetition"));
+		}
+
+		match max
+		{
+			Some(max) if max < min =>
+			{
+				Err(self.error("the repetition has a minimum greater than its maximum"))
+			},
+			_ => Ok((min, max)),
+		}
+	}
+
+	/// Read a bracketed class of characters (e.g. `[a-z]`), after the `[`.
+	fn class(&mut self) -> Result<Class>
+	{
+		let negated = self.eat("^");
+		let mut items = Vec::new();
+		if self.eat("]")
+		{
+			items.push(ClassItem::Char(']'));
+		}
+
+		loop
+		{
+			if self.dialect == Dialect::Rust && ["&&", "--", "~~"].iter().any(|op| self.eat(op))
+			{
+				return Err(self.error("set operations on classes (e.g. `&&`) are not supported"));
+			}
+
+			let item = match self
+				.next()
+				.map_err(|_| self.error("expected `]` to close the brackets"))?
+			{
+				']' => return Ok(Class { items, negated }),
+				'[' if self.eat(":") =>
+				{
+					let start = self.position;
+					while !self.eat(":]")
+					{
+						self.next()?;
+					}
+
+					ClassItem::Posix(self.chars[start..self.position - 2].iter().collect())
+				},
+				'[' if self.dialect == Dialect::Rust =>
+				{
+					return Err(self.error("classes nested within brackets are not supported"))
+				},
+				'[' if self.peek().is_some_and(|c| c == '.' || c == '=') =>
+				{
+					return Err(self.error(
+						"collating elements and equivalence classes (e.g. `[.a.]`) are not supported",
+					))
+				},
+				'\\' =>
+				{
+					let c = self.next()?;
+					self.escape_item(c)?.ok_or_else(|| self.unknown_escape(c))?
+				},
+				c => ClassItem::Char(c),
+			};
+
+			match item
+			{
+				ClassItem::Char(start)
+					if self.peek() == Some('-') &&
+						self.chars.get(self.position + 1) != Some(&']') &&
+						!(self.dialect == Dialect::Rust &&
+							self.chars.get(self.position + 1) == Some(&'-')) =>
+				{
+					self.position += 1;
+					let end = match self.next()?
+					{
+						'\\' => match self.next().and_then(|c| self.escape_item(c))?
+						{
+							Some(ClassItem::Char(c)) => c,
+							_ => return Err(self.error("expected a character to end the range")),
+						},
+						c => c,
+					};
+
+					if end < start
+					{
+						return Err(self.error("the range ends before it starts"));
+					}
+
+					items.push(ClassItem::Range(start, end));
+				},
+				item => items.push(item),
+			}
+		}
+	}
+
+	/// Read zero or more [atoms](Self::atom), each of which may be [repeated](Self::repeat).
+	fn concat(&mut self) -> Result<Node>
+	{
+		let mut nodes = Vec::new();
+		while self.peek().is_some_and(|c| c != '|' && c != ')')
+		{
+			let atom = self.atom()?;
+			nodes.push(self.repeat(atom)?);
+		}
+
+		Ok(match nodes.len()
+		{
+			1 => nodes.remove(0),
+			_ => Node::Concat(nodes),
+		})
+	}
+
+	/// Advance past `s` if it is next in the pattern, and return whether it was.
+	fn eat(&mut self, s: &str) -> bool
+	{
+		let mut position = self.position;
+		let matches = s.chars().all(|c| {
+			let matches = self.chars.get(position) == Some(&c);
+			position += 1;
+			matches
+		});
+
+		if matches
+		{
+			self.position = position;
+		}
+
+		matches
+	}
+
+	/// Create an [`Error`] with some `reason` at the current position.
+	fn error(&self, reason: impl Into<String>) -> Error
+	{
+		Error::RegexSyntax {
+			dialect: self.dialect,
+			position: self.position,
+			reason: reason.into(),
+		}
+	}
+
+	/// Read an escape sequence outside of brackets, after the `\`.
+	fn escape(&mut self) -> Result<Node>
+	{
+		let c = match (self.dialect, self.next()?)
+		{
+			(Dialect::Rust, 'b') if self.eat("{start}") => '<',
+			(Dialect::Rust, 'b') if self.eat("{end}") => '>',
+			(_, c) => c,
+		};
+
+		let assertion = match (self.dialect, c)
+		{
+			(_, 'A') => Some(Assertion::Start),
+			(Dialect::Postgres, 'M') | (Dialect::Rust, '>') => Some(Assertion::WordEnd),
+			(Dialect::Postgres, 'm') | (Dialect::Rust, '<') => Some(Assertion::WordStart),
+			(Dialect::Postgres, 'Y') | (Dialect::Rust, 'B') => Some(Assertion::NotWordBoundary),
+			(Dialect::Postgres, 'y') | (Dialect::Rust, 'b') => Some(Assertion::WordBoundary),
+			(Dialect::Postgres, 'Z') | (Dialect::Rust, 'z') => Some(Assertion::End),
+			_ => None,
+		};
+
+		if let Some(assertion) = assertion
+		{
+			return Ok(Node::Assertion(assertion));
+		}
+
+		if ('1'..='9').contains(&c)
+		{
+			return match self.dialect
+			{
+				Dialect::Postgres =>
+				{
+					self.position -= 1;
+					self.number().map(Node::Backreference)
+				},
+				Dialect::Rust => Err(Error::RegexUnsupported {
+					construct: Construct::Backreference,
+					dialect: self.dialect,
+				}),
+			};
+		}
+
+		Ok(match self.escape_item(c)?
+		{
+			Some(ClassItem::Char(c)) => Node::Literal(c),
+			Some(item) => Node::Class(Class {
+				items: vec![item],
+				negated: false,
+			}),
+			None => return Err(self.unknown_escape(c)),
+		})
+	}
+
+	/// Read an escape sequence which is valid both inside and outside of brackets, after the `\c`.
+	fn escape_item(&mut self, c: char) -> Result<Option<ClassItem>>
+	{
+		let shorthand = match c
+		{
+			'd' => Some(Shorthand::Digit),
+			'D' => Some(Shorthand::NotDigit),
+			's' => Some(Shorthand::Space),
+			'S' => Some(Shorthand::NotSpace),
+			'w' => Some(Shorthand::Word),
+			'W' => Some(Shorthand::NotWord),
+			_ => None,
+		};
+
+		if let Some(shorthand) = shorthand
+		{
+			return Ok(Some(ClassItem::Shorthand(shorthand)));
+		}
+
+		Ok(Some(ClassItem::Char(match (self.dialect, c)
+		{
+			(_, 'a') => '\x07',
+			(_, 'f') => '\x0C',
+			(_, 'n') => '\n',
+			(_, 'r') => '\r',
+			(_, 't') => '\t',
+			(_, 'v') => '\x0B',
+			(Dialect::Postgres, 'b') => '\x08',
+			(Dialect::Postgres, 'e') => '\x1B',
+			(Dialect::Postgres, 'u') => self.hex(4, 4)?,
+			(Dialect::Postgres, 'U') => self.hex(8, 8)?,
+			(Dialect::Postgres, 'x') => self.hex(1, 8)?,
+			(Dialect::Rust, 'p' | 'P') =>
+			{
+				let name = match self.eat("{")
+				{
+					true =>
+					{
+						let start = self.position;
+						while !self.eat("}")
+						{
+							self.next()?;
+						}
+
+						self.chars[start..self.position - 1].iter().collect()
+					},
+					false => self.next()?.into(),
+				};
+
+				return Ok(Some(ClassItem::Unicode {
+					name,
+					negated: c == 'P',
+				}));
+			},
+			(Dialect::Rust, 'u') if !self.eat("{") => self.hex(4, 4)?,
+			(Dialect::Rust, 'U') if !self.eat("{") => self.hex(8, 8)?,
+			(Dialect::Rust, 'x') if !self.eat("{") => self.hex(2, 2)?,
+			(Dialect::Rust, 'u' | 'U' | 'x') =>
+			{
+				let c = self.hex(1, 8)?;
+				if !self.eat("}")
+				{
+					return Err(self.error("expected `}` to close the escape sequence"));
+				}
+
+				c
+			},
+			(_, c) if c.is_ascii_punctuation() => c,
+			_ => return Ok(None),
+		})))
+	}
+
+	/// Read a group, after the `(`.
+	fn group(&mut self) -> Result<Node>
+	{
+		if self.depth == MAX_DEPTH
+		{
+			return Err(self.error(format!(
+				"groups cannot be nested more than {MAX_DEPTH} deep"
+			)));
+		}
+
+		self.depth += 1;
+		let node = self.group_contents();
+		self.depth -= 1;
+		node
+	}
+
+	/// Read the contents of a group, after the `(`.
+	fn group_contents(&mut self) -> Result<Node>
+	{
+		let lookaround = [
+			("?=", true, false),
+			("?!", true, true),
+			("?<=", false, false),
+			("?<!", false, true),
+		]
+		.into_iter()
+		.find(|(prefix, ..)| self.eat(prefix));
+
+		let node = match lookaround
+		{
+			Some(_) if self.dialect == Dialect::Rust =>
+			{
+				return Err(Error::RegexUnsupported {
+					construct: Construct::Lookaround,
+					dialect: self.dialect,
+				})
+			},
+			Some((_, ahead, negated)) => Node::Lookaround {
+				ahead,
+				negated,
+				node: self.alternation()?.into(),
+			},
+			None if self.eat("?:") => Node::Group {
+				capturing: false,
+				node: self.alternation()?.into(),
+			},
+			None if self.dialect == Dialect::Rust && self.eat("?s:") =>
+			{
+				let dot_all = core::mem::replace(&mut self.dot_all, true);
+				let node = self.alternation();
+				self.dot_all = dot_all;
+				Node::Group {
+					capturing: false,
+					node: node?.into(),
+				}
+			},
+			None if self.dialect == Dialect::Rust && (self.eat("?P<") || self.eat("?<")) =>
+			{
+				while !self.eat(">")
+				{
+					self.next()?;
+				}
+
+				Node::Group {
+					capturing: true,
+					node: self.alternation()?.into(),
+				}
+			},
+			None if self.peek() == Some('?') =>
+			{
+				return Err(self.error(
+					"only `(?i)` at the start of the pattern and `(?s:…)` are supported as flags",
+				))
+			},
+			None => Node::Group {
+				capturing: true,
+				node: self.alternation()?.into(),
+			},
+		};
+
+		match self.eat(")")
+		{
+			true => Ok(node),
+			false => Err(self.error("expected `)` to close the group")),
+		}
+	}
+
+	/// Read between `min` and `max` hexadecimal digits as the code of a character.
+	fn hex(&mut self, min: usize, max: usize) -> Result<char>
+	{
+		let start = self.position;
+		while self.position - start < max && self.peek().is_some_and(|c| c.is_ascii_hexdigit())
+		{
+			self.position += 1;
+		}
+
+		let digits: String = self.chars[start..self.position].iter().collect();
+		if digits.len() < min
+		{
+			return Err(self.error(format!("expected at least {min} hexadecimal digits")));
+		}
+
+		u32::from_str_radix(&digits, 16)
+			.ok()
+			.and_then(char::from_u32)
+			.ok_or_else(|| self.error(format!("`{digits}` is not a valid character code")))
+	}
+
+	/// Read the next character.
+	fn next(&mut self) -> Result<char>
+	{
+		let c = self
+			.peek()
+			.ok_or_else(|| self.error("the pattern ended unexpectedly"))?;
+		self.position += 1;
+		Ok(c)
+	}
+
+	/// Read a decimal number.
+	fn number(&mut self) -> Result<u32>
+	{
+		let start = self.position;
+		while self.peek().is_some_and(|c| c.is_ascii_digit())
+		{
+			self.position += 1;
+		}
+
+		self.chars[start..self.position]
+			.iter()
+			.collect::<String>()
+			.parse()
+			.map_err(|_| self.error("expected a number"))
+	}
+
+	/// Read the whole pattern, returning whether it is case-insensitive along with its [`Node`].
+	pub(super) fn parse(mut self) -> Result<(bool, Node)>
+	{
+		if self.dialect == Dialect::Postgres
+		{
+			if self.eat("***=")
+			{
+				let literals = self.chars[self.position..]
+					.iter()
+					.copied()
+					.map(Node::Literal);
+				return Ok((false, Node::Concat(literals.collect())));
+			}
+
+			self.eat("***:");
+		}
+
+		let case_insensitive = self.eat("(?i)");
+		let node = self.alternation()?;
+		match self.peek()
+		{
+			Some(_) => Err(self.error("there is no group for `)` to close")),
+			None => Ok((case_insensitive, node)),
+		}
+	}
+
+	/// Get the next character without reading it.
+	fn peek(&self) -> Option<char>
+	{
+		self.chars.get(self.position).copied()
+	}
+
+	/// Read a repetition of the `node` (e.g. `*`), if there is one.
+	fn repeat(&mut self, node: Node) -> Result<Node>
+	{
+		let (min, max) = if self.eat("*")
+		{
+			(0, None)
+		}
+		else if self.eat("+")
+		{
+			(1, None)
+		}
+		else if self.eat("?")
+		{
+			(0, Some(1))
+		}
+		else if self.peek() == Some('{') &&
+			self
+				.chars
+				.get(self.position + 1)
+				.is_some_and(char::is_ascii_digit)
+		{
+			self.position += 1;
+			self.bounds()?
+		}
+		else
+		{
+			return Ok(node);
+		};
+
+		if matches!(node, Node::Assertion(_) | Node::Lookaround { .. })
+		{
+			return Err(self.error("an assertion cannot be repeated"));
+		}
+
+		Ok(Node::Repeat {
+			greedy: !self.eat("?"),
+			max,
+			min,
+			node: node.into(),
+		})
+	}
+
+	/// Create an [`Error`] for an escape sequence `\c` which is not valid.
+	fn unknown_escape(&self, c: char) -> Error
+	{
+		self.error(format!("`\\{c}` is not a valid escape sequence"))
+	}
+}
@@ -0,0 +1,196 @@
+use core::fmt::{Display, Formatter, Result, Write};
+
+use super::{
+	node::{Assertion, Class, ClassItem, Node},
+	Dialect,
+	Pattern,
+};
+
+/// Characters which must be escaped outside of brackets.
+const META: &str = "\\.+*?()|[]{}^$";
+
+/// Characters which must be escaped inside of brackets.
+const CLASS_META: &str = "\\[]^-";
+
+/// Characters which must be escaped inside of brackets in [`Dialect::Rust`], where doubling them
+/// is a set operation (e.g. `&&`).
+const RUST_CLASS_META: &str = "\\[]^-&~";
+
+/// Writes a [`Pattern`] as a regular expression of some [`Dialect`].
+pub(super) struct Writer<'pattern>
+{
+	/// The syntax to write in.
+	pub(super) dialect: Dialect,
+
+	/// The pattern to write.
+	pub(super) pattern: &'pattern Pattern,
+}
+
+impl Writer<'_>
+{
+	/// Write an [`Assertion`].
+	fn write_assertion(&self, f: &mut Formatter<'_>, assertion: Assertion) -> Result
+	{
+		f.write_str(match (self.dialect, assertion)
+		{
+			(_, Assertion::End) => "$",
+			(_, Assertion::Start) => "^",
+			(Dialect::Postgres, Assertion::NotWordBoundary) => "\\Y",
+			(Dialect::Postgres, Assertion::WordBoundary) => "\\y",
+			(Dialect::Postgres, Assertion::WordEnd) => "\\M",
+			(Dialect::Postgres, Assertion::WordStart) => "\\m",
+			(Dialect::Rust, Assertion::NotWordBoundary) => "\\B",
+			(Dialect::Rust, Assertion::WordBoundary) => "\\b",
+			(Dialect::Rust, Assertion::WordEnd) => "\\b{end}",
+			(Dialect::Rust, Assertion::WordStart) => "\\b{start}",
+		})
+	}
+
+	/// Write a single character `c`, escaping it if it is one of the `meta` characters.
+	fn write_char(&self, f: &mut Formatter<'_>, c: char, meta: &str) -> Result
+	{
+		match c
+		{
+			'\n' => f.write_str("\\n"),
+			'\r' => f.write_str("\\r"),
+			'\t' => f.write_str("\\t"),
+			c if c.is_control() => match self.dialect
+			{
+				Dialect::Postgres => write!(f, "\\U{:08X}", u32::from(c)),
+				Dialect::Rust => write!(f, "\\x{{{:X}}}", u32::from(c)),
+			},
+			c if meta.contains(c) => write!(f, "\\{c}"),
+			c => f.write_char(c),
+		}
+	}
+
+	/// Write a [`Class`].
+	fn write_class(&self, f: &mut Formatter<'_>, class: &Class) -> Result
+	{
+		if let (false, [item @ (ClassItem::Shorthand(_) | ClassItem::Unicode { .. })]) =
+			(class.negated, class.items.as_slice())
+		{
+			return self.write_class_item(f, item);
+		}
+
+		f.write_str(if class.negated { "[^" } else { "[" })?;
+		class
+			.items
+			.iter()
+			.try_for_each(|item| self.write_class_item(f, item))?;
+		f.write_char(']')
+	}
+
+	/// Write a [`ClassItem`].
+	fn write_class_item(&self, f: &mut Formatter<'_>, item: &ClassItem) -> Result
+	{
+		let meta = match self.dialect
+		{
+			Dialect::Postgres => CLASS_META,
+			Dialect::Rust => RUST_CLASS_META,
+		};
+
+		match item
+		{
+			ClassItem::Char(c) => self.write_char(f, *c, meta),
+			ClassItem::Posix(name) => write!(f, "[:{name}:]"),
+			ClassItem::Range(start, end) =>
+			{
+				self.write_char(f, *start, meta)?;
+				f.write_char('-')?;
+				self.write_char(f, *end, meta)
+			},
+			ClassItem::Shorthand(shorthand) => write!(f, "\\{}", shorthand.letter()),
+			ClassItem::Unicode { name, negated } =>
+			{
+				write!(f, "\\{}{{{name}}}", if *negated { 'P' } else { 'p' })
+			},
+		}
+	}
+
+	/// Write a [`Node`].
+	fn write_node(&self, f: &mut Formatter<'_>, node: &Node) -> Result
+	{
+		match node
+		{
+			Node::Alternation(branches) => branches.iter().enumerate().try_for_each(|(i, branch)| {
+				if i > 0
+				{
+					f.write_char('|')?;
+				}
+
+				self.write_node(f, branch)
+			}),
+			Node::AnyChar { newline } => f.write_str(match (self.dialect, newline)
+			{
+				(Dialect::Postgres, false) => "[^\\n]",
+				(Dialect::Rust, true) => "(?s:.)",
+				_ => ".",
+			}),
+			Node::Assertion(assertion) => self.write_assertion(f, *assertion),
+			Node::Backreference(index) => write!(f, "\\{index}"),
+			Node::Class(class) => self.write_class(f, class),
+			Node::Concat(nodes) => nodes.iter().try_for_each(|n| self.write_node(f, n)),
+			Node::Group { capturing, node } =>
+			{
+				f.write_str(if *capturing { "(" } else { "(?:" })?;
+				self.write_node(f, node)?;
+				f.write_char(')')
+			},
+			Node::Literal(c) => self.write_char(f, *c, META),
+			Node::Lookaround {
+				ahead,
+				negated,
+				node,
+			} =>
+			{
+				f.write_str(match (ahead, negated)
+				{
+					(true, false) => "(?=",
+					(true, true) => "(?!",
+					(false, false) => "(?<=",
+					(false, true) => "(?<!",
+				})?;
+				self.write_node(f, node)?;
+				f.write_char(')')
+			},
+			Node::Repeat {
+				greedy,
+				max,
+				min,
+				node,
+			} =>
+			{
+				self.write_node(f, node)?;
+				match (min, max)
+				{
+					(0, None) => f.write_char('*'),
+					(1, None) => f.write_char('+'),
+					(0, Some(1)) => f.write_char('?'),
+					(min, None) => write!(f, "{{{min},}}"),
+					(min, Some(max)) if min == max => write!(f, "{{{min}}}"),
+					(min, Some(max)) => write!(f, "{{{min},{max}}}"),
+				}?;
+
+				match greedy
+				{
+					true => Ok(()),
+					false => f.write_char('?'),
+				}
+			},
+		}
+	}
+}
+
+impl Display for Writer<'_>
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result
+	{
+		if self.pattern.case_insensitive
+		{
+			f.write_str("(?i)")?;
+		}
+
+		self.write_node(f, &self.pattern.node)
+	}
+}
@@ -0,0 +1,248 @@
+mod cursor;
+mod direction;
+mod field_value;
+mod order_by;
+mod orderable;
+
+use core::cmp::Ordering;
+
+pub use cursor::Cursor;
+pub use direction::Direction;
+pub use field_value::FieldValue;
+pub use order_by::OrderBy;
+pub(crate) use orderable::value_at;
+pub use orderable::Orderable;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Compile, Error, FieldPath, IsMatch, Result};
+
+/// An entity matcher (e.g. [`MatchEmployee`](crate::MatchEmployee)), along with the order in which
+/// the records that match it should be returned, which page of them to return, and which of their
+/// fields to [`select`](Query::select).
+///
+/// Records are sorted by each [`OrderBy`] in turn, and records which are equal by every
+/// [`OrderBy`] remain in the order they were given. Strings are compared by their bytes (as with
+/// `COLLATE "C"` in Postgres), and `NULL`s are greater than every other value.
+///
+/// A page can be selected either by its `offset`, or by a [`Cursor`] (i.e. keyset pagination),
+/// which only returns the records that come after the last record of the previous page. Cursors
+/// are more efficient for adapters, and do not skip or repeat records when others are inserted
+/// between pages, but require the [`OrderBy`]s to order every record uniquely (e.g. by ending with
+/// the `id`).
+///
+/// The records of a page can be [projected](Query::project) onto the fields which are selected, so
+/// that callers which only need some fields (e.g. a list endpoint) do not have to extract them.
+///
+/// # Examples
+///
+/// ```rust
+/// use clinvoice_match::{Direction, MatchEmployee, MatchStr, OrderBy, Query};
+/// use clinvoice_schema::Employee;
+/// # use pretty_assertions::assert_eq;
+///
+/// let employee = |id, name: &str| Employee { id, name: name.into(), ..Default::default() };
+/// let employees = vec![employee(1, "Bob"), employee(2, "Alice"), employee(3, "Bob"), employee(4, "Zoë")];
+///
+/// let mut query = Query {
+///   limit: Some(2),
+///   order_by: vec![
+///     OrderBy::new("name".parse().unwrap(), Direction::Descending),
+///     OrderBy::new("id".parse().unwrap(), Direction::Ascending),
+///   ],
+///   ..Query::new(MatchEmployee { name: MatchStr::Not(MatchStr::from("Zoë".to_owned()).into()), ..Default::default() })
+/// };
+///
+/// let page = query.apply(employees.clone()).unwrap();
+/// assert_eq!(page.iter().map(|e| e.id).collect::<Vec<_>>(), [1, 3]);
+///
+/// query.after = Some(query.cursor(&page[1]).unwrap());
+/// let page = query.apply(employees).unwrap();
+/// assert_eq!(page.iter().map(|e| e.id).collect::<Vec<_>>(), [2]);
+///
+/// query.select = vec!["id".parse().unwrap(), "name".parse().unwrap()];
+/// assert_eq!(query.project(&page[0]).unwrap(), [2.into(), "Alice".into()]);
+/// ```
+///
+/// ## YAML
+///
+/// Requires the `serde` feature. If any field except the `condition` is omitted, it will be set
+/// to the [`Default`] for its type.
+///
+/// ```rust
+/// # assert!(serde_yaml::from_str::<clinvoice_match::Query<clinvoice_match::MatchJob>>(r#"
+/// condition:
+///   client:
+///     name:
+///       contains: "Corp"
+/// order_by:
+///   - field: client.name
+///   - field: date_open
+///     direction: descending
+///   - field: id
+/// limit: 25
+/// offset: 50
+/// select: [id, client.name]
+/// # "#).is_ok());
+/// ```
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Query<M>
+{
+	/// Only return the records which come after this [`Cursor`].
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub after: Option<Cursor>,
+
+	/// The matcher which records must match in order to be returned.
+	pub condition: M,
+
+	/// The greatest number of records to return.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub limit: Option<usize>,
+
+	/// The number of records to skip before returning any.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub offset: usize,
+
+	/// How the records are sorted, from most to least significant.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub order_by: Vec<OrderBy>,
+
+	/// The fields which are [projected](Query::project) from each record.
+	#[cfg_attr(feature = "serde", serde(default))]
+	#[cfg_attr(feature = "schemars", schemars(with = "Vec<String>"))]
+	pub select: Vec<FieldPath>,
+}
+
+/// Compare the `left` and `right` values of the fields in the `order_by`.
+fn compare(order_by: &[OrderBy], left: &[FieldValue], right: &[FieldValue]) -> Ordering
+{
+	order_by
+		.iter()
+		.zip(left.iter().zip(right))
+		.map(|(o, (l, r))| o.direction.apply(l.cmp(r)))
+		.find(|ordering| ordering.is_ne())
+		.unwrap_or(Ordering::Equal)
+}
+
+impl<M> Query<M>
+{
+	/// Create a [`Query`] for every record which matches the `condition`, in the order they are
+	/// given.
+	pub const fn new(condition: M) -> Self
+	{
+		Self {
+			after: None,
+			condition,
+			limit: None,
+			offset: 0,
+			order_by: Vec::new(),
+			select: Vec::new(),
+		}
+	}
+}
+
+impl<M> Query<M>
+where
+	M: Orderable,
+{
+	/// Get the page of the `records` which match, in order.
+	///
+	/// # Errors
+	///
+	/// * When the `condition` cannot be [compiled](Compile).
+	/// * When the [`Query`] is not [valid](Self::check).
+	pub fn apply(&self, records: Vec<M::Record>) -> Result<Vec<M::Record>>
+	where
+		M: Compile,
+		M::Compiled: IsMatch<M::Record>,
+	{
+		self.check()?;
+		let compiled = self.condition.compile()?;
+
+		let mut keyed = records
+			.into_iter()
+			.filter(|r| compiled.is_match(r))
+			.map(|r| self.key(&r).map(|key| (key, r)))
+			.collect::<Result<Vec<_>>>()?;
+
+		keyed.sort_by(|(l, _), (r, _)| compare(&self.order_by, l, r));
+
+		Ok(keyed
+			.into_iter()
+			.filter(|(key, _)| {
+				self
+					.after
+					.as_ref()
+					.is_none_or(|after| compare(&self.order_by, key, &after.0).is_gt())
+			})
+			.skip(self.offset)
+			.take(self.limit.unwrap_or(usize::MAX))
+			.map(|(_, record)| record)
+			.collect())
+	}
+
+	/// Check that each [`OrderBy`] and `select`ed field refers to a field which records can be
+	/// ordered by, and that the [`Cursor`] (if any) has a value for each [`OrderBy`].
+	///
+	/// # Errors
+	///
+	/// * When an [`OrderBy`] or `select`ed field refers to a field which does not exist, or cannot
+	///   be ordered by (e.g. the `expenses` of a [`Timesheet`](clinvoice_schema::Timesheet)).
+	/// * When the [`Cursor`] does not have a value for each [`OrderBy`].
+	pub fn check(&self) -> Result<()>
+	{
+		self
+			.order_by
+			.iter()
+			.map(|o| &o.field)
+			.chain(&self.select)
+			.try_for_each(|path| value_at::<M>(None, path).map(drop))?;
+
+		match &self.after
+		{
+			Some(after) if after.0.len() != self.order_by.len() => Err(Error::CursorLength {
+				expected: self.order_by.len(),
+				found: after.0.len(),
+			}),
+			_ => Ok(()),
+		}
+	}
+
+	/// Create a [`Cursor`] which selects the records that come after the `record`.
+	///
+	/// # Errors
+	///
+	/// When an [`OrderBy`] refers to a field which records cannot be ordered by.
+	pub fn cursor(&self, record: &M::Record) -> Result<Cursor>
+	{
+		self.key(record).map(Cursor)
+	}
+
+	/// Get the value of each `select`ed field of the `record`, in order.
+	///
+	/// # Errors
+	///
+	/// When a `select`ed field refers to a field which records cannot be ordered by.
+	pub fn project(&self, record: &M::Record) -> Result<Vec<FieldValue>>
+	{
+		self
+			.select
+			.iter()
+			.map(|path| value_at::<M>(Some(record), path))
+			.collect()
+	}
+
+	/// Get the value of each field in the `order_by` for the `record`.
+	fn key(&self, record: &M::Record) -> Result<Vec<FieldValue>>
+	{
+		self
+			.order_by
+			.iter()
+			.map(|o| value_at::<M>(Some(record), &o.field))
+			.collect()
+	}
+}
@@ -0,0 +1,24 @@
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::FieldValue;
+
+/// The position of a record in the order of a [`Query`](super::Query), which is used to get the
+/// records that come after it (i.e. the next page).
+///
+/// Created by [`Query::cursor`](super::Query::cursor).
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize), serde(transparent))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Cursor(pub(super) Vec<FieldValue>);
+
+impl Cursor
+{
+	/// The value of each field which the [`Query`](super::Query) is ordered by, for the record.
+	pub fn values(&self) -> &[FieldValue]
+	{
+		&self.0
+	}
+}
@@ -0,0 +1,37 @@
+use core::cmp::Ordering;
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Whether an [`OrderBy`](super::OrderBy) sorts records from least to greatest, or the reverse.
+#[cfg_attr(
+	feature = "serde",
+	derive(Deserialize, Serialize),
+	serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction
+{
+	/// From least to greatest.
+	#[default]
+	Ascending,
+
+	/// From greatest to least.
+	Descending,
+}
+
+impl Direction
+{
+	/// Orient the `ordering` of two values in this [`Direction`].
+	pub(super) const fn apply(self, ordering: Ordering) -> Ordering
+	{
+		match self
+		{
+			Self::Ascending => ordering,
+			Self::Descending => ordering.reverse(),
+		}
+	}
+}
@@ -0,0 +1,45 @@
+mod from;
+mod ord;
+
+use core::time::Duration;
+
+use clinvoice_schema::{chrono::NaiveDateTime, Id, Money};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The value of a field which records can be [ordered](super::OrderBy) by.
+#[cfg_attr(
+	feature = "serde",
+	derive(Deserialize, Serialize),
+	serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum FieldValue
+{
+	/// A [`Duration`].
+	Duration(
+		#[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+		#[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Duration"))]
+		Duration,
+	),
+
+	/// An [`Id`].
+	Id(Id),
+
+	/// An amount of [`Money`].
+	Money(#[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Money"))] Money),
+
+	/// A [`NaiveDateTime`].
+	NaiveDateTime(NaiveDateTime),
+
+	/// The absence of a value (e.g. a [`None`] date, or a field of an outer location which does not
+	/// exist). It is greater than every other value.
+	#[default]
+	Null,
+
+	/// A [`String`].
+	String(String),
+}
@@ -0,0 +1,55 @@
+use core::time::Duration;
+
+use clinvoice_schema::{chrono::NaiveDateTime, Id, Money};
+
+use super::FieldValue;
+
+impl From<Duration> for FieldValue
+{
+	fn from(duration: Duration) -> Self
+	{
+		Self::Duration(duration)
+	}
+}
+
+impl From<Id> for FieldValue
+{
+	fn from(id: Id) -> Self
+	{
+		Self::Id(id)
+	}
+}
+
+impl From<Money> for FieldValue
+{
+	fn from(money: Money) -> Self
+	{
+		Self::Money(money)
+	}
+}
+
+impl From<NaiveDateTime> for FieldValue
+{
+	fn from(date: NaiveDateTime) -> Self
+	{
+		Self::NaiveDateTime(date)
+	}
+}
+
+impl<T> From<Option<T>> for FieldValue
+where
+	T: Into<Self>,
+{
+	fn from(value: Option<T>) -> Self
+	{
+		value.map_or(Self::Null, Into::into)
+	}
+}
+
+impl From<&str> for FieldValue
+{
+	fn from(s: &str) -> Self
+	{
+		Self::String(s.to_owned())
+	}
+}
@@ -0,0 +1,44 @@
+use core::cmp::Ordering;
+
+use super::FieldValue;
+
+impl FieldValue
+{
+	/// The position of this kind of value relative to the others, when they are not the same kind.
+	const fn rank(&self) -> u8
+	{
+		match self
+		{
+			Self::Duration(_) => 0,
+			Self::Id(_) => 1,
+			Self::Money(_) => 2,
+			Self::NaiveDateTime(_) => 3,
+			Self::String(_) => 4,
+			Self::Null => 5,
+		}
+	}
+}
+
+impl Ord for FieldValue
+{
+	fn cmp(&self, other: &Self) -> Ordering
+	{
+		match (self, other)
+		{
+			(Self::Duration(l), Self::Duration(r)) => l.cmp(r),
+			(Self::Id(l), Self::Id(r)) => l.cmp(r),
+			(Self::Money(l), Self::Money(r)) => l.cmp(r),
+			(Self::NaiveDateTime(l), Self::NaiveDateTime(r)) => l.cmp(r),
+			(Self::String(l), Self::String(r)) => l.cmp(r),
+			_ => self.rank().cmp(&other.rank()),
+		}
+	}
+}
+
+impl PartialOrd for FieldValue
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		Some(self.cmp(other))
+	}
+}
@@ -0,0 +1,32 @@
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::Direction;
+use crate::FieldPath;
+
+/// A field which the records of a [`Query`](super::Query) are sorted by.
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct OrderBy
+{
+	/// The [`Direction`] to sort in.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub direction: Direction,
+
+	/// The path to the field, from the root of the record (e.g. `client.name` for a
+	/// [`Job`](clinvoice_schema::Job)).
+	#[cfg_attr(feature = "schemars", schemars(with = "String"))]
+	pub field: FieldPath,
+}
+
+impl OrderBy
+{
+	/// Sort by the `field` in some `direction`.
+	pub const fn new(field: FieldPath, direction: Direction) -> Self
+	{
+		Self { direction, field }
+	}
+}
@@ -0,0 +1,37 @@
+use super::FieldValue;
+use crate::{Error, FieldPath, Result};
+
+/// A matcher whose records can be [ordered](super::OrderBy) by their fields.
+pub trait Orderable
+{
+	/// The type of record which this matcher matches.
+	type Record;
+
+	/// Get the value of the `field` of the `record`, where the `field` is given as the name of each
+	/// field from the root of the `record`. When the `record` is [`None`], the value is
+	/// [`FieldValue::Null`].
+	///
+	/// Returns [`None`] if the `field` does not exist, or records cannot be ordered by it.
+	fn field_value(record: Option<&Self::Record>, field: &[&str]) -> Option<FieldValue>;
+
+	/// Whether the values of the `field` can be added together by an
+	/// [`Aggregate::Sum`](crate::Aggregate::Sum) (i.e. it is a [`Duration`](core::time::Duration)
+	/// or [`Money`](clinvoice_schema::Money)).
+	fn is_summable(_field: &[&str]) -> bool
+	{
+		false
+	}
+}
+
+/// Get the value of the field at the `path` of the `record` (see [`Orderable::field_value`]).
+///
+/// # Errors
+///
+/// When records cannot be ordered by the field at the `path`.
+pub(crate) fn value_at<M>(record: Option<&M::Record>, path: &FieldPath) -> Result<FieldValue>
+where
+	M: Orderable + ?Sized,
+{
+	let fields: Vec<_> = path.fields().collect();
+	M::field_value(record, &fields).ok_or_else(|| Error::UnknownField { path: path.clone() })
+}
@@ -0,0 +1,4 @@
+use crate::Error;
+
+/// A [`Result`](core::result::Result) which may fail with an [`Error`] from this crate.
+pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,79 @@
+mod domain;
+mod satisfiability;
+
+pub(crate) use domain::{representatives, Domain};
+pub use satisfiability::Satisfiability;
+
+use crate::FieldPath;
+
+/// The greatest number of distinct conditions (e.g. [`MatchStr::Contains`](crate::MatchStr::Contains))
+/// that are combined when proving that a [`MatchStr`](crate::MatchStr) or
+/// [`MatchSet`](crate::MatchSet) is unsatisfiable, since every combination of them is tried.
+pub(crate) const MAX_CONDITIONS: usize = 12;
+
+/// A matcher which can determine whether any record matches it, and if so, create one.
+///
+/// This is useful both for warning that a filter matches nothing, and for generating records
+/// which a filter should match (e.g. as test fixtures).
+///
+/// Conditions on dates, [`Id`](clinvoice_schema::Id)s, and durations are always either satisfied
+/// or proven unsatisfiable. Conditions on strings are satisfied by trying the values they contain
+/// (e.g. each [`MatchStr::EqualTo`](crate::MatchStr::EqualTo)), as well as an example of each
+/// simple [`MatchStr::Regex`](crate::MatchStr::Regex), and are only proven unsatisfiable when they
+/// contradict themselves (e.g. `a` and not `a`). When neither is possible, the result is
+/// [`Satisfiability::Unknown`].
+///
+/// # Examples
+///
+/// ```rust
+/// use clinvoice_match::{
+///   Compile,
+///   FieldPath,
+///   IsMatch,
+///   Match,
+///   MatchEmployee,
+///   MatchStr,
+///   MatchTimesheet,
+///   Satisfiability,
+///   Satisfy,
+/// };
+/// use clinvoice_schema::chrono::NaiveDate;
+/// # use pretty_assertions::assert_eq;
+///
+/// let date = |y| NaiveDate::from_ymd_opt(y, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+/// let mut condition = MatchTimesheet {
+///   employee: MatchEmployee {
+///     id: Match::GreaterThan(10),
+///     name: MatchStr::Regex("^Bob (Ross|Dylan)$".into()),
+///     ..Default::default()
+///   },
+///   time_begin: Match::InRange(date(2022), date(2023)),
+///   work_notes: MatchStr::And(vec![MatchStr::Contains("bug".into()), MatchStr::Contains("fix".into())]),
+///   ..Default::default()
+/// };
+///
+/// let timesheet = condition.satisfy().example().unwrap();
+/// assert!(condition.compile().unwrap().is_match(&timesheet));
+/// assert_eq!(timesheet.employee.id, 11);
+/// assert_eq!(timesheet.employee.name, "Bob Ross");
+///
+/// condition.employee.id = Match::And(vec![Match::GreaterThan(10), Match::LessThan(5)]);
+/// assert_eq!(
+///   condition.satisfy(),
+///   Satisfiability::Unsatisfiable(FieldPath::default().join("employee").join("id")),
+/// );
+/// ```
+pub trait Satisfy
+{
+	/// The type of record which this matcher matches.
+	type Record;
+
+	/// Determine whether any record matches `self`.
+	fn satisfy(&self) -> Satisfiability<Self::Record>
+	{
+		self.satisfy_at(&FieldPath::default())
+	}
+
+	/// Determine whether any record matches `self`, as if `self` were located at the `path`.
+	fn satisfy_at(&self, path: &FieldPath) -> Satisfiability<Self::Record>;
+}
@@ -0,0 +1,197 @@
+use core::time::Duration;
+
+use clinvoice_schema::{
+	chrono::{self, NaiveDateTime},
+	Id,
+	Money,
+};
+
+/// What lies strictly between two values of a [`Domain`].
+pub(crate) enum Between<T>
+{
+	/// There are no values between them.
+	Empty,
+
+	/// It is not known whether there are any values between them.
+	Unknown,
+
+	/// A value between them.
+	Value(T),
+}
+
+/// A totally ordered type whose values can be found near some others, so that any condition on it
+/// can be [satisfied](crate::Satisfy) or proven unsatisfiable.
+pub(crate) trait Domain: Clone + Ord
+{
+	/// The greatest value which is less than `self`, if any.
+	fn before(&self) -> Option<Self>;
+
+	/// A value between `self` and some greater value.
+	fn between(&self, greater: &Self) -> Between<Self>;
+
+	/// A value to use when no others are known.
+	fn example() -> Self;
+
+	/// The least value which is greater than `self`, if any.
+	fn next(&self) -> Option<Self>;
+}
+
+/// Get a value from each region of the [`Domain`] which is delimited by the `constants`, and
+/// whether that includes every region.
+///
+/// Every condition which only compares against the `constants` is either true for all of the
+/// values in a region, or none of them.
+pub(crate) fn representatives<T>(mut constants: Vec<T>) -> (Vec<T>, bool)
+where
+	T: Domain,
+{
+	constants.sort_unstable();
+	constants.dedup();
+
+	let Some((first, last)) = constants.first().zip(constants.last())
+	else
+	{
+		return (vec![T::example()], true);
+	};
+
+	let mut exhaustive = true;
+	let mut values: Vec<_> = first.before().into_iter().collect();
+	constants.iter().enumerate().for_each(|(i, constant)| {
+		values.push(constant.clone());
+		if let Some(greater) = constants.get(i + 1)
+		{
+			match constant.between(greater)
+			{
+				Between::Empty => (),
+				Between::Unknown => exhaustive = false,
+				Between::Value(value) => values.push(value),
+			}
+		}
+	});
+
+	values.extend(last.next());
+	(values, exhaustive)
+}
+
+/// Implement [`Domain::between`] for a discrete type, where the only value which needs to be
+/// considered is the [`Domain::next`] one.
+fn next_between<T>(value: &T, greater: &T) -> Between<T>
+where
+	T: Domain,
+{
+	value
+		.next()
+		.filter(|next| next < greater)
+		.map_or(Between::Empty, Between::Value)
+}
+
+impl Domain for Duration
+{
+	fn before(&self) -> Option<Self>
+	{
+		self.checked_sub(Self::from_nanos(1))
+	}
+
+	fn between(&self, greater: &Self) -> Between<Self>
+	{
+		next_between(self, greater)
+	}
+
+	fn example() -> Self
+	{
+		Self::from_secs(60 * 15)
+	}
+
+	fn next(&self) -> Option<Self>
+	{
+		self.checked_add(Self::from_nanos(1))
+	}
+}
+
+impl Domain for Id
+{
+	fn before(&self) -> Option<Self>
+	{
+		self.checked_sub(1)
+	}
+
+	fn between(&self, greater: &Self) -> Between<Self>
+	{
+		next_between(self, greater)
+	}
+
+	fn example() -> Self
+	{
+		0
+	}
+
+	fn next(&self) -> Option<Self>
+	{
+		self.checked_add(1)
+	}
+}
+
+impl Domain for Money
+{
+	fn before(&self) -> Option<Self>
+	{
+		self
+			.amount
+			.checked_sub(Self::new(1, 0, self.currency).amount)
+			.map(|amount| Self { amount, ..*self })
+	}
+
+	/// Values of the same amount in different currencies are not considered, since comparing them
+	/// is not meaningful.
+	fn between(&self, greater: &Self) -> Between<Self>
+	{
+		match self.amount == greater.amount
+		{
+			true if self.currency == greater.currency => Between::Empty,
+			true => Between::Unknown,
+			false => self
+				.amount
+				.checked_add(greater.amount)
+				.and_then(|sum| sum.checked_div(Self::new(2, 0, self.currency).amount))
+				.map_or(Between::Unknown, |amount| {
+					Between::Value(Self { amount, ..*self })
+				}),
+		}
+	}
+
+	fn example() -> Self
+	{
+		Self::default()
+	}
+
+	fn next(&self) -> Option<Self>
+	{
+		self
+			.amount
+			.checked_add(Self::new(1, 0, self.currency).amount)
+			.map(|amount| Self { amount, ..*self })
+	}
+}
+
+impl Domain for NaiveDateTime
+{
+	fn before(&self) -> Option<Self>
+	{
+		self.checked_sub_signed(chrono::Duration::nanoseconds(1))
+	}
+
+	fn between(&self, greater: &Self) -> Between<Self>
+	{
+		next_between(self, greater)
+	}
+
+	fn example() -> Self
+	{
+		Self::default()
+	}
+
+	fn next(&self) -> Option<Self>
+	{
+		self.checked_add_signed(chrono::Duration::nanoseconds(1))
+	}
+}
@@ -0,0 +1,96 @@
+use crate::FieldPath;
+
+/// Whether any record matches a matcher which implements [`Satisfy`](crate::Satisfy).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Satisfiability<T>
+{
+	/// The contained record matches.
+	Satisfiable(T),
+
+	/// It could not be determined whether any record matches.
+	Unknown,
+
+	/// No record matches, because no value matches the condition on the field at the contained
+	/// [`FieldPath`].
+	Unsatisfiable(FieldPath),
+}
+
+impl<T> Satisfiability<T>
+{
+	/// Get the record which matches, if there is one.
+	pub fn example(self) -> Option<T>
+	{
+		match self
+		{
+			Self::Satisfiable(record) => Some(record),
+			_ => None,
+		}
+	}
+
+	/// Whether a record which matches was found.
+	pub const fn is_satisfiable(&self) -> bool
+	{
+		matches!(self, Self::Satisfiable(_))
+	}
+
+	/// Whether no record can match.
+	pub const fn is_unsatisfiable(&self) -> bool
+	{
+		matches!(self, Self::Unsatisfiable(_))
+	}
+
+	/// Transform the record which matches, if there is one.
+	pub fn map<F, U>(self, f: F) -> Satisfiability<U>
+	where
+		F: FnOnce(T) -> U,
+	{
+		match self
+		{
+			Self::Satisfiable(record) => Satisfiability::Satisfiable(f(record)),
+			Self::Unknown => Satisfiability::Unknown,
+			Self::Unsatisfiable(path) => Satisfiability::Unsatisfiable(path),
+		}
+	}
+
+	/// Get the path to the field which cannot match, if there is one.
+	pub(crate) const fn proof(&self) -> Option<&FieldPath>
+	{
+		match self
+		{
+			Self::Unsatisfiable(path) => Some(path),
+			_ => None,
+		}
+	}
+
+	/// Find the first of the `candidates` which matches. If none do, the field at the `path` is
+	/// unsatisfiable when the `candidates` are `exhaustive`.
+	pub(crate) fn search<F>(
+		candidates: impl IntoIterator<Item = T>,
+		exhaustive: bool,
+		path: &FieldPath,
+		is_match: F,
+	) -> Self
+	where
+		F: FnMut(&T) -> bool,
+	{
+		match candidates.into_iter().find(is_match)
+		{
+			Some(record) => Self::Satisfiable(record),
+			None if exhaustive => Self::Unsatisfiable(path.clone()),
+			None => Self::Unknown,
+		}
+	}
+
+	/// The result for a record whose fields were not all satisfiable, given the `proofs` that each
+	/// field is unsatisfiable.
+	pub(crate) fn unsatisfied<'path>(
+		proofs: impl IntoIterator<Item = Option<&'path FieldPath>>,
+	) -> Self
+	{
+		proofs
+			.into_iter()
+			.flatten()
+			.next()
+			.map_or(Self::Unknown, |path| Self::Unsatisfiable(path.clone()))
+	}
+}
@@ -0,0 +1,225 @@
+use core::{fmt, marker::PhantomData};
+
+use serde::{
+	de::{
+		self,
+		value::{MapAccessDeserializer, StringDeserializer},
+		DeserializeSeed,
+		IgnoredAny,
+		IntoDeserializer,
+		MapAccess,
+		Unexpected,
+		Visitor,
+	},
+	Deserialize,
+	Deserializer,
+};
+
+/// The explicit form of a matcher, which can also be written in some shorthand.
+pub(crate) trait Conditions
+{
+	/// The names of the conditions (i.e. variants) of the explicit form.
+	const CONDITIONS: &'static [&'static str];
+}
+
+/// A matcher written as one of its [`Conditions`] `E` (e.g. `equal_to: 3`).
+///
+/// This is tried before any shorthand, and accepts any single-key map whose key is a condition, so
+/// that the reason a condition is [`Invalid`](Condition::Invalid) is reported instead of the
+/// shorthand's.
+pub(crate) enum Condition<E>
+{
+	/// The condition could not be read, for this reason.
+	Invalid(String),
+
+	/// The condition was read.
+	Valid(E),
+}
+
+impl<E> Condition<E>
+{
+	/// Get the condition, or the reason it could not be read.
+	pub(crate) fn into_result<Er>(self) -> Result<E, Er>
+	where
+		Er: de::Error,
+	{
+		match self
+		{
+			Self::Invalid(reason) => Err(Er::custom(reason)),
+			Self::Valid(condition) => Ok(condition),
+		}
+	}
+}
+
+impl<'de, E> Deserialize<'de> for Condition<E>
+where
+	E: Conditions + Deserialize<'de>,
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		deserializer.deserialize_any(ConditionVisitor(PhantomData))
+	}
+}
+
+/// A [`Visitor`] for a [`Condition`].
+struct ConditionVisitor<E>(PhantomData<E>);
+
+impl<'de, E> Visitor<'de> for ConditionVisitor<E>
+where
+	E: Conditions + Deserialize<'de>,
+{
+	type Value = Condition<E>;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+	{
+		formatter.write_str("a condition (e.g. `equal_to: 3`)")
+	}
+
+	fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+	where
+		A: MapAccess<'de>,
+	{
+		let key = map
+			.next_key::<String>()?
+			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+
+		if !E::CONDITIONS.contains(&key.as_str())
+		{
+			return Err(de::Error::unknown_variant(&key, E::CONDITIONS));
+		}
+
+		let condition = E::deserialize(MapAccessDeserializer::new(Replay {
+			key: Some(key),
+			map: &mut map,
+		}));
+
+		if map.next_key::<IgnoredAny>()?.is_some()
+		{
+			return Err(de::Error::invalid_length(2, &self));
+		}
+
+		Ok(condition.map_or_else(|e| Condition::Invalid(e.to_string()), Condition::Valid))
+	}
+
+	fn visit_str<Er>(self, v: &str) -> Result<Self::Value, Er>
+	where
+		Er: de::Error,
+	{
+		// conditions without any arguments (e.g. `any`) are written as strings
+		if !E::CONDITIONS.contains(&v)
+		{
+			return Err(Er::invalid_value(Unexpected::Str(v), &self));
+		}
+
+		E::deserialize(v.into_deserializer()).map(Condition::Valid)
+	}
+}
+
+/// A [`MapAccess`] which yields a `key` that was already read from the `map`, before the rest of it.
+struct Replay<A>
+{
+	key: Option<String>,
+	map: A,
+}
+
+impl<'de, A> MapAccess<'de> for Replay<A>
+where
+	A: MapAccess<'de>,
+{
+	type Error = A::Error;
+
+	fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+	where
+		K: DeserializeSeed<'de>,
+	{
+		match self.key.take()
+		{
+			Some(key) => seed.deserialize(StringDeserializer::new(key)).map(Some),
+			None => self.map.next_key_seed(seed),
+		}
+	}
+
+	fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+	where
+		V: DeserializeSeed<'de>,
+	{
+		self.map.next_value_seed(seed)
+	}
+}
+
+/// A single-key map whose key is not one of the [`Conditions`] `E`, and which is not any other
+/// shorthand either.
+///
+/// This is tried after every shorthand, so that a misspelled condition (e.g. `greter_than: 3`) is
+/// reported as such.
+pub(crate) struct Unknown<E>
+{
+	conditions: PhantomData<E>,
+	key: String,
+}
+
+impl<E> Unknown<E>
+where
+	E: Conditions,
+{
+	/// Explain that the key of this map is not one of the [`Conditions`].
+	pub(crate) fn into_error<Er>(self) -> Er
+	where
+		Er: de::Error,
+	{
+		Er::custom(format_args!(
+			"unknown condition `{}`, expected one of {}",
+			self.key,
+			E::CONDITIONS
+				.iter()
+				.map(|condition| format!("`{condition}`"))
+				.collect::<Vec<_>>()
+				.join(", "),
+		))
+	}
+}
+
+impl<'de, E> Deserialize<'de> for Unknown<E>
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		deserializer.deserialize_map(UnknownVisitor(PhantomData))
+	}
+}
+
+/// A [`Visitor`] for an [`Unknown`] condition.
+struct UnknownVisitor<E>(PhantomData<E>);
+
+impl<'de, E> Visitor<'de> for UnknownVisitor<E>
+{
+	type Value = Unknown<E>;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+	{
+		formatter.write_str("a map with one key")
+	}
+
+	fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+	where
+		A: MapAccess<'de>,
+	{
+		let key = map
+			.next_key::<String>()?
+			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+		map.next_value::<IgnoredAny>()?;
+
+		if map.next_key::<IgnoredAny>()?.is_some()
+		{
+			return Err(de::Error::invalid_length(2, &self));
+		}
+
+		Ok(Unknown {
+			conditions: PhantomData,
+			key,
+		})
+	}
+}
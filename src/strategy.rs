@@ -0,0 +1,75 @@
+mod record_strategy;
+mod recursion;
+mod value;
+
+use core::{borrow::Borrow, fmt::Debug};
+
+use proptest::{
+	prelude::{BoxedStrategy, Strategy},
+	strategy::Union,
+};
+pub use record_strategy::RecordStrategy;
+pub use recursion::Recursion;
+pub(crate) use value::Value;
+
+use crate::{Compile, IsMatch, Satisfy};
+
+/// Records from the `records` which match the `matcher`, replacing any which do not with an example
+/// which does. [`None`] if no example could be found.
+pub(crate) fn matching<M, Q>(
+	matcher: &M,
+	records: BoxedStrategy<M::Record>,
+) -> Option<BoxedStrategy<M::Record>>
+where
+	M: Compile + Satisfy,
+	M::Compiled: IsMatch<Q> + 'static,
+	M::Record: Borrow<Q> + Clone + Debug + 'static,
+	Q: ?Sized,
+{
+	let compiled = matcher.compile().ok()?;
+	let example = matcher.satisfy().example()?;
+	Some(
+		records
+			.prop_map(move |record| match compiled.is_match(record.borrow())
+			{
+				true => record,
+				false => example.clone(),
+			})
+			.boxed(),
+	)
+}
+
+/// Pick from any of the `strategies` which exist. [`None`] if none do.
+pub(crate) fn union<T>(
+	strategies: impl IntoIterator<Item = Option<BoxedStrategy<T>>>,
+) -> Option<BoxedStrategy<T>>
+where
+	T: Debug + 'static,
+{
+	let strategies: Vec<_> = strategies.into_iter().flatten().collect();
+	match strategies.is_empty()
+	{
+		true => None,
+		false => Some(Union::new(strategies).boxed()),
+	}
+}
+
+/// Records from the `records` whose field is `set` to one of the `values`, if there are any.
+pub(crate) fn with<R, V>(
+	records: BoxedStrategy<R>,
+	values: Option<BoxedStrategy<V>>,
+	set: fn(&mut R, V),
+) -> Option<BoxedStrategy<R>>
+where
+	R: Debug + 'static,
+	V: Debug + 'static,
+{
+	values.map(|values| {
+		(records, values)
+			.prop_map(move |(mut record, value)| {
+				set(&mut record, value);
+				record
+			})
+			.boxed()
+	})
+}
@@ -0,0 +1,65 @@
+use proptest::prelude::BoxedStrategy;
+
+use crate::Satisfy;
+
+/// A matcher which can generate records that match it, and records that do not, for
+/// property-based testing with [`proptest`].
+///
+/// Records which match are built from the records which match each field, and records which do
+/// not match are built by changing one field of a record so that it does not match. Each field is
+/// generated at random, and replaced with an [example](Satisfy) when it does not match, so any
+/// matcher which can be [satisfied](Satisfy) can generate records.
+///
+/// This is useful for testing that an adapter (e.g. one which translates matchers into SQL)
+/// selects the same records as [`IsMatch`](crate::IsMatch) does in memory.
+///
+/// # Examples
+///
+/// ```rust
+/// use clinvoice_match::{Compile, IsMatch, MatchEmployee, Recursion, RecordStrategy};
+/// use proptest::{
+///   prelude::{any_with, Strategy},
+///   strategy::ValueTree,
+///   test_runner::TestRunner,
+/// };
+///
+/// let mut runner = TestRunner::deterministic();
+/// for _ in 0..32
+/// {
+///   let condition = any_with::<MatchEmployee>(Recursion::default())
+///     .new_tree(&mut runner)
+///     .unwrap()
+///     .current();
+///
+///   let compiled = condition.compile().unwrap();
+///   if let Some(matching) = condition.matching()
+///   {
+///     let employee = matching.new_tree(&mut runner).unwrap().current();
+///     assert!(compiled.is_match(&employee));
+///   }
+///
+///   if let Some(not_matching) = condition.not_matching()
+///   {
+///     let employee = not_matching.new_tree(&mut runner).unwrap().current();
+///     assert!(!compiled.is_match(&employee));
+///   }
+/// }
+/// ```
+pub trait RecordStrategy: Satisfy
+{
+	/// Generate records which match `self`.
+	///
+	/// [`None`] if no record which matches `self` could be found.
+	fn matching(&self) -> Option<BoxedStrategy<Self::Record>>;
+
+	/// Generate records which do not match `self`.
+	///
+	/// [`None`] if no record which does not match `self` could be found (e.g. because `self` matches
+	/// every record).
+	fn not_matching(&self) -> Option<BoxedStrategy<Self::Record>>;
+
+	/// Generate any record, whether it matches or not.
+	fn records() -> BoxedStrategy<Self::Record>
+	where
+		Self: Sized;
+}
@@ -0,0 +1,71 @@
+mod default;
+
+use core::fmt::Debug;
+
+use proptest::{
+	collection,
+	prelude::{BoxedStrategy, Strategy},
+	prop_oneof,
+};
+
+/// How deeply the [`Arbitrary`](proptest::arbitrary::Arbitrary) matchers of this crate nest
+/// conditions (e.g. [`Match::And`](crate::Match::And)) and
+/// [locations](crate::MatchOuterLocation::Some) inside one another.
+///
+/// # Examples
+///
+/// ```rust
+/// use clinvoice_match::{Match, Recursion};
+/// use proptest::prelude::{any_with, Strategy};
+///
+/// let flat = any_with::<Match<i64>>(Recursion { depth: 0, ..Default::default() });
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Recursion
+{
+	/// The greatest number of conditions which a [`Match::And`](crate::Match::And) (or similar)
+	/// contains.
+	pub branches: u32,
+
+	/// The greatest number of conditions or locations which are nested inside one another.
+	pub depth: u32,
+
+	/// The number of conditions which a matcher should contain, on average.
+	pub size: u32,
+}
+
+impl Recursion
+{
+	/// The [`Recursion`] of a matcher which is nested inside of another.
+	pub(crate) const fn deeper(self) -> Self
+	{
+		Self {
+			depth: self.depth.saturating_sub(1),
+			..self
+		}
+	}
+
+	/// Nest the conditions from the `leaf` strategy inside of conditions which require `all`,
+	/// `not`, or `any` of them to match.
+	pub(crate) fn nest<T>(
+		self,
+		leaf: BoxedStrategy<T>,
+		all: fn(Vec<T>) -> T,
+		not: fn(Box<T>) -> T,
+		any: fn(Vec<T>) -> T,
+	) -> BoxedStrategy<T>
+	where
+		T: Debug + 'static,
+	{
+		let branches = self.branches as usize;
+		leaf
+			.prop_recursive(self.depth, self.size, self.branches, move |inner| {
+				prop_oneof![
+					collection::vec(inner.clone(), 0..=branches).prop_map(all),
+					inner.clone().prop_map(move |c| not(c.into())),
+					collection::vec(inner, 0..=branches).prop_map(any),
+				]
+			})
+			.boxed()
+	}
+}
@@ -0,0 +1,13 @@
+use super::Recursion;
+
+impl Default for Recursion
+{
+	fn default() -> Self
+	{
+		Self {
+			branches: 3,
+			depth: 2,
+			size: 8,
+		}
+	}
+}
@@ -0,0 +1,84 @@
+use core::{fmt::Debug, time::Duration};
+
+use clinvoice_schema::{
+	chrono::{NaiveDate, NaiveDateTime},
+	Currency,
+	Id,
+	Money,
+};
+use humantime_serde::Serde;
+use proptest::prelude::{BoxedStrategy, Strategy};
+
+/// A type whose values can be generated, both as records and as the values which conditions
+/// compare against.
+///
+/// Values are drawn from a narrow range, so that the values of records often coincide with those
+/// of conditions.
+pub(crate) trait Value: Clone + Debug + Sized + 'static
+{
+	/// Generate values of this type.
+	fn strategy() -> BoxedStrategy<Self>;
+}
+
+impl Value for Duration
+{
+	/// Whole minutes, up to one day.
+	fn strategy() -> BoxedStrategy<Self>
+	{
+		(0..=24 * 60_u64)
+			.prop_map(|minutes| Self::from_secs(minutes * 60))
+			.boxed()
+	}
+}
+
+impl Value for Id
+{
+	fn strategy() -> BoxedStrategy<Self>
+	{
+		(0..16_i64).boxed()
+	}
+}
+
+impl Value for Money
+{
+	/// Amounts in a single [`Currency`], since amounts in different currencies cannot be compared
+	/// without exchange rates.
+	fn strategy() -> BoxedStrategy<Self>
+	{
+		(-20_00..=20_00_i64)
+			.prop_map(|amount| Self::new(amount, 2, Currency::Usd))
+			.boxed()
+	}
+}
+
+impl Value for NaiveDateTime
+{
+	/// Whole minutes, from 2020 through 2024.
+	fn strategy() -> BoxedStrategy<Self>
+	{
+		(2020..2025, 1..=12_u32, 1..=28_u32, 0..24_u32, 0..60_u32)
+			.prop_filter_map("not a valid date", |(year, month, day, hour, minute)| {
+				NaiveDate::from_ymd_opt(year, month, day).and_then(|d| d.and_hms_opt(hour, minute, 0))
+			})
+			.boxed()
+	}
+}
+
+impl<T> Value for Serde<T>
+where
+	T: Value,
+	Self: Debug,
+{
+	fn strategy() -> BoxedStrategy<Self>
+	{
+		T::strategy().prop_map(Self::from).boxed()
+	}
+}
+
+impl Value for String
+{
+	fn strategy() -> BoxedStrategy<Self>
+	{
+		"[a-c]{0,3}".boxed()
+	}
+}
@@ -0,0 +1,190 @@
+mod parameters;
+mod placeholder;
+
+use core::marker::PhantomData;
+use std::collections::HashSet;
+
+pub use parameters::Parameters;
+use placeholder::Segment;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_yaml::Value;
+
+use crate::{Error, Limits, Result};
+
+/// A matcher of type `M` (e.g. a [`MatchTimesheet`](crate::MatchTimesheet)) where some values are
+/// placeholders (e.g. `$start`) that are filled in by [binding](Template::bind) [`Parameters`].
+///
+/// Placeholders are strings which start with a `$` and are followed by a name made of ASCII letters,
+/// digits, and underscores. To write a string which starts with a `$` without it being a
+/// placeholder, start it with `$$` instead (e.g. `$$5` is the string "$5").
+///
+/// # Examples
+///
+/// ```rust
+/// use clinvoice_match::{Limits, Match, MatchTimesheet, Parameters, Template};
+/// use clinvoice_schema::chrono::NaiveDate;
+/// # use pretty_assertions::assert_eq;
+///
+/// let template: Template<MatchTimesheet> = serde_yaml::from_str("
+///   employee:
+///     id:
+///       equal_to: $employee_id
+///   time_begin:
+///     greater_than: $start
+/// ").unwrap();
+///
+/// let start = NaiveDate::from_ymd_opt(2022, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+/// let mut parameters = Parameters::new();
+/// parameters.insert("employee_id", 3).unwrap().insert("start", start).unwrap();
+///
+/// let condition = template.bind(&parameters, &Limits::default()).unwrap();
+/// assert_eq!(condition.employee.id, Match::EqualTo(3));
+/// assert_eq!(condition.time_begin, Match::GreaterThan(start));
+///
+/// parameters.insert("employee_id", "Bob").unwrap();
+/// assert_eq!(
+///   template.bind(&parameters, &Limits::default()).unwrap_err().to_string(),
+///   "the value of parameter `employee_id` has the wrong type at `employee.id`: invalid type: \
+///   string \"Bob\", expected i64",
+/// );
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(bound = "", transparent)]
+pub struct Template<M>
+{
+	/// The matcher, including its placeholders.
+	value: Value,
+
+	/// The type of matcher which the template is bound to.
+	#[serde(skip)]
+	matcher: PhantomData<fn() -> M>,
+}
+
+impl<M> Template<M>
+{
+	/// Create a [`Template`] from a `value` which may contain placeholders.
+	pub const fn new(value: Value) -> Self
+	{
+		Self {
+			value,
+			matcher: PhantomData,
+		}
+	}
+
+	/// Replace each placeholder with the value of the corresponding [`Parameters`], check that the
+	/// result is within the `limits`, and then deserialize it.
+	///
+	/// # Errors
+	///
+	/// * When a placeholder does not have a value in the `parameters`.
+	/// * When the `parameters` contain a value which does not have a placeholder.
+	/// * When the template is not within the `limits` once the `parameters` are bound (see
+	///   [`Limits::check`]).
+	/// * When the value of a parameter has the wrong type for where its placeholder is used.
+	/// * When the template is not a valid `M`, regardless of the `parameters`.
+	///
+	/// Sometimes the location of an error is not precise enough to tell which placeholder caused it
+	/// (e.g. an error in `outer: {id: $id, name: $name}` is reported at `outer`). In that case, each
+	/// placeholder is checked in turn, with the fields below that location which contain the other
+	/// placeholders removed, and the first one which is still not valid is reported. Placeholders
+	/// within the same field (e.g. `in_range: [$start, $end]`) cannot be removed separately, so the
+	/// parameter being checked is substituted for each of them instead, since the values of such a
+	/// field all have the same type.
+	pub fn bind(&self, parameters: &Parameters, limits: &Limits) -> Result<M>
+	where
+		M: DeserializeOwned,
+	{
+		let placeholders = placeholder::find(&self.value);
+
+		if let Some((_, name)) = placeholders
+			.iter()
+			.find(|(_, name)| parameters.get(name).is_none())
+		{
+			return Err(Error::MissingParameter {
+				parameter: name.clone(),
+			});
+		}
+
+		if let Some(name) = parameters.names().find(|name| {
+			placeholders
+				.iter()
+				.all(|(_, placeholder)| placeholder != name)
+		})
+		{
+			return Err(Error::UnknownParameter {
+				parameter: name.into(),
+			});
+		}
+
+		let bound = placeholder::substitute(&self.value, &|name| parameters.get(name).cloned());
+		limits.check_value(&bound)?;
+
+		let error = match serde_path_to_error::deserialize(bound)
+		{
+			Ok(matcher) => return Ok(matcher),
+			Err(e) => e,
+		};
+
+		let path = Segment::from_path(error.path());
+		let candidates: Vec<_> = placeholders
+			.iter()
+			.filter(|(location, _)| location.starts_with(&path))
+			.collect();
+
+		let Some((_, first)) = candidates.first()
+		else
+		{
+			return Err(Error::Yaml(error.into_inner()));
+		};
+
+		let parameter = candidates
+			.iter()
+			.find(|(location, candidate)| {
+				let branch = location.get(path.len());
+				let others: Vec<_> = candidates
+					.iter()
+					.filter_map(|(other, _)| other.get(path.len()))
+					.filter(|other| Some(*other) != branch)
+					.collect();
+
+				let field = placeholder::field(location);
+				let value = parameters.get(candidate).cloned();
+				let substituted = placeholder::substitute(
+					&placeholder::without(&self.value, &path, &others),
+					&|name| {
+						if placeholders
+							.iter()
+							.any(|(l, n)| n == name && placeholder::field(l) == field)
+						{
+							value.clone()
+						}
+						else
+						{
+							parameters.get(name).cloned()
+						}
+					},
+				);
+
+				serde_yaml::from_value::<M>(substituted).is_err()
+			})
+			.map_or(first, |(_, name)| name);
+
+		Err(Error::ParameterType {
+			parameter: parameter.clone(),
+			path: error.path().to_string(),
+			reason: error.into_inner().to_string(),
+		})
+	}
+
+	/// The names of the placeholders in this template, in the order they appear.
+	pub fn parameters(&self) -> Vec<String>
+	{
+		let mut names: Vec<_> = placeholder::find(&self.value)
+			.into_iter()
+			.map(|(_, name)| name)
+			.collect();
+		let mut seen = HashSet::new();
+		names.retain(|name| seen.insert(name.clone()));
+		names
+	}
+}
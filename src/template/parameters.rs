@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_yaml::Value;
+
+use crate::Result;
+
+/// The values of the placeholders in a [`Template`](super::Template), by name.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Parameters
+{
+	/// The value of each parameter.
+	values: HashMap<String, Value>,
+}
+
+impl Parameters
+{
+	/// Create [`Parameters`] without any values.
+	pub fn new() -> Self
+	{
+		Self::default()
+	}
+
+	/// Get the value of the parameter with some `name`.
+	pub fn get(&self, name: &str) -> Option<&Value>
+	{
+		self.values.get(name)
+	}
+
+	/// Set the `value` of the parameter with some `name` (without the leading `$`), replacing any
+	/// previous value.
+	///
+	/// # Errors
+	///
+	/// * When the `value` cannot be serialized.
+	pub fn insert<T>(&mut self, name: impl Into<String>, value: T) -> Result<&mut Self>
+	where
+		T: Serialize,
+	{
+		self
+			.values
+			.insert(name.into(), serde_yaml::to_value(value)?);
+		Ok(self)
+	}
+
+	/// The names of every parameter which has a value.
+	pub fn names(&self) -> impl Iterator<Item = &str>
+	{
+		self.values.keys().map(String::as_str)
+	}
+}
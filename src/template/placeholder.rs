@@ -0,0 +1,145 @@
+use serde_path_to_error::Path;
+use serde_yaml::Value;
+
+/// One step of the path from the root of a [`Value`] to some value within it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) enum Segment
+{
+	/// An index of a [`Value::Sequence`].
+	Index(usize),
+
+	/// A key of a [`Value::Mapping`], or the variant of an enum.
+	Key(String),
+}
+
+impl Segment
+{
+	/// Convert the `path` where an error occurred into [`Segment`]s, ending at the first segment
+	/// whose location is unknown.
+	pub(super) fn from_path(path: &Path) -> Vec<Self>
+	{
+		path
+			.iter()
+			.map_while(|segment| match segment
+			{
+				serde_path_to_error::Segment::Enum { variant } => Some(Self::Key(variant.clone())),
+				serde_path_to_error::Segment::Map { key } => Some(Self::Key(key.clone())),
+				serde_path_to_error::Segment::Seq { index } => Some(Self::Index(*index)),
+				serde_path_to_error::Segment::Unknown => None,
+			})
+			.collect()
+	}
+}
+
+/// The location of the field which contains the placeholder at some `location`, which excludes
+/// the indices of any sequences that the placeholder is in (e.g. `in_range: [$start, $end]`).
+pub(super) fn field(location: &[Segment]) -> &[Segment]
+{
+	let end = location
+		.iter()
+		.rposition(|segment| matches!(segment, Segment::Key(_)))
+		.map_or(0, |index| index + 1);
+	&location[..end]
+}
+
+/// Find the location and name of every placeholder in the `value`, in the order they appear.
+pub(super) fn find(value: &Value) -> Vec<(Vec<Segment>, String)>
+{
+	let mut placeholders = Vec::new();
+	find_at(value, &mut Vec::new(), &mut placeholders);
+	placeholders
+}
+
+/// Find the placeholders in some `value` which is located at the `path`, adding them to
+/// `placeholders`.
+fn find_at(value: &Value, path: &mut Vec<Segment>, placeholders: &mut Vec<(Vec<Segment>, String)>)
+{
+	match value
+	{
+		Value::Mapping(mapping) => mapping.iter().for_each(|(key, value)| {
+			path.push(Segment::Key(
+				key.as_str().map_or_else(|| format!("{key:?}"), Into::into),
+			));
+			find_at(value, path, placeholders);
+			path.pop();
+		}),
+		Value::Sequence(sequence) => sequence.iter().enumerate().for_each(|(index, value)| {
+			path.push(Segment::Index(index));
+			find_at(value, path, placeholders);
+			path.pop();
+		}),
+		Value::String(s) =>
+		{
+			if let Some(name) = name(s)
+			{
+				placeholders.push((path.clone(), name.into()));
+			}
+		},
+		_ => (),
+	}
+}
+
+/// Get the name of the placeholder `s`, if it is one.
+fn name(s: &str) -> Option<&str>
+{
+	s.strip_prefix('$').filter(|name| {
+		!name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+	})
+}
+
+/// Replace each placeholder in the `value` with the result of `get`ting its name, and each string
+/// that starts with `$$` with the same string starting with `$`.
+///
+/// Placeholders which `get` does not return a value for are left as-is.
+pub(super) fn substitute(value: &Value, get: &dyn Fn(&str) -> Option<Value>) -> Value
+{
+	match value
+	{
+		Value::Mapping(mapping) => Value::Mapping(
+			mapping
+				.iter()
+				.map(|(key, value)| (key.clone(), substitute(value, get)))
+				.collect(),
+		),
+		Value::Sequence(sequence) => Value::Sequence(
+			sequence
+				.iter()
+				.map(|value| substitute(value, get))
+				.collect(),
+		),
+		Value::String(s) => match s.strip_prefix("$$")
+		{
+			Some(_) => Value::String(s[1..].into()),
+			None => name(s).and_then(get).unwrap_or_else(|| value.clone()),
+		},
+		_ => value.clone(),
+	}
+}
+
+/// Copy the `value`, and remove each of the `keys` from the mapping located at the `path` of the
+/// copy (if there is one).
+pub(super) fn without(value: &Value, path: &[Segment], keys: &[&Segment]) -> Value
+{
+	let mut copy = value.clone();
+
+	let mapping = path
+		.iter()
+		.try_fold(&mut copy, |value, segment| match (value, segment)
+		{
+			(Value::Mapping(mapping), Segment::Key(key)) => mapping.get_mut(&key.as_str().into()),
+			(Value::Sequence(sequence), Segment::Index(index)) => sequence.get_mut(*index),
+			_ => None,
+		});
+
+	if let Some(Value::Mapping(mapping)) = mapping
+	{
+		keys.iter().for_each(|key| {
+			if let Segment::Key(key) = key
+			{
+				mapping.remove(&key.as_str().into());
+			}
+		});
+	}
+
+	copy
+}
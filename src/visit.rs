@@ -0,0 +1,108 @@
+mod fold;
+mod visit_mut;
+
+use core::time::Duration;
+
+use clinvoice_schema::{chrono::NaiveDateTime, Id, Money};
+pub use fold::{Fold, Folder};
+use humantime_serde::Serde;
+pub use visit_mut::{VisitMut, VisitorMut};
+
+use crate::{FieldPath, Match, MatchOption, MatchStr};
+
+/// A matcher which can be walked by a [`Visitor`].
+///
+/// Every leaf condition (e.g. the [`MatchStr`] in the `name` of a
+/// [`MatchEmployee`](crate::MatchEmployee)) is reported to the [`Visitor`] along with the
+/// [`FieldPath`] which leads to it.
+///
+/// # Examples
+///
+/// ```rust
+/// use clinvoice_match::{
+///   FieldPath,
+///   MatchJob,
+///   MatchLocation,
+///   MatchOuterLocation,
+///   MatchStr,
+///   Visit,
+///   Visitor,
+/// };
+/// # use pretty_assertions::assert_eq;
+///
+/// /// Collects the path of every string field which is being matched.
+/// #[derive(Default)]
+/// struct StrPaths(Vec<String>);
+///
+/// impl Visitor for StrPaths {
+///   fn visit_match_str(&mut self, path: &FieldPath, condition: &MatchStr<String>) {
+///     if *condition != MatchStr::Any {
+///       self.0.push(path.to_string());
+///     }
+///   }
+/// }
+///
+/// let mut job = MatchJob::default();
+/// job.client.location.outer = MatchOuterLocation::Some(Box::new(MatchLocation {
+///   name: "Sweden".to_owned().into(),
+///   ..Default::default()
+/// }));
+/// job.notes = MatchStr::Contains("overtime".into());
+///
+/// let mut paths = StrPaths::default();
+/// job.visit(&mut paths);
+/// assert_eq!(paths.0, ["client.location.outer.name", "notes"]);
+/// ```
+pub trait Visit
+{
+	/// Walk `self` with the `visitor`, starting from the root [`FieldPath`].
+	fn visit<V>(&self, visitor: &mut V)
+	where
+		V: Visitor + ?Sized,
+	{
+		self.visit_at(&FieldPath::default(), visitor);
+	}
+
+	/// Walk `self` with the `visitor`, as if `self` were located at the `path`.
+	fn visit_at<V>(&self, path: &FieldPath, visitor: &mut V)
+	where
+		V: Visitor + ?Sized;
+}
+
+/// Receives each leaf condition of a matcher which implements [`Visit`].
+///
+/// Every method does nothing by default, so only the methods for the leaf conditions which are
+/// relevant need to be implemented.
+pub trait Visitor
+{
+	/// Visit a condition on a date (e.g. `date_open` of a [`MatchJob`](crate::MatchJob)).
+	fn visit_match_date(&mut self, _path: &FieldPath, _condition: &Match<NaiveDateTime>) {}
+
+	/// Visit a condition on a [`Duration`] (e.g. `increment` of a [`MatchJob`](crate::MatchJob)).
+	fn visit_match_duration(&mut self, _path: &FieldPath, _condition: &Match<Serde<Duration>>) {}
+
+	/// Visit a condition on an [`Id`].
+	fn visit_match_id(&mut self, _path: &FieldPath, _condition: &Match<Id>) {}
+
+	/// Visit a condition on [`Money`] (e.g. `cost` of a [`MatchExpense`](crate::MatchExpense)).
+	fn visit_match_money(&mut self, _path: &FieldPath, _condition: &Match<Money>) {}
+
+	/// Visit a condition on an optional date (e.g. `time_end` of a
+	/// [`MatchTimesheet`](crate::MatchTimesheet)).
+	fn visit_match_option_date(&mut self, _path: &FieldPath, _condition: &MatchOption<NaiveDateTime>)
+	{
+	}
+
+	/// Visit a condition on an optional [`Duration`] (e.g. `duration` of a
+	/// [`MatchTimesheet`](crate::MatchTimesheet)).
+	fn visit_match_option_duration(
+		&mut self,
+		_path: &FieldPath,
+		_condition: &MatchOption<Serde<Duration>>,
+	)
+	{
+	}
+
+	/// Visit a condition on a string (e.g. `name` of a [`MatchEmployee`](crate::MatchEmployee)).
+	fn visit_match_str(&mut self, _path: &FieldPath, _condition: &MatchStr<String>) {}
+}
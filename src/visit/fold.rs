@@ -0,0 +1,123 @@
+use core::time::Duration;
+
+use clinvoice_schema::{chrono::NaiveDateTime, Id, Money};
+use humantime_serde::Serde;
+
+use crate::{FieldPath, Match, MatchOption, MatchStr};
+
+/// A matcher which can be rebuilt by a [`Folder`].
+///
+/// This is the same as [`Visit`](crate::Visit), except that `self` is consumed, and each leaf
+/// condition is replaced by whatever the [`Folder`] returns.
+///
+/// # Examples
+///
+/// ```rust
+/// use clinvoice_match::{FieldPath, Fold, Folder, Match, MatchLocation, MatchOuterLocation};
+/// # use pretty_assertions::assert_eq;
+///
+/// /// Makes every `id` condition match exactly one value.
+/// struct PinIds(i64);
+///
+/// impl Folder for PinIds {
+///   fn fold_match_id(&mut self, _: &FieldPath, _: Match<i64>) -> Match<i64> {
+///     Match::EqualTo(self.0)
+///   }
+/// }
+///
+/// let location = MatchLocation {
+///   outer: MatchOuterLocation::Some(Box::new(MatchLocation::default())),
+///   ..Default::default()
+/// };
+///
+/// assert_eq!(
+///   location.fold(&mut PinIds(7)),
+///   MatchLocation {
+///     id: 7.into(),
+///     outer: MatchOuterLocation::Some(Box::new(7.into())),
+///     ..Default::default()
+///   },
+/// );
+/// ```
+pub trait Fold
+{
+	/// Rebuild `self` with the `folder`, starting from the root [`FieldPath`].
+	fn fold<F>(self, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized,
+		Self: Sized,
+	{
+		self.fold_at(&FieldPath::default(), folder)
+	}
+
+	/// Rebuild `self` with the `folder`, as if `self` were located at the `path`.
+	fn fold_at<F>(self, path: &FieldPath, folder: &mut F) -> Self
+	where
+		F: Folder + ?Sized;
+}
+
+/// Replaces each leaf condition of a matcher which implements [`Fold`].
+///
+/// Every method returns the `condition` it was given by default, so only the methods for the leaf
+/// conditions which are relevant need to be implemented.
+pub trait Folder
+{
+	/// Same as [`Visitor::visit_match_date`](crate::Visitor::visit_match_date).
+	fn fold_match_date(
+		&mut self,
+		_path: &FieldPath,
+		condition: Match<NaiveDateTime>,
+	) -> Match<NaiveDateTime>
+	{
+		condition
+	}
+
+	/// Same as [`Visitor::visit_match_duration`](crate::Visitor::visit_match_duration).
+	fn fold_match_duration(
+		&mut self,
+		_path: &FieldPath,
+		condition: Match<Serde<Duration>>,
+	) -> Match<Serde<Duration>>
+	{
+		condition
+	}
+
+	/// Same as [`Visitor::visit_match_id`](crate::Visitor::visit_match_id).
+	fn fold_match_id(&mut self, _path: &FieldPath, condition: Match<Id>) -> Match<Id>
+	{
+		condition
+	}
+
+	/// Same as [`Visitor::visit_match_money`](crate::Visitor::visit_match_money).
+	fn fold_match_money(&mut self, _path: &FieldPath, condition: Match<Money>) -> Match<Money>
+	{
+		condition
+	}
+
+	/// Same as [`Visitor::visit_match_option_date`](crate::Visitor::visit_match_option_date).
+	fn fold_match_option_date(
+		&mut self,
+		_path: &FieldPath,
+		condition: MatchOption<NaiveDateTime>,
+	) -> MatchOption<NaiveDateTime>
+	{
+		condition
+	}
+
+	/// Same as [`Visitor::visit_match_option_duration`](crate::Visitor::visit_match_option_duration).
+	fn fold_match_option_duration(
+		&mut self,
+		_path: &FieldPath,
+		condition: MatchOption<Serde<Duration>>,
+	) -> MatchOption<Serde<Duration>>
+	{
+		condition
+	}
+
+	/// Same as [`Visitor::visit_match_str`](crate::Visitor::visit_match_str).
+	fn fold_match_str(&mut self, _path: &FieldPath, condition: MatchStr<String>)
+		-> MatchStr<String>
+	{
+		condition
+	}
+}
@@ -0,0 +1,97 @@
+use core::time::Duration;
+
+use clinvoice_schema::{chrono::NaiveDateTime, Id, Money};
+use humantime_serde::Serde;
+
+use crate::{FieldPath, Match, MatchOption, MatchStr};
+
+/// A matcher which can be walked by a [`VisitorMut`].
+///
+/// This is the same as [`Visit`](crate::Visit), except that the [`VisitorMut`] may modify each
+/// leaf condition in-place.
+///
+/// # Examples
+///
+/// ```rust
+/// use clinvoice_match::{FieldPath, MatchEmployee, MatchStr, VisitMut, VisitorMut};
+/// # use pretty_assertions::assert_eq;
+///
+/// /// Removes all string conditions from a matcher.
+/// struct Redact;
+///
+/// impl VisitorMut for Redact {
+///   fn visit_match_str_mut(&mut self, _: &FieldPath, condition: &mut MatchStr<String>) {
+///     *condition = MatchStr::Any;
+///   }
+/// }
+///
+/// let mut employee = MatchEmployee {
+///   id: 3.into(),
+///   name: "Bob".to_owned().into(),
+///   ..Default::default()
+/// };
+///
+/// employee.visit_mut(&mut Redact);
+/// assert_eq!(employee, MatchEmployee { id: 3.into(), ..Default::default() });
+/// ```
+pub trait VisitMut
+{
+	/// Walk `self` with the `visitor`, starting from the root [`FieldPath`].
+	fn visit_mut<V>(&mut self, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized,
+	{
+		self.visit_mut_at(&FieldPath::default(), visitor);
+	}
+
+	/// Walk `self` with the `visitor`, as if `self` were located at the `path`.
+	fn visit_mut_at<V>(&mut self, path: &FieldPath, visitor: &mut V)
+	where
+		V: VisitorMut + ?Sized;
+}
+
+/// Receives each leaf condition of a matcher which implements [`VisitMut`].
+///
+/// Every method does nothing by default, so only the methods for the leaf conditions which are
+/// relevant need to be implemented.
+pub trait VisitorMut
+{
+	/// Same as [`Visitor::visit_match_date`](crate::Visitor::visit_match_date).
+	fn visit_match_date_mut(&mut self, _path: &FieldPath, _condition: &mut Match<NaiveDateTime>) {}
+
+	/// Same as [`Visitor::visit_match_duration`](crate::Visitor::visit_match_duration).
+	fn visit_match_duration_mut(
+		&mut self,
+		_path: &FieldPath,
+		_condition: &mut Match<Serde<Duration>>,
+	)
+	{
+	}
+
+	/// Same as [`Visitor::visit_match_id`](crate::Visitor::visit_match_id).
+	fn visit_match_id_mut(&mut self, _path: &FieldPath, _condition: &mut Match<Id>) {}
+
+	/// Same as [`Visitor::visit_match_money`](crate::Visitor::visit_match_money).
+	fn visit_match_money_mut(&mut self, _path: &FieldPath, _condition: &mut Match<Money>) {}
+
+	/// Same as [`Visitor::visit_match_option_date`](crate::Visitor::visit_match_option_date).
+	fn visit_match_option_date_mut(
+		&mut self,
+		_path: &FieldPath,
+		_condition: &mut MatchOption<NaiveDateTime>,
+	)
+	{
+	}
+
+	/// Same as [`Visitor::visit_match_option_duration`](crate::Visitor::visit_match_option_duration).
+	fn visit_match_option_duration_mut(
+		&mut self,
+		_path: &FieldPath,
+		_condition: &mut MatchOption<Serde<Duration>>,
+	)
+	{
+	}
+
+	/// Same as [`Visitor::visit_match_str`](crate::Visitor::visit_match_str).
+	fn visit_match_str_mut(&mut self, _path: &FieldPath, _condition: &mut MatchStr<String>) {}
+}
@@ -0,0 +1,284 @@
+mod common;
+
+use core::time::Duration;
+
+use clinvoice_match::{
+	Aggregate,
+	Aggregation,
+	Error,
+	FieldValue,
+	Group,
+	Match,
+	MatchExpense,
+	MatchTimesheet,
+};
+use clinvoice_schema::{Currency, Employee, Expense, Job, Money, Timesheet};
+use common::time;
+use money2::ExchangeRates;
+use pretty_assertions::assert_eq;
+
+/// Several [`Timesheet`]s by two [`Employee`]s, one of which has not ended.
+fn timesheets() -> Vec<Timesheet>
+{
+	let timesheet = |id, employee: &str, time_begin, time_end| Timesheet {
+		employee: Employee {
+			id: employee.len() as i64,
+			name: employee.into(),
+			..Default::default()
+		},
+		id,
+		..common::timesheet(time_begin, time_end)
+	};
+
+	vec![
+		timesheet(1, "Bob", time(8, 0), Some(time(9, 30))),
+		timesheet(2, "Alice", time(9, 0), Some(time(10, 0))),
+		timesheet(3, "Bob", time(10, 0), Some(time(12, 15))),
+		timesheet(4, "Alice", time(13, 0), None),
+	]
+}
+
+#[test]
+fn hours_per_employee()
+{
+	let aggregation = Aggregation {
+		aggregates: vec![
+			Aggregate::Sum("duration".parse().unwrap()),
+			Aggregate::Min("time_begin".parse().unwrap()),
+			Aggregate::Max("time_end".parse().unwrap()),
+		],
+		group_by: vec!["employee.name".parse().unwrap()],
+		..Aggregation::new(MatchTimesheet::default(), Currency::Usd)
+	};
+
+	assert_eq!(
+		aggregation
+			.apply(&timesheets(), &ExchangeRates::default())
+			.unwrap(),
+		[
+			Group {
+				count: 2,
+				key: vec!["Alice".into()],
+				values: vec![
+					Duration::from_secs(60 * 60).into(),
+					time(9, 0).into(),
+					time(10, 0).into(),
+				],
+			},
+			Group {
+				count: 2,
+				key: vec!["Bob".into()],
+				values: vec![
+					Duration::from_secs(60 * (90 + 135)).into(),
+					time(8, 0).into(),
+					time(12, 15).into(),
+				],
+			},
+		]
+	);
+}
+
+#[test]
+fn ungrouped()
+{
+	let aggregation = Aggregation {
+		aggregates: vec![Aggregate::Sum("duration".parse().unwrap())],
+		..Aggregation::new(
+			MatchTimesheet {
+				id: Match::GreaterThan(2),
+				..Default::default()
+			},
+			Currency::Usd,
+		)
+	};
+
+	assert_eq!(
+		aggregation
+			.apply(&timesheets(), &ExchangeRates::default())
+			.unwrap(),
+		[Group {
+			count: 2,
+			key: Vec::new(),
+			values: vec![Duration::from_secs(60 * 135).into()],
+		}]
+	);
+
+	let aggregation = Aggregation {
+		condition: MatchTimesheet {
+			id: Match::GreaterThan(4),
+			..Default::default()
+		},
+		..aggregation
+	};
+
+	assert_eq!(
+		aggregation
+			.apply(&timesheets(), &ExchangeRates::default())
+			.unwrap(),
+		[Group {
+			count: 0,
+			key: Vec::new(),
+			values: vec![FieldValue::Null],
+		}]
+	);
+}
+
+#[test]
+fn cost_per_category()
+{
+	let expense = |category: &str, cents, timesheet_id| Expense {
+		category: category.into(),
+		cost: Money::new(cents, 2, Currency::Usd),
+		timesheet_id,
+		..Default::default()
+	};
+
+	let expenses = [
+		expense("Food", 12_50, 1),
+		expense("Travel", 40_00, 1),
+		expense("Food", 7_25, 2),
+		expense("Lodging", 10_000, 3),
+	];
+
+	let aggregation = Aggregation {
+		aggregates: vec![Aggregate::Sum("cost".parse().unwrap())],
+		group_by: vec!["category".parse().unwrap()],
+		..Aggregation::new(
+			MatchExpense {
+				timesheet_id: Match::LessThan(3),
+				..Default::default()
+			},
+			Currency::Usd,
+		)
+	};
+
+	assert_eq!(
+		aggregation
+			.apply(&expenses, &ExchangeRates::default())
+			.unwrap(),
+		[
+			Group {
+				count: 2,
+				key: vec!["Food".into()],
+				values: vec![Money::new(19_75, 2, Currency::Usd).into()],
+			},
+			Group {
+				count: 1,
+				key: vec!["Travel".into()],
+				values: vec![Money::new(40_00, 2, Currency::Usd).into()],
+			},
+		]
+	);
+}
+
+#[test]
+fn currencies()
+{
+	let expense = |cost| Expense {
+		cost,
+		..Default::default()
+	};
+
+	// 1 EUR is worth 2 USD, so the expense in EUR is the most expensive, even though its amount is
+	// the smallest
+	let expenses = [
+		expense(Money::new(15_00, 2, Currency::Usd)),
+		expense(Money::new(10_00, 2, Currency::Eur)),
+		expense(Money::new(18_00, 2, Currency::Usd)),
+	];
+	let rates: ExchangeRates = "Date, USD\n01 January 2022, 2.0".parse().unwrap();
+
+	let aggregation = Aggregation {
+		aggregates: vec![
+			Aggregate::Max("cost".parse().unwrap()),
+			Aggregate::Min("cost".parse().unwrap()),
+			Aggregate::Sum("cost".parse().unwrap()),
+		],
+		..Aggregation::new(MatchExpense::default(), Currency::Usd)
+	};
+
+	assert_eq!(aggregation.apply(&expenses, &rates).unwrap(), [Group {
+		count: 3,
+		key: Vec::new(),
+		values: vec![
+			Money::new(20_00, 2, Currency::Usd).into(),
+			Money::new(15_00, 2, Currency::Usd).into(),
+			Money::new(53_00, 2, Currency::Usd).into(),
+		],
+	}]);
+
+	let largest = Money {
+		amount: "79228162514264337593543950335".parse().unwrap(),
+		currency: Currency::Usd,
+	};
+	assert!(matches!(
+		Aggregation {
+			aggregates: vec![Aggregate::Sum("cost".parse().unwrap())],
+			..aggregation
+		}
+		.apply(&[expense(largest), expense(largest)], &rates),
+		Err(Error::Overflow { path }) if path.to_string() == "cost"
+	));
+}
+
+#[test]
+fn duration_overflow()
+{
+	let timesheet = Timesheet {
+		job: Job {
+			increment: Duration::MAX,
+			..Default::default()
+		},
+		..Default::default()
+	};
+
+	assert!(matches!(
+		Aggregation {
+			aggregates: vec![Aggregate::Sum("job.increment".parse().unwrap())],
+			..Aggregation::new(MatchTimesheet::default(), Currency::Usd)
+		}
+		.apply(&[timesheet.clone(), timesheet], &ExchangeRates::default()),
+		Err(Error::Overflow { path }) if path.to_string() == "job.increment"
+	));
+}
+
+#[test]
+fn invalid()
+{
+	let aggregation = Aggregation {
+		aggregates: vec![Aggregate::Sum("employee.name".parse().unwrap())],
+		..Aggregation::new(MatchTimesheet::default(), Currency::Usd)
+	};
+
+	assert!(matches!(
+		aggregation.check(),
+		Err(Error::NotSummable { path }) if path.to_string() == "employee.name"
+	));
+	assert!(matches!(
+		aggregation.apply(&timesheets(), &ExchangeRates::default()),
+		Err(Error::NotSummable { .. })
+	));
+	assert!(Aggregation {
+		aggregates: vec![Aggregate::Sum("job.invoice.hourly_rate".parse().unwrap())],
+		..aggregation.clone()
+	}
+	.check()
+	.is_ok());
+
+	for aggregation in [
+		Aggregation {
+			group_by: vec!["expenses".parse().unwrap()],
+			..aggregation.clone()
+		},
+		Aggregation {
+			aggregates: vec![Aggregate::Max("employee.salary".parse().unwrap())],
+			..aggregation
+		},
+	]
+	{
+		assert!(matches!(
+			aggregation.check(),
+			Err(Error::UnknownField { .. })
+		));
+	}
+}
@@ -0,0 +1,121 @@
+//! Utilities which are shared between integration tests.
+
+// each integration test uses a different subset of these utilities
+#![allow(dead_code)]
+
+use std::{fs, path::Path};
+
+use clinvoice_schema::{
+	chrono::{NaiveDate, NaiveDateTime},
+	Timesheet,
+};
+
+/// An example of a serialized matcher which is part of the documentation of this crate.
+#[derive(Debug)]
+pub struct Example
+{
+	/// The file which the example was found in.
+	pub file: String,
+
+	/// The name of the type which the example deserializes into (e.g. `MatchEmployee`).
+	pub type_name: String,
+
+	/// The example, written in YAML.
+	pub yaml: String,
+}
+
+/// Midnight on some day.
+pub fn date(year: i32, month: u32, day: u32) -> NaiveDateTime
+{
+	NaiveDate::from_ymd_opt(year, month, day)
+		.and_then(|d| d.and_hms_opt(0, 0, 0))
+		.unwrap()
+}
+
+/// A time on 2022-01-01.
+pub fn time(hour: u32, minute: u32) -> NaiveDateTime
+{
+	NaiveDate::from_ymd_opt(2022, 1, 1)
+		.and_then(|d| d.and_hms_opt(hour, minute, 0))
+		.unwrap()
+}
+
+/// A [`Timesheet`] which began at `time_begin` and ended at `time_end`.
+pub fn timesheet(time_begin: NaiveDateTime, time_end: Option<NaiveDateTime>) -> Timesheet
+{
+	Timesheet {
+		time_begin,
+		time_end,
+		..Default::default()
+	}
+}
+
+/// Find every YAML example in the documentation of this crate.
+///
+/// Documented examples look like this:
+///
+/// ```text
+/// /// # assert!(serde_yaml::from_str::<clinvoice_match::MatchEmployee>(r#"
+/// /// status:
+/// ///   equal_to: "Hired"
+/// /// # "#).is_ok());
+/// ```
+pub fn documented_examples() -> Vec<Example>
+{
+	let mut examples = Vec::new();
+	find_examples(
+		&Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+		&mut examples,
+	);
+	examples.sort_by(|lhs, rhs| lhs.file.cmp(&rhs.file));
+	examples
+}
+
+/// Add the examples of every file in the `dir` to `examples`.
+fn find_examples(dir: &Path, examples: &mut Vec<Example>)
+{
+	fs::read_dir(dir)
+		.unwrap()
+		.map(|entry| entry.unwrap().path())
+		.for_each(|path| {
+			if path.is_dir()
+			{
+				find_examples(&path, examples);
+			}
+			else if path.extension().is_some_and(|ext| ext == "rs")
+			{
+				let contents = fs::read_to_string(&path).unwrap();
+				let mut lines = contents.lines().map(str::trim_start);
+
+				while let Some(line) = lines.next()
+				{
+					let Some(type_name) = line
+						.strip_prefix("/// # assert")
+						.and_then(|l| l.split_once("from_str::<"))
+						.and_then(|(_, l)| l.rsplit_once(">("))
+						.map(|(t, _)| t.split('<').next().unwrap().rsplit("::").next().unwrap())
+					else
+					{
+						continue;
+					};
+
+					let yaml = lines
+						.by_ref()
+						.take_while(|l| !l.starts_with("/// # \""))
+						.map(|l| {
+							l.strip_prefix("///")
+								.unwrap()
+								.strip_prefix(' ')
+								.unwrap_or_default()
+						})
+						.fold(String::new(), |yaml, l| yaml + l + "\n");
+
+					examples.push(Example {
+						file: path.display().to_string(),
+						type_name: type_name.into(),
+						yaml,
+					});
+				}
+			}
+		});
+}
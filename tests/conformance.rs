@@ -0,0 +1,210 @@
+#![cfg(feature = "testing")]
+
+use std::collections::HashSet;
+
+use clinvoice_match::{
+	conformance::{self, Adapter, AdapterResult, Case, Dataset},
+	Compile,
+	Dialect,
+	Error,
+	IsMatch,
+	MatchEmployee,
+	MatchExpense,
+	MatchJob,
+	MatchTimesheet,
+};
+use clinvoice_schema::Id;
+use pretty_assertions::assert_eq;
+
+/// An [`Adapter`] which evaluates matchers in memory, optionally retrieving a [`Timesheet`] once
+/// for each of its expenses (as a naive SQL `JOIN` would).
+#[derive(Default)]
+struct Memory
+{
+	dataset: Dataset,
+	duplicate_joins: bool,
+}
+
+impl Adapter for Memory
+{
+	fn insert(&mut self, dataset: &Dataset) -> AdapterResult<()>
+	{
+		self.dataset = dataset.clone();
+		Ok(())
+	}
+
+	fn retrieve_employees(&mut self, condition: &MatchEmployee) -> AdapterResult<Vec<Id>>
+	{
+		let compiled = condition.compile()?;
+		Ok(self
+			.dataset
+			.employees
+			.iter()
+			.filter(|e| compiled.is_match(e))
+			.map(|e| e.id)
+			.collect())
+	}
+
+	fn retrieve_expenses(&mut self, condition: &MatchExpense) -> AdapterResult<Vec<Id>>
+	{
+		let compiled = condition.compile()?;
+		Ok(self
+			.dataset
+			.expenses
+			.iter()
+			.filter(|e| compiled.is_match(e))
+			.map(|e| e.id)
+			.collect())
+	}
+
+	fn retrieve_jobs(&mut self, condition: &MatchJob) -> AdapterResult<Vec<Id>>
+	{
+		let compiled = condition.compile()?;
+		Ok(self
+			.dataset
+			.jobs
+			.iter()
+			.filter(|j| compiled.is_match(j))
+			.map(|j| j.id)
+			.collect())
+	}
+
+	fn retrieve_timesheets(&mut self, condition: &MatchTimesheet) -> AdapterResult<Vec<Id>>
+	{
+		let compiled = condition.compile()?;
+		Ok(self
+			.dataset
+			.timesheets
+			.iter()
+			.filter(|t| compiled.is_match(t))
+			.flat_map(|t| match self.duplicate_joins
+			{
+				true => vec![t.id; t.expenses.len().max(1)],
+				false => vec![t.id],
+			})
+			.collect())
+	}
+}
+
+/// An [`Adapter`] which cannot store anything.
+struct Broken;
+
+impl Adapter for Broken
+{
+	fn insert(&mut self, _: &Dataset) -> AdapterResult<()>
+	{
+		Err("the database is offline".into())
+	}
+
+	fn retrieve_employees(&mut self, _: &MatchEmployee) -> AdapterResult<Vec<Id>>
+	{
+		unreachable!()
+	}
+
+	fn retrieve_expenses(&mut self, _: &MatchExpense) -> AdapterResult<Vec<Id>>
+	{
+		unreachable!()
+	}
+
+	fn retrieve_jobs(&mut self, _: &MatchJob) -> AdapterResult<Vec<Id>>
+	{
+		unreachable!()
+	}
+
+	fn retrieve_timesheets(&mut self, _: &MatchTimesheet) -> AdapterResult<Vec<Id>>
+	{
+		unreachable!()
+	}
+}
+
+#[test]
+fn catalog()
+{
+	let catalog = Case::catalog();
+	assert_eq!(
+		catalog.iter().map(|c| c.name).collect::<HashSet<_>>().len(),
+		catalog.len(),
+		"every case has a unique name"
+	);
+
+	let dataset = Dataset::fixture();
+	let expected = |name: &str| {
+		catalog
+			.iter()
+			.find(|c| c.name == name)
+			.unwrap()
+			.query
+			.expected(&dataset)
+			.unwrap()
+	};
+
+	assert_eq!(expected("`in_range` includes only its lower bound"), [2, 3]);
+	assert_eq!(expected("`contains` is case-sensitive"), [1]);
+	assert_eq!(expected("`contains` a percent sign"), [3]);
+	assert_eq!(expected("`not` `equal_to` matches `NULL`"), [1, 3]);
+	assert_eq!(expected("`less_than` does not match `NULL`"), [2]);
+	assert_eq!(
+		expected("`regex` anchors do not match at line breaks"),
+		Vec::<Id>::new()
+	);
+	assert_eq!(expected("empty set"), [2]);
+	assert_eq!(expected("unpaid invoices"), [1, 2]);
+}
+
+#[test]
+fn catalog_is_portable()
+{
+	Case::catalog().into_iter().for_each(|case| {
+		let result = match &case.query
+		{
+			conformance::Query::Employee(c) => Dialect::Postgres.check(c),
+			conformance::Query::Expense(c) => Dialect::Postgres.check(c),
+			conformance::Query::Job(c) => Dialect::Postgres.check(c.as_ref()),
+			conformance::Query::Timesheet(c) => Dialect::Postgres.check(c.as_ref()),
+		};
+
+		assert!(result.is_ok(), "{}: {result:?}", case.name);
+	});
+}
+
+#[test]
+fn conforms()
+{
+	assert_eq!(conformance::run(&mut Memory::default()).unwrap(), []);
+}
+
+#[test]
+fn errors()
+{
+	assert!(matches!(
+		conformance::run(&mut Broken),
+		Err(Error::Adapter(_))
+	));
+}
+
+#[test]
+fn mismatches()
+{
+	let mismatches = conformance::run(&mut Memory {
+		duplicate_joins: true,
+		..Default::default()
+	})
+	.unwrap();
+
+	let mismatch = mismatches
+		.iter()
+		.find(|m| m.case.name == "set `contains` matches each record once")
+		.unwrap();
+	assert_eq!(mismatch.expected, [1, 3, 4]);
+	assert_eq!(mismatch.actual, [1, 1, 3, 4]);
+	assert!(mismatch.to_string().starts_with(
+		"set `contains` matches each record once: expected [1, 3, 4], but the adapter retrieved [1, \
+		 1, 3, 4]"
+	));
+	assert!(mismatches.iter().all(|m| m
+		.case
+		.query
+		.expected(&Dataset::fixture())
+		.unwrap()
+		.contains(&1)));
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "serde")]
+
+use clinvoice_match::{Match, MatchLocation, MatchOption, MatchOuterLocation, MatchStr};
+use pretty_assertions::assert_eq;
+use serde_yaml::from_str;
+
+#[test]
+fn conditions()
+{
+	assert_eq!(from_str::<Match<u32>>("any").unwrap(), Match::Any);
+	assert_eq!(from_str::<Match<u32>>("3").unwrap(), Match::EqualTo(3));
+	assert_eq!(
+		from_str::<Match<u32>>("[1, 2]").unwrap(),
+		Match::Or(vec![Match::EqualTo(1), Match::EqualTo(2)]),
+	);
+	assert_eq!(
+		from_str::<MatchOption<u32>>("none").unwrap(),
+		MatchOption::None
+	);
+	assert_eq!(
+		from_str::<MatchStr<String>>("equal_to").unwrap(),
+		MatchStr::EqualTo("equal_to".into()),
+	);
+
+	[
+		(
+			"greter_than: 3",
+			"unknown condition `greter_than`, expected one of `and`, `any`",
+		),
+		("in_range: [1]", "invalid length 1"),
+		("in_range: [1, two]", "expected u32"),
+		("and: [{greter_than: 3}]", "unknown condition `greter_than`"),
+		("not: {in_range: 1}", "invalid type: integer `1`"),
+	]
+	.into_iter()
+	.for_each(|(yaml, error)| {
+		let actual = from_str::<Match<u32>>(yaml).unwrap_err().to_string();
+		assert!(
+			actual.contains(error),
+			"{yaml} failed with {actual}, not {error}"
+		);
+	});
+
+	let error = from_str::<MatchOption<u32>>("equal_to: three")
+		.unwrap_err()
+		.to_string();
+	assert!(error.contains("expected u32"), "{error}");
+
+	let error = from_str::<MatchStr<String>>("regx: a")
+		.unwrap_err()
+		.to_string();
+	assert!(error.contains("unknown condition `regx`"), "{error}");
+}
+
+#[test]
+fn outer_location()
+{
+	assert_eq!(
+		from_str::<MatchOuterLocation>("{}").unwrap(),
+		MatchOuterLocation::Some(MatchLocation::default().into()),
+	);
+
+	assert_eq!(
+		from_str::<MatchLocation>("outer: {id: 3}").unwrap().outer,
+		MatchOuterLocation::Some(
+			MatchLocation {
+				id: 3.into(),
+				..Default::default()
+			}
+			.into()
+		),
+	);
+
+	[
+		"naem: {equal_to: Europe}",
+		"outer: {naem: {equal_to: Europe}}",
+		"some: {name: {equal_to: Europe}}\nid: 3",
+		"some: {}\nname: {equal_to: Europe}",
+	]
+	.into_iter()
+	.for_each(|yaml| {
+		assert!(
+			from_str::<MatchOuterLocation>(yaml).is_err(),
+			"{yaml} should not deserialize"
+		);
+	});
+}
@@ -0,0 +1,32 @@
+//! A macro which calls a generic function with the type of a documented example.
+
+/// Call the generic function `$f` with the type that corresponds to `$type_name`, passing it each
+/// `$arg`. The types which are used for generic matchers (e.g. [`Match<isize>`]) are the same ones
+/// that the documentation examples use.
+macro_rules! dispatch {
+	($type_name:expr, $f:ident $(, $arg:expr)* $(,)?) => {
+		match $type_name
+		{
+			"Aggregate" => $f::<clinvoice_match::Aggregate>($($arg),*),
+			"Aggregation" => $f::<clinvoice_match::Aggregation<clinvoice_match::MatchTimesheet>>($($arg),*),
+			"Match" => $f::<clinvoice_match::Match<isize>>($($arg),*),
+			"MatchContact" => $f::<clinvoice_match::MatchContact>($($arg),*),
+			"MatchContactKind" => $f::<clinvoice_match::MatchContactKind>($($arg),*),
+			"MatchEmployee" => $f::<clinvoice_match::MatchEmployee>($($arg),*),
+			"MatchExpense" => $f::<clinvoice_match::MatchExpense>($($arg),*),
+			"MatchInvoice" => $f::<clinvoice_match::MatchInvoice>($($arg),*),
+			"MatchJob" => $f::<clinvoice_match::MatchJob>($($arg),*),
+			"MatchLocation" => $f::<clinvoice_match::MatchLocation>($($arg),*),
+			"MatchOption" => $f::<clinvoice_match::MatchOption<isize>>($($arg),*),
+			"MatchOrganization" => $f::<clinvoice_match::MatchOrganization>($($arg),*),
+			"MatchOuterLocation" => $f::<clinvoice_match::MatchOuterLocation>($($arg),*),
+			"MatchSet" => $f::<clinvoice_match::MatchSet<clinvoice_match::Match<isize>>>($($arg),*),
+			"MatchStr" => $f::<clinvoice_match::MatchStr<String>>($($arg),*),
+			"MatchTimesheet" => $f::<clinvoice_match::MatchTimesheet>($($arg),*),
+			"Query" => $f::<clinvoice_match::Query<clinvoice_match::MatchJob>>($($arg),*),
+			name => panic!("There is no type for documented examples of `{name}`"),
+		}
+	};
+}
+
+pub(crate) use dispatch;
@@ -0,0 +1,237 @@
+#![cfg(feature = "yaml")]
+
+use core::fmt::Debug;
+use std::{fs, path::Path};
+
+use clinvoice_match::{
+	Document,
+	Error,
+	Limit,
+	MatchExpense,
+	MatchInvoice,
+	MatchJob,
+	MatchLocation,
+	MatchOrganization,
+	MatchStr,
+	MatchTimesheet,
+	Migrations,
+	DOCUMENT_VERSION,
+};
+use pretty_assertions::assert_eq;
+use serde::de::DeserializeOwned;
+use serde_yaml::Value;
+
+/// Assert that every historical document in `tests/documents/{dir}` is migrated to the document
+/// with the same name and the `.expected.yaml` extension.
+fn assert_corpus_migrates<M>(dir: &str)
+where
+	M: Debug + DeserializeOwned + PartialEq + 'static,
+{
+	let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+		.join("tests")
+		.join("documents")
+		.join(dir);
+	let mut count = 0;
+
+	fs::read_dir(dir)
+		.unwrap()
+		.map(|entry| entry.unwrap().path())
+		.for_each(|path| {
+			let file_name = path.file_name().unwrap().to_str().unwrap();
+			if file_name.ends_with(".expected.yaml")
+			{
+				return;
+			}
+
+			let read = |path: &Path| -> Document<M> {
+				serde_yaml::from_str(&fs::read_to_string(path).unwrap())
+					.unwrap_or_else(|e| panic!("Could not read {}: {e}", path.display()))
+			};
+
+			let expected = read(&path.with_file_name(file_name.replace(".yaml", ".expected.yaml")));
+			assert_eq!(expected.version, DOCUMENT_VERSION);
+			assert_eq!(
+				read(&path),
+				expected,
+				"{} was not migrated correctly",
+				path.display()
+			);
+			count += 1;
+		});
+
+	assert!(count > 0);
+}
+
+#[test]
+fn fragment_expansion()
+{
+	// each fragment uses the previous one twice, so `f32` expands to 2^32 copies of `f0`
+	let fragments = (1..=32)
+		.map(|i| format!("f{i}: [{{$ref: f{}}}, {{$ref: f{}}}]", i - 1, i - 1))
+		.collect::<Vec<_>>()
+		.join(", ");
+	let value = serde_yaml::from_str(&format!(
+		"{{filter: {{client: {{name: {{$ref: f32}}}}}}, fragments: {{f0: Bob, {fragments}}}, \
+		 version: 2}}"
+	))
+	.unwrap();
+
+	assert!(matches!(
+		Document::<MatchJob>::from_value(value, &Default::default(), &Default::default()),
+		Err(Error::LimitExceeded { limit: Limit::Nodes, path, .. })
+			if path.to_string().starts_with("client.name.0.0.0")
+	));
+}
+
+#[test]
+fn fragment_cycle()
+{
+	let value = serde_yaml::from_str(
+		"{
+			filter: {client: {$ref: a}},
+			fragments: {
+				a: {$include: b, name: {equal_to: A}},
+				b: {location: {$ref: c}},
+				c: {outer: {$ref: b}},
+			},
+			version: 2,
+		}",
+	)
+	.unwrap();
+
+	assert!(matches!(
+		Document::<MatchJob>::from_value(value, &Default::default(), &Default::default()),
+		Err(Error::FragmentCycle { cycle }) if cycle == ["b", "c", "b"]
+	));
+}
+
+#[test]
+fn unknown_fragment()
+{
+	let value: Value =
+		serde_yaml::from_str("{filter: {client: {$ref: missing}}, version: 2}").unwrap();
+	let library = serde_yaml::from_str("{missing: {name: {equal_to: Found}}}").unwrap();
+
+	assert!(matches!(
+		Document::<MatchJob>::from_value(value.clone(), &Default::default(), &Default::default()),
+		Err(Error::UnknownFragment { fragment }) if fragment == "missing"
+	));
+	assert_eq!(
+		Document::<MatchJob>::from_value_with_library(
+			value,
+			&library,
+			&Default::default(),
+			&Default::default()
+		)
+		.unwrap()
+		.filter
+		.client
+		.name,
+		MatchStr::EqualTo("Found".into())
+	);
+}
+
+#[test]
+fn historical_corpus()
+{
+	assert_corpus_migrates::<MatchInvoice>("match_invoice");
+	assert_corpus_migrates::<MatchJob>("match_job");
+	assert_corpus_migrates::<MatchTimesheet>("match_timesheet");
+}
+
+#[test]
+fn nested_migrations()
+{
+	/// A migration which renames the field `from` to `to`.
+	fn rename(from: &'static str, to: &'static str) -> impl Fn(&mut Value) -> Result<(), String>
+	{
+		move |filter| {
+			let fields = filter
+				.as_mapping_mut()
+				.ok_or("expected the fields of a matcher")?;
+			if let Some(value) = fields.remove(&from.into())
+			{
+				fields.insert(to.into(), value);
+			}
+
+			Ok(())
+		}
+	}
+
+	let mut migrations = Migrations::default();
+	migrations
+		.register::<MatchExpense>(1, rename("kind", "category"))
+		.register::<MatchJob>(1, rename("goals", "objectives"))
+		.register::<MatchLocation>(1, rename("country", "name"))
+		.register::<MatchOrganization>(1, rename("company_name", "name"));
+
+	let value = serde_yaml::from_str(
+		"{
+			filter: {
+				employee: Bob,
+				expenses: {
+					and: [{contains: {kind: Travel}}, {not: {contains: {kind: Food}}}],
+				},
+				job: {
+					client: {
+						company_name: Acme,
+						location: {
+							country: Stockholm,
+							outer: {country: Sweden, outer: {some: {country: Europe}}},
+						},
+					},
+					goals: {contains: refactor},
+				},
+			},
+			version: 1,
+		}",
+	)
+	.unwrap();
+
+	assert_eq!(
+		Document::<MatchTimesheet>::from_value(value, &migrations, &Default::default())
+			.unwrap()
+			.filter,
+		serde_yaml::from_str(
+			"{
+				employee: {name: Bob},
+				expenses: {
+					and: [{contains: {category: Travel}}, {not: {contains: {category: Food}}}],
+				},
+				job: {
+					client: {
+						location: {name: Stockholm, outer: {name: Sweden, outer: {name: Europe}}},
+						name: Acme,
+					},
+					objectives: {contains: refactor},
+				},
+			}"
+		)
+		.unwrap(),
+	);
+}
+
+#[test]
+fn newer_version()
+{
+	let value = serde_yaml::from_str(&format!(
+		"{{filter: {{}}, version: {}}}",
+		DOCUMENT_VERSION + 1
+	))
+	.unwrap();
+	assert!(matches!(
+		Document::<MatchTimesheet>::from_value(value, &Default::default(), &Default::default()),
+		Err(Error::DocumentVersion { current: DOCUMENT_VERSION, found }) if found == DOCUMENT_VERSION + 1
+	));
+}
+
+#[test]
+fn round_trip()
+{
+	let document = Document::new(MatchTimesheet::default());
+	let yaml = serde_yaml::to_string(&document).unwrap();
+	assert_eq!(
+		serde_yaml::from_str::<Document<MatchTimesheet>>(&yaml).unwrap(),
+		document
+	);
+}
@@ -0,0 +1,137 @@
+mod common;
+
+use core::time::Duration;
+
+use clinvoice_match::{
+	Compile,
+	IsMatch,
+	Match,
+	MatchOption,
+	MatchTimesheet,
+	Satisfiability,
+	Satisfy,
+};
+use common::{time, timesheet};
+use pretty_assertions::assert_eq;
+
+/// A [`MatchTimesheet`] which matches the `duration`.
+fn condition(duration: MatchOption<Duration>) -> MatchTimesheet
+{
+	MatchTimesheet {
+		duration: duration.map(Into::into),
+		..Default::default()
+	}
+}
+
+const HOUR: Duration = Duration::from_secs(60 * 60);
+
+#[test]
+fn is_match()
+{
+	let long = condition(MatchOption::GreaterThan(HOUR * 8))
+		.compile()
+		.unwrap();
+	assert!(long.is_match(&timesheet(time(8, 0), Some(time(16, 30)))));
+	assert!(!long.is_match(&timesheet(time(8, 0), Some(time(16, 0)))));
+	assert!(!long.is_match(&timesheet(time(8, 0), None)));
+
+	let unfinished = condition(MatchOption::None).compile().unwrap();
+	assert!(unfinished.is_match(&timesheet(time(8, 0), None)));
+	assert!(!unfinished.is_match(&timesheet(time(8, 0), Some(time(8, 0)))));
+
+	let empty = condition(MatchOption::EqualTo(Duration::ZERO))
+		.compile()
+		.unwrap();
+	assert!(empty.is_match(&timesheet(time(8, 0), Some(time(8, 0)))));
+	assert!(empty.is_match(&timesheet(time(8, 0), Some(time(7, 0)))));
+}
+
+#[test]
+fn satisfy()
+{
+	let mut condition = MatchTimesheet {
+		time_begin: Match::GreaterThan(time(12, 0)),
+		time_end: MatchOption::LessThan(time(14, 0)),
+		..condition(MatchOption::InRange(HOUR, HOUR * 2))
+	};
+
+	let example = condition.satisfy().example().unwrap();
+	assert!(condition.compile().unwrap().is_match(&example));
+
+	condition.duration = MatchOption::GreaterThan((HOUR * 2).into());
+	assert!(!condition.satisfy().is_satisfiable());
+
+	condition.duration = MatchOption::None;
+	assert!(!condition.satisfy().is_satisfiable());
+
+	condition.time_end = MatchOption::Any;
+	let example = condition.satisfy().example().unwrap();
+	assert_eq!(example.time_end, None);
+	assert!(condition.compile().unwrap().is_match(&example));
+
+	condition.duration = MatchOption::And(vec![
+		MatchOption::GreaterThan(HOUR.into()),
+		MatchOption::LessThan(HOUR.into()),
+	]);
+	assert_eq!(
+		condition.satisfy(),
+		Satisfiability::Unsatisfiable("duration".parse().unwrap()),
+	);
+}
+
+#[test]
+fn sql()
+{
+	let sql = |duration| condition(duration).duration_sql("time_begin", "time_end");
+	let interval = "(time_end - LEAST(time_begin, time_end))";
+
+	assert_eq!(sql(MatchOption::Any), "TRUE");
+	assert_eq!(sql(MatchOption::None), format!("{interval} IS NULL"));
+	assert_eq!(
+		sql(MatchOption::Or(vec![
+			MatchOption::LessThan(Duration::from_millis(1500)),
+			MatchOption::InRange(HOUR, HOUR * 8),
+		])),
+		format!(
+			"(COALESCE({interval} < INTERVAL '1.500000 seconds', FALSE) OR COALESCE({interval} >= \
+			 INTERVAL '3600 seconds' AND {interval} < INTERVAL '28800 seconds', FALSE))"
+		),
+	);
+	assert_eq!(sql(MatchOption::And(Vec::new())), "TRUE");
+	assert_eq!(
+		sql(MatchOption::Not(MatchOption::EqualTo(HOUR).into())),
+		format!("NOT COALESCE({interval} = INTERVAL '3600 seconds', FALSE)"),
+	);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde()
+{
+	let condition: MatchTimesheet = serde_yaml::from_str(
+		r#"
+duration:
+  greater_than: "8h"
+"#,
+	)
+	.unwrap();
+	assert_eq!(
+		condition,
+		self::condition(MatchOption::GreaterThan(HOUR * 8))
+	);
+
+	let condition: MatchTimesheet = serde_yaml::from_str(
+		r#"
+duration:
+  in_range: ["15min", "1h 30m"]
+"#,
+	)
+	.unwrap();
+	assert_eq!(
+		condition,
+		self::condition(MatchOption::InRange(
+			Duration::from_secs(15 * 60),
+			HOUR * 3 / 2
+		)),
+	);
+}
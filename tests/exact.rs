@@ -0,0 +1,184 @@
+mod common;
+
+use core::time::Duration;
+
+use clinvoice_match::{Compile, IsMatch, MatchContact, MatchLocation, MatchTimesheet};
+use clinvoice_schema::{
+	Contact,
+	ContactKind,
+	Currency,
+	Employee,
+	Expense,
+	Invoice,
+	InvoiceDate,
+	Job,
+	Location,
+	Money,
+	Organization,
+	Timesheet,
+};
+use common::date;
+
+/// A [`Location`] inside of two others.
+fn location() -> Location
+{
+	let earth = Location {
+		id: 1,
+		name: "Earth".into(),
+		outer: None,
+	};
+	let usa = Location {
+		id: 2,
+		name: "USA".into(),
+		outer: Some(earth.into()),
+	};
+	Location {
+		id: 3,
+		name: "Arizona".into(),
+		outer: Some(usa.into()),
+	}
+}
+
+/// A [`Timesheet`] with every field set.
+fn timesheet() -> Timesheet
+{
+	let expense = |id, category: &str| Expense {
+		category: category.into(),
+		cost: Money::new(20_00, 2, Currency::Usd),
+		description: "Trip".into(),
+		id,
+		timesheet_id: 1,
+	};
+
+	Timesheet {
+		employee: Employee {
+			id: 1,
+			name: "Alice".into(),
+			status: "Hired".into(),
+			title: "CEO".into(),
+		},
+		expenses: vec![expense(1, "Travel"), expense(2, "Food")],
+		id: 1,
+		job: Job {
+			client: Organization {
+				id: 1,
+				location: location(),
+				name: "Big Old Test".into(),
+			},
+			date_close: None,
+			date_open: date(2022, 1, 1),
+			id: 1,
+			increment: Duration::from_secs(15 * 60),
+			invoice: Invoice {
+				date: Some(InvoiceDate {
+					issued: date(2022, 1, 2),
+					paid: None,
+				}),
+				hourly_rate: Money::new(20_00, 2, Currency::Usd),
+			},
+			notes: "Notes".into(),
+			objectives: "Objectives".into(),
+		},
+		time_begin: date(2022, 1, 1),
+		time_end: Some(date(2022, 1, 2)),
+		work_notes: "Work notes".into(),
+	}
+}
+
+#[test]
+fn contact()
+{
+	let contact = Contact {
+		kind: ContactKind::Address(location()),
+		label: "Office".into(),
+	};
+	let compiled = MatchContact::from(&contact).compile().unwrap();
+	assert!(compiled.is_match(&contact));
+
+	let mut other = contact.clone();
+	other.kind = ContactKind::Email("Office".into());
+	assert!(!compiled.is_match(&other));
+}
+
+#[test]
+fn location_chain()
+{
+	let location = location();
+	let compiled = MatchLocation::from(&location).compile().unwrap();
+	assert!(compiled.is_match(&location));
+
+	let mut without_outer = location.clone();
+	without_outer.outer.as_mut().unwrap().outer = None;
+	assert!(!compiled.is_match(&without_outer));
+
+	let mut with_outer = location.clone();
+	with_outer
+		.outer
+		.as_mut()
+		.unwrap()
+		.outer
+		.as_mut()
+		.unwrap()
+		.outer = Some(location.into());
+	assert!(!compiled.is_match(&with_outer));
+}
+
+#[test]
+fn timesheet_exact()
+{
+	let timesheet = timesheet();
+	let compiled = MatchTimesheet::from(&timesheet).compile().unwrap();
+	assert!(compiled.is_match(&timesheet));
+
+	let mut changed = Vec::new();
+	changed.push(Timesheet {
+		work_notes: "Other".into(),
+		..timesheet.clone()
+	});
+	changed.push(Timesheet {
+		time_end: None,
+		..timesheet.clone()
+	});
+
+	let mut fewer_expenses = timesheet.clone();
+	fewer_expenses.expenses.pop();
+	changed.push(fewer_expenses);
+
+	let mut more_expenses = timesheet.clone();
+	more_expenses.expenses.push(Expense {
+		id: 3,
+		..more_expenses.expenses[0].clone()
+	});
+	changed.push(more_expenses);
+
+	let mut different_expense = timesheet.clone();
+	different_expense.expenses[1].category = "Travel".into();
+	changed.push(different_expense);
+
+	let mut paid = timesheet.clone();
+	paid.job.invoice.date.as_mut().unwrap().paid = Some(paid.time_begin);
+	changed.push(paid);
+
+	let mut increment = timesheet.clone();
+	increment.job.increment = Duration::from_secs(60);
+	changed.push(increment);
+
+	let mut moved = timesheet.clone();
+	moved.job.client.location.outer.as_mut().unwrap().name = "Mexico".into();
+	changed.push(moved);
+
+	changed
+		.iter()
+		.for_each(|t| assert!(!compiled.is_match(t), "{t:?}"));
+
+	let no_expenses = Timesheet {
+		expenses: Vec::new(),
+		..timesheet
+	};
+	let compiled = MatchTimesheet::from(&no_expenses).compile().unwrap();
+	assert!(compiled.is_match(&no_expenses));
+	assert!(!compiled.is_match(&Timesheet {
+		expenses: vec![Expense::default()],
+		..no_expenses
+	}));
+}
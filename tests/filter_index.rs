@@ -0,0 +1,133 @@
+mod common;
+
+use clinvoice_match::{Compile, FilterIndex, IsMatch, Match, MatchJob, MatchStr, MatchTimesheet};
+use clinvoice_schema::{Job, Timesheet};
+use common::time;
+use pretty_assertions::assert_eq;
+
+/// A [`Timesheet`] for each hour of 2022-01-01, whose job was opened on the same hour.
+fn timesheets() -> Vec<Timesheet>
+{
+	(0..24)
+		.map(|hour| Timesheet {
+			id: hour.into(),
+			job: Job {
+				date_open: time(hour, 0),
+				..Default::default()
+			},
+			time_begin: time(hour, 0),
+			work_notes: ["a", "b", "c"][hour as usize % 3].into(),
+			..Default::default()
+		})
+		.collect()
+}
+
+/// Filters which use each kind of index: ids, strings, both date fields with every kind of range,
+/// and no index at all.
+fn filters() -> Vec<(&'static str, MatchTimesheet)>
+{
+	let time_begin = |condition| MatchTimesheet {
+		time_begin: condition,
+		..Default::default()
+	};
+
+	vec![
+		("id", MatchTimesheet::from(Match::EqualTo(3))),
+		(
+			"ids",
+			MatchTimesheet::from(Match::Or(vec![5.into(), 7.into()])),
+		),
+		("notes", MatchTimesheet {
+			work_notes: "b".to_owned().into(),
+			..Default::default()
+		}),
+		("after", time_begin(Match::GreaterThan(time(20, 0)))),
+		("before", time_begin(Match::LessThan(time(3, 0)))),
+		("range", time_begin(Match::InRange(time(5, 0), time(8, 0)))),
+		(
+			"between",
+			time_begin(Match::And(vec![
+				Match::GreaterThan(time(10, 0)),
+				Match::LessThan(time(12, 0)),
+			])),
+		),
+		("at", time_begin(Match::EqualTo(time(15, 0)))),
+		(
+			"edges",
+			time_begin(Match::Or(vec![
+				Match::LessThan(time(1, 0)),
+				Match::GreaterThan(time(22, 0)),
+			])),
+		),
+		("opened", MatchTimesheet {
+			job: MatchJob {
+				date_open: Match::InRange(time(12, 0), time(12, 1)),
+				..Default::default()
+			},
+			..Default::default()
+		}),
+		("contains", MatchTimesheet {
+			work_notes: MatchStr::Contains("c".into()),
+			..Default::default()
+		}),
+		(
+			"not",
+			time_begin(Match::Not(Match::EqualTo(time(9, 0)).into())),
+		),
+	]
+}
+
+/// Assert that the `index` finds the same filters as matching each of the `filters` directly.
+fn assert_agrees(index: &FilterIndex<&str, MatchTimesheet>, filters: &[(&str, MatchTimesheet)])
+{
+	let compiled: Vec<_> = filters
+		.iter()
+		.map(|(key, filter)| (key, filter.compile().unwrap()))
+		.collect();
+
+	timesheets().iter().for_each(|timesheet| {
+		let expected: Vec<_> = compiled
+			.iter()
+			.filter(|(_, filter)| filter.is_match(timesheet))
+			.map(|(key, _)| *key)
+			.collect();
+
+		assert_eq!(
+			index.matches(timesheet),
+			expected,
+			"for the timesheet which began at {}",
+			timesheet.time_begin
+		);
+	});
+}
+
+#[test]
+fn agrees_with_is_match()
+{
+	let filters = filters();
+	let mut index = FilterIndex::new();
+	filters
+		.iter()
+		.for_each(|(key, filter)| index.insert(*key, filter).unwrap());
+
+	assert_eq!(index.len(), filters.len());
+	assert_agrees(&index, &filters);
+}
+
+#[test]
+fn insert_after_matching()
+{
+	let filters = filters();
+	let (first, rest) = filters.split_at(filters.len() / 2);
+	let mut index = FilterIndex::new();
+
+	first
+		.iter()
+		.for_each(|(key, filter)| index.insert(*key, filter).unwrap());
+	assert_agrees(&index, first);
+
+	rest
+		.iter()
+		.for_each(|(key, filter)| index.insert(*key, filter).unwrap());
+	assert_agrees(&index, &filters);
+}
@@ -0,0 +1,133 @@
+use core::time::Duration;
+
+use clinvoice_match::{
+	Compile,
+	Dialect,
+	FromExample,
+	IsMatch,
+	Match,
+	MatchJob,
+	MatchLocation,
+	MatchOrganization,
+	MatchOuterLocation,
+	MatchStr,
+	TextSearch,
+};
+use clinvoice_schema::{Job, Location, Organization};
+use pretty_assertions::assert_eq;
+
+/// An [`Organization`] with every field set.
+fn organization() -> Organization
+{
+	Organization {
+		id: 7,
+		location: Location {
+			id: 2,
+			name: "Phoenix".into(),
+			outer: Some(
+				Location {
+					id: 1,
+					name: "Arizona".into(),
+					outer: None,
+				}
+				.into(),
+			),
+		},
+		name: "Big Old Test Corp.".into(),
+	}
+}
+
+#[test]
+fn empty()
+{
+	assert_eq!(
+		MatchOrganization::from_example(&Organization::default(), TextSearch::Prefix),
+		MatchOrganization::default()
+	);
+	assert_eq!(
+		MatchJob::from_example(&Job::default(), TextSearch::Contains),
+		MatchJob::default()
+	);
+}
+
+#[test]
+fn job()
+{
+	let form = Job {
+		client: Organization {
+			name: "Big".into(),
+			..Default::default()
+		},
+		increment: Duration::from_secs(15 * 60),
+		..Default::default()
+	};
+
+	assert_eq!(
+		MatchJob::from_example(&form, TextSearch::Contains),
+		MatchJob {
+			client: MatchOrganization {
+				name: MatchStr::Contains("Big".into()),
+				..Default::default()
+			},
+			increment: Match::EqualTo(Duration::from_secs(15 * 60).into()),
+			..Default::default()
+		}
+	);
+}
+
+#[test]
+fn nested()
+{
+	let form = Organization {
+		location: Location {
+			outer: Some(
+				Location {
+					name: "ariz".into(),
+					..Default::default()
+				}
+				.into(),
+			),
+			..Default::default()
+		},
+		..Default::default()
+	};
+
+	let condition = MatchOrganization::from_example(&form, TextSearch::Prefix);
+	assert_eq!(condition, MatchOrganization {
+		location: MatchLocation {
+			outer: MatchOuterLocation::Some(
+				MatchLocation {
+					name: MatchStr::Regex("(?i)^ariz".into()),
+					..Default::default()
+				}
+				.into()
+			),
+			..Default::default()
+		},
+		..Default::default()
+	});
+	assert!(condition.compile().unwrap().is_match(&organization()));
+}
+
+#[test]
+fn prefix()
+{
+	let search = |name: &str| {
+		let condition = MatchOrganization::from_example(
+			&Organization {
+				name: name.into(),
+				..Default::default()
+			},
+			TextSearch::Prefix,
+		);
+		assert!(Dialect::Postgres.check(&condition).is_ok());
+		condition.compile().unwrap().is_match(&organization())
+	};
+
+	assert!(search("big old"));
+	assert!(search("BIG"));
+	assert!(search("Big Old Test Corp."));
+	assert!(!search("Old"));
+	assert!(!search("Big Old Test Corp.."));
+	assert!(!search("Big.Old"));
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "schemars")]
+
+mod common;
+mod dispatch;
+
+use common::Example;
+use jsonschema::JSONSchema;
+use schemars::{schema_for, JsonSchema};
+
+/// Assert that the `example` is valid according to the [`JsonSchema`] of `T`.
+fn assert_valid<T>(example: &Example)
+where
+	T: JsonSchema,
+{
+	let schema = serde_json::to_value(schema_for!(T)).unwrap();
+	let compiled = JSONSchema::compile(&schema).unwrap();
+	let instance: serde_json::Value = serde_yaml::from_str(&example.yaml).unwrap();
+
+	let errors: Vec<_> = match compiled.validate(&instance)
+	{
+		Ok(()) => return,
+		Err(errors) => errors
+			.map(|e| format!("* {e} (at {})", e.instance_path))
+			.collect(),
+	};
+
+	panic!(
+		"The example of `{}` in {} is not valid:\n{}\n{}",
+		example.type_name,
+		example.file,
+		example.yaml,
+		errors.join("\n"),
+	);
+}
+
+#[test]
+fn documented_examples()
+{
+	let examples = common::documented_examples();
+	assert!(!examples.is_empty());
+	examples
+		.iter()
+		.for_each(|example| dispatch::dispatch!(example.type_name.as_str(), assert_valid, example));
+}
@@ -0,0 +1,218 @@
+#![cfg(feature = "yaml")]
+
+use clinvoice_match::{
+	Document,
+	Error,
+	Limit,
+	Limits,
+	MatchEmployee,
+	MatchJob,
+	MatchStr,
+	MatchTimesheet,
+	Parameters,
+	RegexEngine,
+	Template,
+};
+use pretty_assertions::assert_eq;
+
+/// Assert that reading the `yaml` with the `limits` fails because the `limit` was exceeded at the
+/// `path`.
+fn assert_exceeds(limits: &Limits, yaml: &str, limit: Limit, path: &str)
+{
+	match limits.from_str::<MatchTimesheet>(yaml)
+	{
+		Err(Error::LimitExceeded {
+			limit: l, path: p, ..
+		}) =>
+		{
+			assert_eq!(l, limit);
+			assert_eq!(p.to_string(), path);
+		},
+		other => panic!("Expected the {limit} to be exceeded at `{path}`, but got {other:?}"),
+	}
+}
+
+#[test]
+fn check()
+{
+	let mut condition = MatchStr::EqualTo("Bob".to_owned());
+	(0..100).for_each(|_| condition = MatchStr::Not(condition.clone().into()));
+	let employee = MatchEmployee {
+		name: condition,
+		..Default::default()
+	};
+
+	assert!(matches!(
+		Limits::default().check(&employee),
+		Err(Error::LimitExceeded {
+			limit: Limit::Depth,
+			..
+		})
+	));
+	assert!(Limits {
+		max_depth: 128,
+		..Default::default()
+	}
+	.compile(&employee)
+	.is_ok());
+}
+
+#[test]
+fn depth()
+{
+	let nested = (0..100).fold("equal_to: Bob".to_owned(), |yaml, _| {
+		format!("not: {{{yaml}}}")
+	});
+	let yaml = format!("employee: {{name: {{{nested}}}}}");
+
+	assert_exceeds(
+		&Limits::default(),
+		&yaml,
+		Limit::Depth,
+		&format!("employee.name{}", ".not".repeat(62)),
+	);
+}
+
+#[test]
+fn fragments()
+{
+	let limits = Limits {
+		max_depth: 4,
+		regex_engine: RegexEngine::Disabled,
+		..Default::default()
+	};
+	let read = |yaml| {
+		Document::<MatchTimesheet>::from_value(
+			serde_yaml::from_str(yaml).unwrap(),
+			&Default::default(),
+			&limits,
+		)
+	};
+
+	assert!(matches!(
+		read(
+			"{
+				filter: {work_notes: {regex: {$ref: pattern}}},
+				fragments: {pattern: '^(a+)+$'},
+				version: 2,
+			}"
+		),
+		Err(Error::RegexDisabled { path }) if path.to_string() == "work_notes.regex"
+	));
+	assert!(matches!(
+		read(
+			"{
+				filter: {employee: {$ref: nested}},
+				fragments: {nested: {name: {not: {not: {equal_to: Bob}}}}},
+				version: 2,
+			}"
+		),
+		Err(Error::LimitExceeded {
+			limit: Limit::Depth,
+			..
+		})
+	));
+	assert!(
+		read("{filter: {employee: {$ref: bob}}, fragments: {bob: {name: Bob}}, version: 2}").is_ok()
+	);
+}
+
+#[test]
+fn list_length()
+{
+	let limits = Limits {
+		max_list_length: 3,
+		..Default::default()
+	};
+
+	assert!(limits
+		.from_str::<MatchJob>("client: {name: [a, b, c]}")
+		.is_ok());
+	assert_exceeds(
+		&limits,
+		"job: {client: {name: [a, b, c, d]}}",
+		Limit::ListLength,
+		"job.client.name",
+	);
+}
+
+#[test]
+fn nodes()
+{
+	let limits = Limits {
+		max_nodes: 5,
+		..Default::default()
+	};
+
+	assert!(limits
+		.from_str::<MatchTimesheet>("work_notes: {contains: bug}")
+		.is_ok());
+	assert_exceeds(
+		&limits,
+		"{work_notes: {contains: bug}, employee: {title: {equal_to: CEO}}}",
+		Limit::Nodes,
+		"employee.title.equal_to",
+	);
+}
+
+#[test]
+fn regex_size()
+{
+	let limits = Limits {
+		max_regex_size: 1 << 14,
+		..Default::default()
+	};
+
+	assert!(limits
+		.from_str::<MatchTimesheet>(r"work_notes: {regex: '^[a-z]+$'}")
+		.is_ok());
+	assert_exceeds(
+		&limits,
+		r"work_notes: {regex: '\w{100}'}",
+		Limit::RegexSize,
+		"work_notes.regex",
+	);
+	assert!(matches!(
+		limits.from_str::<MatchTimesheet>("work_notes: {regex: '('}"),
+		Err(Error::Regex(_))
+	));
+}
+
+#[test]
+fn template()
+{
+	let limits = Limits {
+		max_regex_size: 1 << 14,
+		..Default::default()
+	};
+	let template: Template<MatchTimesheet> =
+		serde_yaml::from_str("work_notes: {regex: $pattern}").unwrap();
+	let mut parameters = Parameters::new();
+
+	parameters.insert("pattern", "^[a-z]+$").unwrap();
+	assert!(template.bind(&parameters, &limits).is_ok());
+
+	parameters.insert("pattern", r"\w{100}").unwrap();
+	assert!(matches!(
+		template.bind(&parameters, &limits),
+		Err(Error::LimitExceeded { limit: Limit::RegexSize, path, .. })
+			if path.to_string() == "work_notes.regex"
+	));
+
+	let template: Template<MatchTimesheet> =
+		serde_yaml::from_str("employee: {name: $name}").unwrap();
+	let nested = (0..100).fold(serde_yaml::Value::from("Bob"), |value, _| {
+		let mut not = serde_yaml::Mapping::new();
+		not.insert("not".into(), value);
+		not.into()
+	});
+	parameters = Parameters::new();
+	parameters.insert("name", nested).unwrap();
+	assert!(matches!(
+		template.bind(&parameters, &limits),
+		Err(Error::LimitExceeded {
+			limit: Limit::Depth,
+			..
+		})
+	));
+}
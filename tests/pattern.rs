@@ -0,0 +1,205 @@
+use clinvoice_match::{Construct, Dialect, Error, Pattern};
+use pretty_assertions::assert_eq;
+use regex::Regex;
+
+/// Strings which patterns are matched against to check that translation does not change them.
+const HAYSTACKS: [&str; 8] = [
+	"",
+	"Fixed the bug",
+	"fix: crash on start\nand more",
+	"line one\nline two",
+	"price: $5.00 (approx.)",
+	"tab\there",
+	"αβγ 123",
+	"a-b_c]d^e",
+];
+
+#[test]
+fn postgres_to_rust()
+{
+	[
+		(r"\yfix\y", r"\bfix\b"),
+		(r"\mfix\M", r"\b{start}fix\b{end}"),
+		(r"a.b", r"a(?s:.)b"),
+		(r"***=a.b*", r"a\.b\*"),
+		(r"***:\Aab\Z", r"^ab$"),
+		(r"[[:alpha:]\d]+", r"[[:alpha:]\d]+"),
+		(r"\x41é\b", r"Aé\x{8}"),
+		(r"[a[b]", r"[a\[b]"),
+		(r"[a&&b~~c]", r"[a\&\&b\~\~c]"),
+	]
+	.into_iter()
+	.for_each(|(postgres, rust)| {
+		assert_eq!(
+			Pattern::translate(postgres, Dialect::Postgres, Dialect::Rust).unwrap(),
+			rust,
+			"{postgres}"
+		);
+	});
+}
+
+#[test]
+fn depth()
+{
+	let nested = |depth| format!("{}a{}", "(".repeat(depth), ")".repeat(depth));
+	assert!(Pattern::parse(&nested(64), Dialect::Rust).is_ok());
+
+	[Dialect::Postgres, Dialect::Rust]
+		.into_iter()
+		.for_each(|dialect| {
+			assert!(matches!(
+				Pattern::parse(&nested(65), dialect),
+				Err(Error::RegexSyntax { .. })
+			));
+			assert!(matches!(
+				Pattern::parse(&"(".repeat(100_000), dialect),
+				Err(Error::RegexSyntax { .. })
+			));
+		});
+}
+
+#[test]
+fn postgres_round_trip()
+{
+	[r"a.b", r"^.*$", r"[a[b&&c]+"]
+		.into_iter()
+		.for_each(|pattern| {
+			let rust = Pattern::translate(pattern, Dialect::Postgres, Dialect::Rust).unwrap();
+			let postgres = Pattern::translate(&rust, Dialect::Rust, Dialect::Postgres).unwrap();
+			let (original, translated) = (
+				Regex::new(&rust).unwrap(),
+				Regex::new(&Pattern::translate(&postgres, Dialect::Postgres, Dialect::Rust).unwrap())
+					.unwrap(),
+			);
+
+			HAYSTACKS.into_iter().for_each(|haystack| {
+				assert_eq!(
+					original.is_match(haystack),
+					translated.is_match(haystack),
+					"`{pattern}` became `{rust}` and then `{postgres}`, which differ on {haystack:?}"
+				);
+			});
+		});
+}
+
+#[test]
+fn round_trip()
+{
+	[
+		r"(?i)\bfix(ed)?\b",
+		r"^line .*$",
+		r"\$\d+\.\d{2}",
+		r"[^\s\]-]+",
+		r"(?:a|b|)c{2,}?d{1,3}e{4}",
+		r"(?P<word>\w+)\s",
+		r"\b{start}\w",
+		r"[\t\n]",
+		r"^$",
+	]
+	.into_iter()
+	.for_each(|pattern| {
+		let postgres = Pattern::translate(pattern, Dialect::Rust, Dialect::Postgres).unwrap();
+		let rust = Pattern::translate(&postgres, Dialect::Postgres, Dialect::Rust).unwrap();
+		let (original, translated) = (Regex::new(pattern).unwrap(), Regex::new(&rust).unwrap());
+
+		HAYSTACKS.into_iter().for_each(|haystack| {
+			assert_eq!(
+				original.is_match(haystack),
+				translated.is_match(haystack),
+				"`{pattern}` became `{postgres}` and then `{rust}`, which differ on {haystack:?}"
+			);
+		});
+	});
+}
+
+#[test]
+fn rust_to_postgres()
+{
+	[
+		(r"\bfix\B", r"\yfix\Y"),
+		(r"a.b", r"a[^\n]b"),
+		(r"\Aab\z", r"^ab$"),
+		(r"(?<name>a)", r"(a)"),
+		(r"\x{1}A", r"\U00000001A"),
+		(r"[a\-z]", r"[a\-z]"),
+	]
+	.into_iter()
+	.for_each(|(rust, postgres)| {
+		assert_eq!(
+			Pattern::translate(rust, Dialect::Rust, Dialect::Postgres).unwrap(),
+			postgres,
+			"{rust}"
+		);
+	});
+}
+
+#[test]
+fn syntax()
+{
+	[
+		("(a", Dialect::Rust),
+		("a)", Dialect::Postgres),
+		("*a", Dialect::Rust),
+		("a{3,1}", Dialect::Rust),
+		("[a", Dialect::Postgres),
+		("[z-a]", Dialect::Rust),
+		(r"\q", Dialect::Rust),
+		(r"\p{Greek}", Dialect::Postgres),
+		("(?s).", Dialect::Rust),
+		("[a[bc]]", Dialect::Rust),
+		("[a-z&&[^aeiou]]", Dialect::Rust),
+		("[a&&b]", Dialect::Rust),
+		("[a--b]", Dialect::Rust),
+		("[a~~b]", Dialect::Rust),
+		("[[.a.]]", Dialect::Postgres),
+		("[[=a=]]", Dialect::Postgres),
+	]
+	.into_iter()
+	.for_each(|(pattern, dialect)| {
+		assert!(
+			matches!(
+				Pattern::parse(pattern, dialect),
+				Err(Error::RegexSyntax { .. })
+			),
+			"{pattern}"
+		);
+	});
+}
+
+#[test]
+fn unsupported()
+{
+	[
+		(r"a(?=b)", Dialect::Rust, Construct::Lookaround),
+		(r"(a)\1", Dialect::Rust, Construct::Backreference),
+	]
+	.into_iter()
+	.for_each(|(pattern, dialect, construct)| {
+		assert!(matches!(
+			Pattern::parse(pattern, dialect),
+			Err(Error::RegexUnsupported { construct: c, dialect: d }) if c == construct && d == dialect
+		));
+	});
+
+	[
+		(r"\p{Greek}", Construct::UnicodeClass),
+		(r"[\PL]", Construct::UnicodeClass),
+		(r"[a\D]", Construct::NegatedShorthandInBrackets),
+		(r"[^\W]", Construct::NegatedShorthandInBrackets),
+	]
+	.into_iter()
+	.for_each(|(pattern, construct)| {
+		assert_eq!(
+			Pattern::parse(pattern, Dialect::Rust)
+				.unwrap()
+				.unsupported(Dialect::Postgres),
+			[construct],
+			"{pattern}"
+		);
+	});
+
+	assert!(Pattern::parse(r"\D", Dialect::Rust)
+		.unwrap()
+		.check(Dialect::Postgres)
+		.is_ok());
+}
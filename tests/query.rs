@@ -0,0 +1,334 @@
+mod common;
+
+use clinvoice_match::{
+	Direction,
+	Error,
+	FieldPath,
+	FieldValue,
+	MatchJob,
+	MatchLocation,
+	MatchOption,
+	MatchTimesheet,
+	OrderBy,
+	Query,
+};
+use clinvoice_schema::{Invoice, InvoiceDate, Job, Location, Organization, Timesheet};
+use common::date;
+use pretty_assertions::assert_eq;
+
+/// Several [`Job`]s which have a variety of clients, dates, and invoices.
+fn jobs() -> Vec<Job>
+{
+	let client = |name: &str, outer: Option<&str>| Organization {
+		name: name.into(),
+		location: Location {
+			name: "Phoenix".into(),
+			outer: outer.map(|name| {
+				Location {
+					name: name.into(),
+					..Default::default()
+				}
+				.into()
+			}),
+			..Default::default()
+		},
+		..Default::default()
+	};
+
+	vec![
+		Job {
+			client: client("Big Old Test", Some("Arizona")),
+			date_close: Some(date(2022, 3, 1)),
+			date_open: date(2022, 1, 1),
+			id: 1,
+			..Default::default()
+		},
+		Job {
+			client: client("Alpha", None),
+			date_close: None,
+			date_open: date(2022, 2, 1),
+			id: 2,
+			invoice: Invoice {
+				date: Some(InvoiceDate {
+					issued: date(2022, 4, 1),
+					paid: None,
+				}),
+				..Default::default()
+			},
+			..Default::default()
+		},
+		Job {
+			client: client("Big Old Test", Some("Arizona")),
+			date_close: Some(date(2022, 2, 15)),
+			date_open: date(2022, 1, 15),
+			id: 3,
+			..Default::default()
+		},
+		Job {
+			client: client("Zulu", Some("Utah")),
+			date_close: None,
+			date_open: date(2022, 1, 1),
+			id: 4,
+			..Default::default()
+		},
+	]
+}
+
+/// An [`OrderBy`] the `field` in some `direction`.
+fn order_by(field: &str, direction: Direction) -> OrderBy
+{
+	OrderBy::new(field.parse().unwrap(), direction)
+}
+
+/// The [`Id`](clinvoice_schema::Id) of each [`Job`].
+fn ids(jobs: &[Job]) -> Vec<i64>
+{
+	jobs.iter().map(|j| j.id).collect()
+}
+
+#[test]
+fn unordered()
+{
+	let query = Query::new(MatchJob {
+		date_close: MatchOption::None,
+		..Default::default()
+	});
+	assert_eq!(ids(&query.apply(jobs()).unwrap()), [2, 4]);
+}
+
+#[test]
+fn ordered()
+{
+	let query = Query {
+		order_by: vec![
+			order_by("client.name", Direction::Ascending),
+			order_by("date_open", Direction::Descending),
+		],
+		..Query::<MatchJob>::default()
+	};
+	assert_eq!(ids(&query.apply(jobs()).unwrap()), [2, 3, 1, 4]);
+
+	let query = Query {
+		order_by: vec![order_by("id", Direction::Descending)],
+		..query
+	};
+	assert_eq!(ids(&query.apply(jobs()).unwrap()), [4, 3, 2, 1]);
+}
+
+#[test]
+fn nulls()
+{
+	let query = Query {
+		order_by: vec![
+			order_by("date_close", Direction::Ascending),
+			order_by("id", Direction::Ascending),
+		],
+		..Query::<MatchJob>::default()
+	};
+	assert_eq!(ids(&query.apply(jobs()).unwrap()), [3, 1, 2, 4]);
+
+	let query = Query {
+		order_by: vec![
+			order_by("client.location.outer.name", Direction::Descending),
+			order_by("invoice.date_issued", Direction::Descending),
+			order_by("id", Direction::Ascending),
+		],
+		..query
+	};
+	assert_eq!(ids(&query.apply(jobs()).unwrap()), [2, 4, 1, 3]);
+}
+
+#[test]
+fn offset()
+{
+	let query = Query {
+		limit: Some(2),
+		offset: 1,
+		order_by: vec![order_by("id", Direction::Ascending)],
+		..Query::<MatchJob>::default()
+	};
+	assert_eq!(ids(&query.apply(jobs()).unwrap()), [2, 3]);
+
+	let query = Query { offset: 3, ..query };
+	assert_eq!(ids(&query.apply(jobs()).unwrap()), [4]);
+}
+
+#[test]
+fn cursor()
+{
+	let mut query = Query {
+		limit: Some(3),
+		order_by: vec![
+			order_by("date_open", Direction::Ascending),
+			order_by("id", Direction::Descending),
+		],
+		..Query::<MatchJob>::default()
+	};
+
+	let page = query.apply(jobs()).unwrap();
+	assert_eq!(ids(&page), [4, 1, 3]);
+
+	let cursor = query.cursor(&page[2]).unwrap();
+	assert_eq!(cursor.values(), [
+		FieldValue::NaiveDateTime(date(2022, 1, 15)),
+		FieldValue::Id(3)
+	]);
+
+	query.after = Some(cursor);
+	assert_eq!(ids(&query.apply(jobs()).unwrap()), [2]);
+
+	query.after = Some(query.cursor(&jobs()[1]).unwrap());
+	assert!(query.apply(jobs()).unwrap().is_empty());
+}
+
+#[test]
+fn project()
+{
+	let query = Query {
+		select: [
+			"id",
+			"client.location.outer.name",
+			"date_close",
+			"invoice.date_issued",
+		]
+		.into_iter()
+		.map(|field| field.parse().unwrap())
+		.collect(),
+		..Query::new(MatchJob::default())
+	};
+
+	assert_eq!(
+		query
+			.apply(jobs())
+			.unwrap()
+			.iter()
+			.take(2)
+			.map(|job| query.project(job).unwrap())
+			.collect::<Vec<_>>(),
+		[
+			vec![
+				1.into(),
+				"Arizona".into(),
+				date(2022, 3, 1).into(),
+				FieldValue::Null
+			],
+			vec![
+				2.into(),
+				FieldValue::Null,
+				FieldValue::Null,
+				date(2022, 4, 1).into()
+			],
+		]
+	);
+}
+
+#[test]
+fn invalid()
+{
+	let query = Query {
+		order_by: vec![order_by(
+			"client.location.outer.outer.id",
+			Direction::Ascending,
+		)],
+		..Query::<MatchJob>::default()
+	};
+	assert!(query.check().is_ok());
+
+	for field in ["client.location", "client.name.first", "expenses", "salary"]
+	{
+		let query = Query {
+			order_by: vec![order_by(field, Direction::Ascending)],
+			..query.clone()
+		};
+		match query.check()
+		{
+			Err(Error::UnknownField { path }) => assert_eq!(path, field.parse::<FieldPath>().unwrap()),
+			result => panic!("`{field}` should not be orderable, but got {result:?}"),
+		}
+	}
+
+	assert!(matches!(
+		Query {
+			select: vec!["client.location".parse().unwrap()],
+			..query.clone()
+		}
+		.check(),
+		Err(Error::UnknownField { .. })
+	));
+
+	let query = Query {
+		order_by: vec![order_by("expenses", Direction::Ascending)],
+		..Query::<MatchTimesheet>::default()
+	};
+	assert!(matches!(
+		query.apply(vec![Timesheet::default()]),
+		Err(Error::UnknownField { .. })
+	));
+
+	let query = Query {
+		order_by: vec![order_by("id", Direction::Ascending)],
+		..Query::<MatchLocation>::default()
+	};
+	let cursor = query.cursor(&Location::default()).unwrap();
+	assert!(Query {
+		after: Some(cursor.clone()),
+		..query.clone()
+	}
+	.check()
+	.is_ok());
+
+	let query = Query {
+		after: Some(cursor),
+		order_by: Vec::new(),
+		..query
+	};
+	assert!(matches!(
+		query.check(),
+		Err(Error::CursorLength {
+			expected: 0,
+			found: 1
+		})
+	));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde()
+{
+	let query: Query<MatchJob> = serde_yaml::from_str(
+		r#"
+condition:
+  date_close: none
+order_by:
+  - field: client.name
+  - field: date_open
+    direction: descending
+limit: 1
+select: [id]
+"#,
+	)
+	.unwrap();
+
+	assert_eq!(query.order_by, [
+		order_by("client.name", Direction::Ascending),
+		order_by("date_open", Direction::Descending)
+	]);
+	assert_eq!(ids(&query.apply(jobs()).unwrap()), [2]);
+	assert_eq!(query.project(&jobs()[1]).unwrap(), [2.into()]);
+
+	let cursor = query.cursor(&jobs()[1]).unwrap();
+	let yaml = serde_yaml::to_string(&cursor).unwrap();
+	assert_eq!(
+		serde_yaml::from_str::<clinvoice_match::Cursor>(&yaml).unwrap(),
+		cursor
+	);
+
+	let query = Query {
+		after: Some(cursor),
+		..query
+	};
+	assert_eq!(
+		serde_yaml::from_str::<Query<MatchJob>>(&serde_yaml::to_string(&query).unwrap()).unwrap(),
+		query
+	);
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "serde")]
+
+mod common;
+mod dispatch;
+
+use core::fmt::Debug;
+
+use common::Example;
+use pretty_assertions::assert_eq;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// TOML documents must be tables, so every matcher is stored in a field of this.
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Document<T>
+{
+	condition: T,
+}
+
+/// Assert that the `example` can be serialized to, and deserialized from, every supported format.
+fn assert_round_trip<T>(example: &Example)
+where
+	T: Debug + DeserializeOwned + PartialEq + Serialize,
+{
+	let condition: T = serde_yaml::from_str(&example.yaml).unwrap_or_else(|e| {
+		panic!(
+			"The example of `{}` in {} is not valid YAML: {e}\n{}",
+			example.type_name, example.file, example.yaml
+		)
+	});
+
+	#[cfg(feature = "binary")]
+	{
+		let bytes = clinvoice_match::binary::to_bytes(&condition).unwrap();
+		assert_eq!(
+			clinvoice_match::binary::from_bytes::<T>(&bytes).unwrap(),
+			condition,
+			"Binary:\n{bytes:?}"
+		);
+	}
+
+	let json = serde_json::to_string(&condition).unwrap();
+	assert_eq!(
+		serde_json::from_str::<T>(&json).unwrap(),
+		condition,
+		"JSON:\n{json}"
+	);
+
+	let document = Document { condition };
+	let toml = toml::to_string(&document).unwrap();
+	assert_eq!(
+		toml::from_str::<Document<T>>(&toml).unwrap(),
+		document,
+		"TOML:\n{toml}"
+	);
+
+	let yaml = serde_yaml::to_string(&document.condition).unwrap();
+	assert_eq!(
+		serde_yaml::from_str::<T>(&yaml).unwrap(),
+		document.condition,
+		"YAML:\n{yaml}"
+	);
+}
+
+#[test]
+fn documented_examples()
+{
+	let examples = common::documented_examples();
+	assert!(!examples.is_empty());
+	examples.iter().for_each(|example| {
+		dispatch::dispatch!(example.type_name.as_str(), assert_round_trip, example)
+	});
+}
@@ -0,0 +1,198 @@
+mod common;
+
+use clinvoice_match::{
+	Compile,
+	FieldPath,
+	IsMatch,
+	Match,
+	MatchEmployee,
+	MatchExpense,
+	MatchInvoice,
+	MatchJob,
+	MatchLocation,
+	MatchOption,
+	MatchOrganization,
+	MatchOuterLocation,
+	MatchSet,
+	MatchStr,
+	MatchTimesheet,
+	Satisfiability,
+	Satisfy,
+};
+use clinvoice_schema::{Currency, Money};
+use common::date;
+use money2::Decimal;
+use pretty_assertions::assert_eq;
+
+/// A [`FieldPath`] made of the `fields`.
+fn path(fields: &[&str]) -> FieldPath
+{
+	fields
+		.iter()
+		.fold(FieldPath::default(), |path, field| path.join(*field))
+}
+
+#[test]
+fn examples_match()
+{
+	let condition = MatchTimesheet {
+		employee: MatchEmployee {
+			status: MatchStr::Not(
+				MatchStr::Or(vec!["Fired".to_owned().into(), "Retired".to_owned().into()]).into(),
+			),
+			title: MatchStr::Regex(r"^(Senior|Junior) \w+ [A-Z]{2,3}$".into()),
+			..Default::default()
+		},
+		expenses: MatchSet::And(vec![
+			MatchSet::Contains(MatchExpense {
+				category: "Travel".to_owned().into(),
+				cost: Match::GreaterThan(Money::new(10_000, 2, Currency::Usd)),
+				..Default::default()
+			}),
+			MatchSet::Not(
+				MatchSet::Contains(MatchExpense {
+					category: "Food".to_owned().into(),
+					..Default::default()
+				})
+				.into(),
+			),
+		]),
+		job: MatchJob {
+			client: MatchOrganization {
+				location: MatchLocation {
+					outer: MatchOuterLocation::Some(
+						MatchLocation {
+							name: MatchStr::Contains("land".into()),
+							..Default::default()
+						}
+						.into(),
+					),
+					..Default::default()
+				},
+				..Default::default()
+			},
+			date_close: MatchOption::Not(MatchOption::None.into()),
+			date_open: Match::Not(Match::LessThan(date(2020, 1, 1)).into()),
+			invoice: MatchInvoice {
+				date_paid: MatchOption::GreaterThan(date(2021, 1, 1)),
+				hourly_rate: Match::InRange(
+					Money::new(10_00, 2, Currency::Usd),
+					Money::new(20_00, 2, Currency::Usd),
+				),
+				..Default::default()
+			},
+			..Default::default()
+		},
+		time_end: MatchOption::None,
+		..Default::default()
+	};
+
+	let timesheet = condition.satisfy().example().unwrap();
+	assert!(condition.compile().unwrap().is_match(&timesheet));
+	assert_eq!(timesheet.expenses.len(), 1);
+	assert!(timesheet.job.invoice.date.is_some());
+}
+
+#[test]
+fn extreme_amounts()
+{
+	let usd = |amount| Money {
+		amount,
+		currency: Currency::Usd,
+	};
+
+	let max = usd(Decimal::MAX);
+	let min = usd(Decimal::MIN);
+
+	assert_eq!(
+		Match::GreaterThan(max).satisfy(),
+		Satisfiability::Unsatisfiable(FieldPath::default())
+	);
+	assert_eq!(
+		Match::LessThan(min).satisfy(),
+		Satisfiability::Unsatisfiable(FieldPath::default())
+	);
+	assert_eq!(
+		Match::InRange(min, max).satisfy(),
+		Satisfiability::Satisfiable(min)
+	);
+
+	// the midpoint of these overflows while it is being computed
+	let almost_max = usd(Decimal::MAX - Decimal::ONE);
+	assert!(!matches!(
+		Match::And(vec![Match::GreaterThan(almost_max), Match::LessThan(max)]).satisfy(),
+		Satisfiability::Satisfiable(_)
+	));
+}
+
+#[test]
+fn unknown()
+{
+	assert_eq!(
+		MatchStr::<String>::And(vec![
+			MatchStr::Regex("^a".into()),
+			MatchStr::Regex("b$".into()),
+			MatchStr::Contains("c".into())
+		])
+		.satisfy(),
+		Satisfiability::Unknown,
+	);
+}
+
+#[test]
+fn unsatisfiable()
+{
+	let employee = MatchEmployee {
+		name: MatchStr::And(vec![
+			MatchStr::Contains("a".into()),
+			MatchStr::Not(MatchStr::Contains("a".into()).into()),
+		]),
+		..Default::default()
+	};
+	assert_eq!(
+		employee.satisfy(),
+		Satisfiability::Unsatisfiable(path(&["name"]))
+	);
+
+	let timesheet = MatchTimesheet {
+		employee: MatchEmployee {
+			title: MatchStr::And(vec!["CEO".to_owned().into(), "CTO".to_owned().into()]),
+			..Default::default()
+		},
+		expenses: MatchSet::And(vec![
+			MatchSet::Contains(MatchExpense {
+				id: Match::InRange(5, 6),
+				..Default::default()
+			}),
+			MatchSet::Not(
+				MatchSet::Contains(MatchExpense {
+					id: Match::EqualTo(5),
+					..Default::default()
+				})
+				.into(),
+			),
+		]),
+		time_begin: Match::And(vec![
+			Match::GreaterThan(date(2022, 1, 1)),
+			Match::LessThan(date(2022, 1, 1)),
+		]),
+		..Default::default()
+	};
+
+	assert_eq!(
+		timesheet.satisfy(),
+		Satisfiability::Unsatisfiable(path(&["employee", "title"]))
+	);
+	assert_eq!(
+		timesheet.expenses.satisfy_at(&path(&["expenses"])),
+		Satisfiability::Unknown,
+	);
+	assert_eq!(
+		timesheet.time_begin.satisfy(),
+		Satisfiability::Unsatisfiable(FieldPath::default())
+	);
+	assert_eq!(
+		MatchOption::And(vec![MatchOption::EqualTo(3), MatchOption::None]).satisfy(),
+		Satisfiability::Unsatisfiable(FieldPath::default())
+	);
+}
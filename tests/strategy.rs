@@ -0,0 +1,163 @@
+#![cfg(feature = "proptest")]
+
+use core::{borrow::Borrow, fmt::Debug};
+
+use clinvoice_match::{
+	Compile,
+	Dialect,
+	IsMatch,
+	Match,
+	MatchEmployee,
+	MatchExpense,
+	MatchInvoice,
+	MatchJob,
+	MatchLocation,
+	MatchOption,
+	MatchOrganization,
+	MatchOuterLocation,
+	MatchStr,
+	MatchTimesheet,
+	RecordStrategy,
+	Recursion,
+	Satisfy,
+};
+use clinvoice_schema::{chrono::NaiveDateTime, Id, Money};
+use proptest::{
+	arbitrary::{any_with, Arbitrary},
+	prelude::Strategy,
+	strategy::ValueTree,
+	test_runner::{Config, TestCaseError, TestRunner},
+};
+
+/// Check that the records which are generated for arbitrary matchers of type `M` match (or do not
+/// match) them, and that records are generated for every matcher which can be
+/// [satisfied](Satisfy) and every matcher which is `refutable`.
+fn agrees<M, Q>(refutable: impl Fn(&M) -> bool)
+where
+	M: Arbitrary<Parameters = Recursion> + Compile + RecordStrategy,
+	M::Compiled: IsMatch<Q>,
+	M::Record: Borrow<Q> + Debug,
+	Q: ?Sized,
+{
+	let mut runner = TestRunner::new(Config {
+		cases: 64,
+		..Default::default()
+	});
+	runner
+		.run(&any_with::<M>(Recursion::default()), |condition| {
+			let compiled = condition.compile().unwrap();
+			let mut records = TestRunner::deterministic();
+
+			match condition.matching()
+			{
+				Some(matching) => (0..8).try_for_each(|_| {
+					let record = matching.new_tree(&mut records).unwrap().current();
+					match compiled.is_match(record.borrow())
+					{
+						true => Ok(()),
+						false => Err(TestCaseError::fail(format!("{record:?} should match"))),
+					}
+				})?,
+				None if condition.satisfy().is_satisfiable() =>
+				{
+					return Err(TestCaseError::fail(
+						"a satisfiable condition should generate matching records",
+					));
+				},
+				None => (),
+			}
+
+			match condition.not_matching()
+			{
+				Some(not_matching) => (0..8).try_for_each(|_| {
+					let record = not_matching.new_tree(&mut records).unwrap().current();
+					match compiled.is_match(record.borrow())
+					{
+						true => Err(TestCaseError::fail(format!("{record:?} should not match"))),
+						false => Ok(()),
+					}
+				})?,
+				None if refutable(&condition) =>
+				{
+					return Err(TestCaseError::fail(
+						"a refutable condition should generate records which do not match",
+					));
+				},
+				None => (),
+			}
+
+			Ok(())
+		})
+		.unwrap();
+}
+
+#[test]
+fn arbitrary_is_portable()
+{
+	TestRunner::default()
+		.run(
+			&any_with::<MatchTimesheet>(Recursion::default()),
+			|condition| {
+				condition.compile().unwrap();
+				Dialect::Postgres.check(&condition).unwrap();
+				Ok(())
+			},
+		)
+		.unwrap();
+}
+
+#[test]
+fn depth()
+{
+	let mut runner = TestRunner::deterministic();
+	let flat = any_with::<MatchLocation>(Recursion {
+		depth: 0,
+		..Default::default()
+	});
+	for _ in 0..64
+	{
+		let location = flat.new_tree(&mut runner).unwrap().current();
+		assert!(!matches!(location.outer, MatchOuterLocation::Some(_)));
+	}
+}
+
+#[test]
+fn leaves_agree()
+{
+	agrees::<Match<Id>, _>(|condition| {
+		*condition != Match::Any &&
+			Match::Not(condition.clone().into())
+				.satisfy()
+				.is_satisfiable()
+	});
+	agrees::<Match<NaiveDateTime>, _>(|condition| {
+		*condition != Match::Any &&
+			Match::Not(condition.clone().into())
+				.satisfy()
+				.is_satisfiable()
+	});
+	agrees::<MatchOption<Money>, _>(|condition| {
+		*condition != MatchOption::Any &&
+			MatchOption::Not(condition.clone().into())
+				.satisfy()
+				.is_satisfiable()
+	});
+	agrees::<MatchStr<String>, str>(|condition| {
+		*condition != MatchStr::Any &&
+			MatchStr::Not(condition.clone().into())
+				.satisfy()
+				.is_satisfiable()
+	});
+}
+
+#[test]
+fn records_agree()
+{
+	agrees::<MatchEmployee, _>(|_| false);
+	agrees::<MatchExpense, _>(|_| false);
+	agrees::<MatchInvoice, _>(|_| false);
+	agrees::<MatchJob, _>(|_| false);
+	agrees::<MatchLocation, _>(|_| false);
+	agrees::<MatchOrganization, _>(|_| false);
+	agrees::<MatchTimesheet, _>(|_| false);
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "yaml")]
+
+mod common;
+
+use clinvoice_match::{Error, Limits, MatchLocation, MatchTimesheet, Parameters, Template};
+use common::date;
+use pretty_assertions::assert_eq;
+
+/// The parameter which [`Template::bind`] reports as having the wrong type.
+fn wrong_type<M>(template: &str, parameters: &Parameters) -> String
+where
+	M: serde::de::DeserializeOwned,
+{
+	match serde_yaml::from_str::<Template<M>>(template)
+		.unwrap()
+		.bind(parameters, &Limits::default())
+	{
+		Err(Error::ParameterType { parameter, .. }) => parameter,
+		Err(e) => panic!("Expected a parameter to have the wrong type, but got {e}"),
+		Ok(_) => panic!("Expected a parameter to have the wrong type, but the template was bound"),
+	}
+}
+
+#[test]
+fn nested_location()
+{
+	let template = "outer: {id: $id, name: $name}";
+	let mut parameters = Parameters::new();
+	parameters
+		.insert("id", 3)
+		.unwrap()
+		.insert("name", true)
+		.unwrap();
+	assert_eq!(wrong_type::<MatchLocation>(template, &parameters), "name");
+
+	parameters
+		.insert("id", "three")
+		.unwrap()
+		.insert("name", "Europe")
+		.unwrap();
+	assert_eq!(wrong_type::<MatchLocation>(template, &parameters), "id");
+}
+
+#[test]
+fn range()
+{
+	let template = "time_begin: {in_range: [$start, $end]}";
+	let start = date(2022, 1, 1);
+	let mut parameters = Parameters::new();
+
+	parameters
+		.insert("start", start)
+		.unwrap()
+		.insert("end", 5)
+		.unwrap();
+	assert_eq!(wrong_type::<MatchTimesheet>(template, &parameters), "end");
+
+	parameters
+		.insert("start", 5)
+		.unwrap()
+		.insert("end", start)
+		.unwrap();
+	assert_eq!(wrong_type::<MatchTimesheet>(template, &parameters), "start");
+}